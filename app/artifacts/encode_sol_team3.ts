@@ -5,6 +5,11 @@ export type EncodeSolTeam3 = {
     {
       name: "createNativePool";
       accounts: [
+        {
+          name: "creatorRegistry";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "launchPool";
           isMut: true;
@@ -49,6 +54,16 @@ export type EncodeSolTeam3 = {
           name: "rent";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -75,6 +90,22 @@ export type EncodeSolTeam3 = {
         {
           name: "tokenMintDecimals";
           type: "u8";
+        },
+        {
+          name: "proceedsVesting";
+          type: {
+            option: {
+              defined: "ProceedsVesting";
+            };
+          };
+        },
+        {
+          name: "unsoldPolicy";
+          type: {
+            option: {
+              defined: "UnsoldPolicy";
+            };
+          };
         }
       ];
     },
@@ -95,6 +126,7 @@ export type EncodeSolTeam3 = {
           name: "sourceTokenAccount";
           isMut: true;
           isSigner: false;
+          isOptional: true;
         },
         {
           name: "treasurer";
@@ -106,6 +138,18 @@ export type EncodeSolTeam3 = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "teamLock";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "teamLockTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
         {
           name: "authority";
           isMut: true;
@@ -125,6 +169,16 @@ export type EncodeSolTeam3 = {
           name: "rent";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [];
@@ -152,6 +206,42 @@ export type EncodeSolTeam3 = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "stakeAccount";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "nftTokenAccount";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "nftMetadata";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "nftUsage";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "instructionsSysvar";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "referralStats";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
         {
           name: "user";
           isMut: true;
@@ -171,6 +261,16 @@ export type EncodeSolTeam3 = {
           name: "rent";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -197,6 +297,94 @@ export type EncodeSolTeam3 = {
           name: "authority";
           isMut: true;
           isSigner: true;
+        },
+        {
+          name: "treasurer";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "treasury";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "ammProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "ammPool";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "ammLpMint";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "ammTokenReserve";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "lpLock";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "lpTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "liquidityEscrow";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "escrowTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [];
@@ -258,840 +446,9516 @@ export type EncodeSolTeam3 = {
           name: "rent";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [];
-    }
-  ];
-  accounts: [
-    {
-      name: "launchPool";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "unlockDate";
-            type: "i64";
-          },
-          {
-            name: "poolSize";
-            type: "u64";
-          },
-          {
-            name: "minimumTokenAmount";
-            type: "u64";
-          },
-          {
-            name: "maximumTokenAmount";
-            type: "u64";
-          },
-          {
-            name: "rate";
-            type: "u64";
-          },
-          {
-            name: "poolSizeRemaining";
-            type: "u64";
-          },
-          {
-            name: "tokenMint";
-            type: "publicKey";
-          },
-          {
-            name: "tokenMintDecimals";
-            type: "u8";
-          },
-          {
-            name: "authority";
-            type: "publicKey";
-          },
-          {
-            name: "vaultAmount";
-            type: "u64";
-          },
-          {
-            name: "isVesting";
-            type: "bool";
-          },
-          {
-            name: "currency";
-            type: {
-              defined: "CurrencyType";
-            };
-          },
-          {
-            name: "poolType";
-            type: {
-              defined: "LaunchPoolType";
-            };
-          },
-          {
-            name: "status";
-            type: {
-              defined: "LaunchPoolState";
-            };
-          }
-        ];
-      };
-    },
-    {
-      name: "treasurer";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "authority";
-            type: "publicKey";
-          },
-          {
-            name: "launchPool";
-            type: "publicKey";
-          },
-          {
-            name: "tokenMint";
-            type: "publicKey";
-          },
-          {
-            name: "amount";
-            type: "u64";
-          }
-        ];
-      };
     },
     {
-      name: "userPool";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "amount";
-            type: "u64";
-          },
-          {
-            name: "currencyAmount";
-            type: "u64";
-          },
-          {
-            name: "claimed";
-            type: "u64";
-          }
-        ];
-      };
-    }
-  ];
-  types: [
-    {
-      name: "LaunchPoolBumps";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "launchpoolBump";
-            type: "u8";
-          },
-          {
-            name: "treasurerBump";
-            type: "u8";
-          },
-          {
-            name: "vaultBump";
-            type: "u8";
-          }
-        ];
-      };
-    },
-    {
-      name: "CurrencyType";
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "SOL";
-          },
-          {
-            name: "USDC";
-          }
-        ];
-      };
-    },
-    {
-      name: "LaunchPoolType";
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "FairLaunch";
-          },
-          {
-            name: "WhiteList";
-          }
-        ];
-      };
-    },
-    {
-      name: "LaunchPoolState";
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "Pending";
-          },
-          {
-            name: "Active";
-          },
-          {
-            name: "Completed";
-          },
-          {
-            name: "Cancelled";
-          }
-        ];
-      };
-    }
-  ];
-  events: [
-    {
-      name: "BuyTokenWithNativeEvent";
-      fields: [
+      name: "createSaleRound";
+      accounts: [
         {
-          name: "buyer";
-          type: "publicKey";
-          index: false;
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "amount";
+          name: "saleRound";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u8";
+        },
+        {
+          name: "rate";
           type: "u64";
-          index: false;
         },
         {
-          name: "tokenAmount";
+          name: "minimumTokenAmount";
           type: "u64";
-          index: false;
         },
         {
-          name: "vaultAmount";
+          name: "maximumTokenAmount";
           type: "u64";
-          index: false;
+        },
+        {
+          name: "startTime";
+          type: "i64";
+        },
+        {
+          name: "endTime";
+          type: "i64";
+        },
+        {
+          name: "allocation";
+          type: "u64";
+        },
+        {
+          name: "access";
+          type: {
+            defined: "SaleRoundAccess";
+          };
         }
       ];
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "MutationForbidden";
-      msg: "The authority is not authorized to initialize the program";
-    },
-    {
-      code: 6001;
-      name: "InvalidInstruction";
-      msg: "Invalid instruction";
-    },
-    {
-      code: 6002;
-      name: "InvalidUnlockDate";
-      msg: "Invalid unlock date";
-    },
-    {
-      code: 6003;
-      name: "InvalidAuthority";
-      msg: "Invalid authority";
-    },
-    {
-      code: 6004;
-      name: "InvalidTokenMint";
-      msg: "Invalid token mint";
-    },
-    {
-      code: 6005;
-      name: "InvalidLaunchPoolStatus";
-      msg: "Invalid launch pool status";
-    },
-    {
-      code: 6006;
-      name: "InvalidCurrencyType";
-      msg: "Invalid currency type";
-    },
-    {
-      code: 6007;
-      name: "PoolNotEnough";
-      msg: "Pool not enough to buy";
     },
     {
-      code: 6008;
-      name: "InvalidAmount";
-      msg: "Invalid amount";
+      name: "addWalletsToWhitelist";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "saleRound";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "whitelist";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "wallets";
+          type: {
+            vec: "publicKey";
+          };
+        }
+      ];
     },
     {
-      code: 6009;
-      name: "MaximumTokenAmountReached";
-      msg: "Maximum token amount reached";
+      name: "removeWalletFromWhitelist";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "saleRound";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "whitelist";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "wallet";
+          type: "publicKey";
+        }
+      ];
     },
     {
-      code: 6010;
+      name: "buyTokenInRound";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "saleRound";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "whitelist";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakeAccount";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "nftTokenAccount";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "nftMetadata";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "nftUsage";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "instructionsSysvar";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "referralStats";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "advanceSaleRound";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "currentRound";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nextRound";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "signer";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "initializeStaking";
+      accounts: [
+        {
+          name: "stakingConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakeMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "stakeVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "programData";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "tierThresholds";
+          type: {
+            array: [
+              {
+                defined: "TierThreshold";
+              },
+              3
+            ];
+          };
+        }
+      ];
+    },
+    {
+      name: "updateStakingTiers";
+      accounts: [
+        {
+          name: "stakingConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "tierThresholds";
+          type: {
+            array: [
+              {
+                defined: "TierThreshold";
+              },
+              3
+            ];
+          };
+        }
+      ];
+    },
+    {
+      name: "stake";
+      accounts: [
+        {
+          name: "stakingConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakeMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "stakeVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakeAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "lockDuration";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "unstake";
+      accounts: [
+        {
+          name: "stakingConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakeMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "stakeVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakeAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "configureTierAllocations";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "tierCaps";
+          type: {
+            array: ["u64", 3];
+          };
+        }
+      ];
+    },
+    {
+      name: "configureNftGate";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "collection";
+          type: {
+            option: "publicKey";
+          };
+        },
+        {
+          name: "creators";
+          type: {
+            vec: "publicKey";
+          };
+        },
+        {
+          name: "singleUse";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "configureAllocationSigner";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "allocationSigner";
+          type: {
+            option: "publicKey";
+          };
+        }
+      ];
+    },
+    {
+      name: "registerReferrer";
+      accounts: [
+        {
+          name: "referrer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "wallet";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createReferralStats";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "referralStats";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "referrer";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "userPool";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "wallet";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "configureReferral";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "referralBps";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "claimReferralReward";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "referralStats";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "wallet";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "configureLiquidity";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ammProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ammPool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "lpMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "lpLock";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lpLockTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "liquidityEscrow";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "escrowTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "liquidityBps";
+          type: "u16";
+        },
+        {
+          name: "listingRate";
+          type: "u64";
+        },
+        {
+          name: "liquidityTokenReserve";
+          type: "u64";
+        },
+        {
+          name: "lpUnlockDate";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "withdrawLp";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "lpLock";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "lpLockTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destination";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "extendLock";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "lpLock";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "unlockDate";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "withdrawProceeds";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "configureGovernance";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "quorumBps";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "proposeMilestone";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "milestone";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "deadline";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "voteMilestone";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "milestone";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userPool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "vote";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "voter";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "approve";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "executeMilestone";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "milestone";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "reclaimFunds";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "proposeAuthority";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "newAuthority";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "acceptAuthority";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasurer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newAuthority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeUserPool";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "closeLaunchPool";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasurer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "vault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "poolMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "teamLock";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "lpLock";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "creator";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "finalizeUnsoldTokens";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasurer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authorityTokenAccount";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "ammTokenReserve";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "withdrawUnsoldTokens";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasurer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destination";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "burn";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "configureTeamLock";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "teamLock";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "teamLockTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "cliffDate";
+          type: "i64";
+        },
+        {
+          name: "vestingDuration";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "withdrawTeamTokens";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "teamLock";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "teamLockTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "destination";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "updatePoolMetadata";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "poolMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "name";
+          type: "string";
+        },
+        {
+          name: "symbol";
+          type: "string";
+        },
+        {
+          name: "uri";
+          type: "string";
+        },
+        {
+          name: "website";
+          type: {
+            option: "string";
+          };
+        },
+        {
+          name: "socials";
+          type: {
+            vec: "string";
+          };
+        },
+        {
+          name: "contentHash";
+          type: {
+            array: ["u8", 32];
+          };
+        }
+      ];
+    },
+    {
+      name: "migrateLaunchPool";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasurer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createTokenMint";
+      accounts: [
+        {
+          name: "tokenMint";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "mintAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "decimals";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "mintPoolSupply";
+      accounts: [
+        {
+          name: "launchPool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasurer";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasury";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "metadata";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rent";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "eventAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "program";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "liquidityAmount";
+          type: "u64";
+        },
+        {
+          name: "teamAmount";
+          type: "u64";
+        },
+        {
+          name: "tokenMetadata";
+          type: {
+            option: {
+              defined: "TokenMetadata";
+            };
+          };
+        },
+        {
+          name: "revokeAuthorities";
+          type: "bool";
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "creatorRegistry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "creator";
+            type: "publicKey";
+          },
+          {
+            name: "poolCount";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "launchPool";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "unlockDate";
+            type: "i64";
+          },
+          {
+            name: "poolSize";
+            type: "u64";
+          },
+          {
+            name: "minimumTokenAmount";
+            type: "u64";
+          },
+          {
+            name: "maximumTokenAmount";
+            type: "u64";
+          },
+          {
+            name: "rate";
+            type: "u64";
+          },
+          {
+            name: "poolSizeRemaining";
+            type: "u64";
+          },
+          {
+            name: "tokenMint";
+            type: "publicKey";
+          },
+          {
+            name: "tokenMintDecimals";
+            type: "u8";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "vaultAmount";
+            type: "u64";
+          },
+          {
+            name: "isVesting";
+            type: "bool";
+          },
+          {
+            name: "currency";
+            type: {
+              defined: "CurrencyType";
+            };
+          },
+          {
+            name: "poolType";
+            type: {
+              defined: "LaunchPoolType";
+            };
+          },
+          {
+            name: "status";
+            type: {
+              defined: "LaunchPoolState";
+            };
+          },
+          {
+            name: "roundCount";
+            type: "u8";
+          },
+          {
+            name: "currentRound";
+            type: "u8";
+          },
+          {
+            name: "roundsAllocation";
+            type: "u64";
+          },
+          {
+            name: "isTiered";
+            type: "bool";
+          },
+          {
+            name: "tierCaps";
+            type: {
+              array: ["u64", 3];
+            };
+          },
+          {
+            name: "nftGate";
+            type: {
+              defined: "NftGate";
+            };
+          },
+          {
+            name: "allocationSigner";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "referralBps";
+            type: "u16";
+          },
+          {
+            name: "referralRewardTotal";
+            type: "u64";
+          },
+          {
+            name: "liquidityBps";
+            type: "u16";
+          },
+          {
+            name: "listingRate";
+            type: "u64";
+          },
+          {
+            name: "liquidityTokenReserve";
+            type: "u64";
+          },
+          {
+            name: "ammProgram";
+            type: "publicKey";
+          },
+          {
+            name: "ammPool";
+            type: "publicKey";
+          },
+          {
+            name: "liquiditySolAmount";
+            type: "u64";
+          },
+          {
+            name: "liquidityTokenAmount";
+            type: "u64";
+          },
+          {
+            name: "teamLockAmount";
+            type: "u64";
+          },
+          {
+            name: "proceedsVesting";
+            type: {
+              option: {
+                defined: "ProceedsVesting";
+              };
+            };
+          },
+          {
+            name: "proceedsWithdrawn";
+            type: "u64";
+          },
+          {
+            name: "completedAt";
+            type: "i64";
+          },
+          {
+            name: "isGoverned";
+            type: "bool";
+          },
+          {
+            name: "quorumBps";
+            type: "u16";
+          },
+          {
+            name: "milestoneCount";
+            type: "u8";
+          },
+          {
+            name: "hasOpenMilestone";
+            type: "bool";
+          },
+          {
+            name: "milestoneReleased";
+            type: "u64";
+          },
+          {
+            name: "isRefunding";
+            type: "bool";
+          },
+          {
+            name: "refundableAmount";
+            type: "u64";
+          },
+          {
+            name: "creator";
+            type: "publicKey";
+          },
+          {
+            name: "pendingAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "index";
+            type: "u64";
+          },
+          {
+            name: "bumps";
+            type: {
+              defined: "LaunchPoolBumps";
+            };
+          },
+          {
+            name: "openPositions";
+            type: "u32";
+          },
+          {
+            name: "unsoldWithdrawn";
+            type: "bool";
+          },
+          {
+            name: "unsoldPolicy";
+            type: {
+              option: {
+                defined: "UnsoldPolicy";
+              };
+            };
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "mintedSupply";
+            type: "u64";
+          },
+          {
+            name: "refundedSoldAmount";
+            type: "u64";
+          },
+          {
+            name: "refundedTotal";
+            type: "u64";
+          },
+          {
+            name: "referralRewardClaimed";
+            type: "u64";
+          },
+          {
+            name: "claimedSoldAmount";
+            type: "u64";
+          },
+          {
+            name: "refundSoldAmount";
+            type: "u64";
+          },
+          {
+            name: "reserved";
+            type: {
+              array: ["u8", 79];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "lpLock";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "launchPool";
+            type: "publicKey";
+          },
+          {
+            name: "lpMint";
+            type: "publicKey";
+          },
+          {
+            name: "lpTokenAccount";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "unlockDate";
+            type: "i64";
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "milestone";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "launchPool";
+            type: "publicKey";
+          },
+          {
+            name: "index";
+            type: "u8";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "deadline";
+            type: "i64";
+          },
+          {
+            name: "votesFor";
+            type: "u64";
+          },
+          {
+            name: "votesAgainst";
+            type: "u64";
+          },
+          {
+            name: "status";
+            type: {
+              defined: "MilestoneState";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "vote";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "milestone";
+            type: "publicKey";
+          },
+          {
+            name: "voter";
+            type: "publicKey";
+          },
+          {
+            name: "weight";
+            type: "u64";
+          },
+          {
+            name: "approve";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "nftUsage";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "launchPool";
+            type: "publicKey";
+          },
+          {
+            name: "nftMint";
+            type: "publicKey";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "poolMetadata";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "launchPool";
+            type: "publicKey";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "website";
+            type: {
+              option: "string";
+            };
+          },
+          {
+            name: "socials";
+            type: {
+              vec: "string";
+            };
+          },
+          {
+            name: "contentHash";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "referrer";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "wallet";
+            type: "publicKey";
+          },
+          {
+            name: "registeredAt";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "referralStats";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "launchPool";
+            type: "publicKey";
+          },
+          {
+            name: "referrer";
+            type: "publicKey";
+          },
+          {
+            name: "referredCount";
+            type: "u32";
+          },
+          {
+            name: "referredAmount";
+            type: "u64";
+          },
+          {
+            name: "referredCurrencyAmount";
+            type: "u64";
+          },
+          {
+            name: "reward";
+            type: "u64";
+          },
+          {
+            name: "claimed";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "saleRound";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "launchPool";
+            type: "publicKey";
+          },
+          {
+            name: "index";
+            type: "u8";
+          },
+          {
+            name: "rate";
+            type: "u64";
+          },
+          {
+            name: "minimumTokenAmount";
+            type: "u64";
+          },
+          {
+            name: "maximumTokenAmount";
+            type: "u64";
+          },
+          {
+            name: "startTime";
+            type: "i64";
+          },
+          {
+            name: "endTime";
+            type: "i64";
+          },
+          {
+            name: "allocation";
+            type: "u64";
+          },
+          {
+            name: "sold";
+            type: "u64";
+          },
+          {
+            name: "access";
+            type: {
+              defined: "SaleRoundAccess";
+            };
+          },
+          {
+            name: "isClosed";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "whitelist";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "saleRound";
+            type: "publicKey";
+          },
+          {
+            name: "wallets";
+            type: {
+              vec: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "stakingConfig";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "stakeMint";
+            type: "publicKey";
+          },
+          {
+            name: "totalStaked";
+            type: "u64";
+          },
+          {
+            name: "tierThresholds";
+            type: {
+              array: [
+                {
+                  defined: "TierThreshold";
+                },
+                3
+              ];
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "stakeAccount";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "owner";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "lockStart";
+            type: "i64";
+          },
+          {
+            name: "lockEnd";
+            type: "i64";
+          },
+          {
+            name: "tier";
+            type: {
+              defined: "StakeTier";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "teamLock";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "launchPool";
+            type: "publicKey";
+          },
+          {
+            name: "tokenAccount";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "withdrawn";
+            type: "u64";
+          },
+          {
+            name: "schedule";
+            type: {
+              defined: "VestingSchedule";
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "treasurer";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "launchPool";
+            type: "publicKey";
+          },
+          {
+            name: "tokenMint";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "reserved";
+            type: {
+              array: ["u8", 32];
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "userPool";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "currencyAmount";
+            type: "u64";
+          },
+          {
+            name: "claimed";
+            type: "u64";
+          },
+          {
+            name: "roundAmounts";
+            type: {
+              array: ["u64", 3];
+            };
+          },
+          {
+            name: "refunded";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "reserved";
+            type: {
+              array: ["u8", 32];
+            };
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "TokenMetadata";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "AllocationVoucher";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "buyer";
+            type: "publicKey";
+          },
+          {
+            name: "maxAmount";
+            type: "u64";
+          },
+          {
+            name: "expiry";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "LaunchPoolBumps";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "launchpoolBump";
+            type: "u8";
+          },
+          {
+            name: "treasurerBump";
+            type: "u8";
+          },
+          {
+            name: "vaultBump";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "NftGate";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "isEnabled";
+            type: "bool";
+          },
+          {
+            name: "collection";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "creators";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "singleUse";
+            type: "bool";
+          }
+        ];
+      };
+    },
+    {
+      name: "TierThreshold";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "minimumAmount";
+            type: "u64";
+          },
+          {
+            name: "minimumLockDuration";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "VestingSchedule";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "cliffDate";
+            type: "i64";
+          },
+          {
+            name: "endDate";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "ProceedsVesting";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "completionBps";
+            type: "u16";
+          },
+          {
+            name: "period";
+            type: "i64";
+          },
+          {
+            name: "periods";
+            type: "u16";
+          }
+        ];
+      };
+    },
+    {
+      name: "CurrencyType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "SOL";
+          },
+          {
+            name: "USDC";
+          }
+        ];
+      };
+    },
+    {
+      name: "LaunchPoolType";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "FairLaunch";
+          },
+          {
+            name: "WhiteList";
+          }
+        ];
+      };
+    },
+    {
+      name: "LaunchPoolState";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Pending";
+          },
+          {
+            name: "Active";
+          },
+          {
+            name: "Completed";
+          },
+          {
+            name: "Cancelled";
+          }
+        ];
+      };
+    },
+    {
+      name: "UnsoldPolicy";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "RefundToCreator";
+          },
+          {
+            name: "Burn";
+          },
+          {
+            name: "SendToLiquidity";
+          }
+        ];
+      };
+    },
+    {
+      name: "MilestoneState";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Voting";
+          },
+          {
+            name: "Executed";
+          },
+          {
+            name: "Rejected";
+          }
+        ];
+      };
+    },
+    {
+      name: "SaleRoundAccess";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Public";
+          },
+          {
+            name: "Whitelist";
+          }
+        ];
+      };
+    },
+    {
+      name: "StakeTier";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Unranked";
+          },
+          {
+            name: "Bronze";
+          },
+          {
+            name: "Silver";
+          },
+          {
+            name: "Gold";
+          }
+        ];
+      };
+    }
+  ];
+  events: [
+    {
+      name: "AdvanceSaleRoundEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "signer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "closedRound";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "currentRound";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "rolledOver";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "BuyTokenInRoundEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "buyer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "round";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "currencyAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "tokenAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "roundTokenAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "vaultAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "BuyTokenWithNativeEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "buyer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "currencyAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "tokenAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "vaultAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ClaimTokenEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "user";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "claimed";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CloseUserPoolEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "user";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "openPositions";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CloseLaunchPoolEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CompleteLaunchPoolEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "vaultAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "soldAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "liquiditySolAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "liquidityTokenAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ConfigureAllocationSignerEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "allocationSigner";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ConfigureLiquidityEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "ammPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "liquidityBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "listingRate";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "liquidityTokenReserve";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "lpUnlockDate";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ConfigureNftGateEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "collection";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "creators";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "singleUse";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ConfigureTierAllocationsEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tierCaps";
+          type: {
+            array: ["u64", 3];
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CreateLaunchPoolEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "creator";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "index";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "poolSize";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "rate";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "unlockDate";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "unsoldPolicy";
+          type: {
+            option: {
+              defined: "UnsoldPolicy";
+            };
+          };
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CreateSaleRoundEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "index";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "rate";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "allocation";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "startTime";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "endTime";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "access";
+          type: {
+            defined: "SaleRoundAccess";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "FinalizeUnsoldTokensEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "unsoldPolicy";
+          type: {
+            defined: "UnsoldPolicy";
+          };
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "treasurerAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WithdrawLpEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ExtendLpLockEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "unlockDate";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "LaunchPoolMigratedEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "payer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "fromVersion";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "toVersion";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "userPoolCount";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ConfigureGovernanceEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "quorumBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ProposeMilestoneEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "index";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "deadline";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "MilestoneState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "VoteMilestoneEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "voter";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "index";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "weight";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "approve";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ExecuteMilestoneEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "index";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "votesFor";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "votesAgainst";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "MilestoneState";
+          };
+          index: false;
+        },
+        {
+          name: "refundableAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ReclaimFundsEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "user";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ReferredBuyEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "buyer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referrer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "currencyAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "reward";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ReferralRewardClaimedEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referrer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RegisterReferrerEvent";
+      fields: [
+        {
+          name: "referrer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "wallet";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CreateReferralStatsEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referrer";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "isRegistered";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ConfigureReferralEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "referralBps";
+          type: "u16";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "InitializeStakingEvent";
+      fields: [
+        {
+          name: "stakingConfig";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "stakeMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tierThresholds";
+          type: {
+            array: [
+              {
+                defined: "TierThreshold";
+              },
+              3
+            ];
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "UpdateStakingTiersEvent";
+      fields: [
+        {
+          name: "stakingConfig";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tierThresholds";
+          type: {
+            array: [
+              {
+                defined: "TierThreshold";
+              },
+              3
+            ];
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "StakeEvent";
+      fields: [
+        {
+          name: "stakingConfig";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "user";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "staked";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "lockEnd";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "tier";
+          type: {
+            defined: "StakeTier";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "UnstakeEvent";
+      fields: [
+        {
+          name: "stakingConfig";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "user";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "staked";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "tier";
+          type: {
+            defined: "StakeTier";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "StartLaunchPoolEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tokenAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "teamLockAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ConfigureTeamLockEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "cliffDate";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "endDate";
+          type: "i64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WithdrawTeamTokensEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "withdrawn";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CreateTokenMintEvent";
+      fields: [
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "decimals";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "MintPoolSupplyEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "tokenMint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "liquidityAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "teamAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "authoritiesRevoked";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "ProposeAuthorityEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "pendingAuthority";
+          type: {
+            option: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AcceptAuthorityEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "AddWalletsToWhitelistEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "index";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "wallets";
+          type: {
+            vec: "publicKey";
+          };
+          index: false;
+        },
+        {
+          name: "walletCount";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "RemoveWalletFromWhitelistEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "index";
+          type: "u8";
+          index: false;
+        },
+        {
+          name: "wallet";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "walletCount";
+          type: "u32";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WithdrawProceedsEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "proceedsWithdrawn";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "WithdrawUnsoldTokensEvent";
+      fields: [
+        {
+          name: "launchPool";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "authority";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "burned";
+          type: "bool";
+          index: false;
+        },
+        {
+          name: "treasurerAmount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "status";
+          type: {
+            defined: "LaunchPoolState";
+          };
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "MutationForbidden";
+      msg: "The authority is not authorized to initialize the program";
+    },
+    {
+      code: 6001;
+      name: "InvalidInstruction";
+      msg: "Invalid instruction";
+    },
+    {
+      code: 6002;
+      name: "InvalidUnlockDate";
+      msg: "Invalid unlock date";
+    },
+    {
+      code: 6003;
+      name: "InvalidAuthority";
+      msg: "Invalid authority";
+    },
+    {
+      code: 6004;
+      name: "InvalidTokenMint";
+      msg: "Invalid token mint";
+    },
+    {
+      code: 6005;
+      name: "InvalidLaunchPoolStatus";
+      msg: "Invalid launch pool status";
+    },
+    {
+      code: 6006;
+      name: "InvalidCurrencyType";
+      msg: "Invalid currency type";
+    },
+    {
+      code: 6007;
+      name: "PoolNotEnough";
+      msg: "Pool not enough to buy";
+    },
+    {
+      code: 6008;
+      name: "InvalidAmount";
+      msg: "Invalid amount";
+    },
+    {
+      code: 6009;
+      name: "MaximumTokenAmountReached";
+      msg: "Maximum token amount reached";
+    },
+    {
+      code: 6010;
       name: "TimeLockNotExpired";
       msg: "Time lock not expired";
     },
     {
-      code: 6011;
-      name: "NoBump";
-      msg: "Cannot find treasurer account";
+      code: 6011;
+      name: "NoBump";
+      msg: "Cannot find treasurer account";
+    },
+    {
+      code: 6012;
+      name: "MinimumTokenAmountNotReached";
+      msg: "Minimum token amount not reached";
+    },
+    {
+      code: 6013;
+      name: "InvalidCreator";
+      msg: "Invalid creator";
+    },
+    {
+      code: 6014;
+      name: "PoolSizeRemainingNotEnough";
+      msg: "Pool size remaining not enough";
+    },
+    {
+      code: 6015;
+      name: "InvalidTreasurer";
+      msg: "Invalid treasurer";
+    },
+    {
+      code: 6016;
+      name: "InvalidVault";
+      msg: "Invalid vault";
+    },
+    {
+      code: 6017;
+      name: "InvalidLaunchPool";
+      msg: "Invalid launch pool";
+    },
+    {
+      code: 6018;
+      name: "WhitelistFulled";
+      msg: "White list is full";
+    },
+    {
+      code: 6019;
+      name: "WalletAlreadyAdded";
+      msg: "Wallet already added";
+    },
+    {
+      code: 6020;
+      name: "WalletNotInList";
+      msg: "Wallet not in list";
+    },
+    {
+      code: 6021;
+      name: "NumberCastError";
+      msg: "Unable to cast number into BigInt";
+    },
+    {
+      code: 6022;
+      name: "InvalidWhitelist";
+      msg: "Invalid whitelist";
+    },
+    {
+      code: 6023;
+      name: "InvalidLaunchPoolType";
+      msg: "Invalid launch pool type";
+    },
+    {
+      code: 6024;
+      name: "WalletsMustNotBeEmpty";
+      msg: "Wallets must not be empty";
+    },
+    {
+      code: 6025;
+      name: "WhitelistNotEnoughSpace";
+      msg: "Whitelist not enough space";
+    },
+    {
+      code: 6026;
+      name: "LaunchPoolAlreadyCompleted";
+      msg: "Launch pool already completed";
+    },
+    {
+      code: 6027;
+      name: "UserNotInWhiteList";
+      msg: "User not in whitelist";
+    },
+    {
+      code: 6028;
+      name: "Overflow";
+      msg: "Calculation overflow";
+    },
+    {
+      code: 6029;
+      name: "InvalidVestingPlan";
+      msg: "Invalid vesting plan";
+    },
+    {
+      code: 6030;
+      name: "InvalidScheduleSize";
+      msg: "Invalid schedule size";
+    },
+    {
+      code: 6031;
+      name: "InvalidSaleRound";
+      msg: "Invalid sale round";
+    },
+    {
+      code: 6032;
+      name: "TooManySaleRounds";
+      msg: "Too many sale rounds";
+    },
+    {
+      code: 6033;
+      name: "SaleRoundAllocationExceeded";
+      msg: "Sale round allocation exceeds pool size";
+    },
+    {
+      code: 6034;
+      name: "InvalidSaleRoundTime";
+      msg: "Invalid sale round time window";
+    },
+    {
+      code: 6035;
+      name: "SaleRoundNotOpen";
+      msg: "Sale round is not open";
+    },
+    {
+      code: 6036;
+      name: "SaleRoundAllocationNotEnough";
+      msg: "Sale round allocation not enough";
+    },
+    {
+      code: 6037;
+      name: "LaunchPoolHasSaleRounds";
+      msg: "Launch pool is sold in rounds";
+    },
+    {
+      code: 6038;
+      name: "InvalidStakeTiers";
+      msg: "Invalid stake tiers";
+    },
+    {
+      code: 6039;
+      name: "InvalidStakeMint";
+      msg: "Invalid stake mint";
+    },
+    {
+      code: 6040;
+      name: "InvalidStakeAccount";
+      msg: "Invalid stake account";
+    },
+    {
+      code: 6041;
+      name: "StakeTierRequired";
+      msg: "Stake tier required";
+    },
+    {
+      code: 6042;
+      name: "InvalidNftGate";
+      msg: "Invalid NFT gate";
+    },
+    {
+      code: 6043;
+      name: "NftRequired";
+      msg: "NFT required to buy";
+    },
+    {
+      code: 6044;
+      name: "InvalidNftAccount";
+      msg: "Invalid NFT account";
+    },
+    {
+      code: 6045;
+      name: "NftNotInCollection";
+      msg: "NFT not in allowed collection";
+    },
+    {
+      code: 6046;
+      name: "NftAlreadyUsed";
+      msg: "NFT already used";
+    },
+    {
+      code: 6047;
+      name: "AllocationVoucherRequired";
+      msg: "Allocation voucher required";
+    },
+    {
+      code: 6048;
+      name: "InvalidAllocationVoucher";
+      msg: "Invalid allocation voucher";
+    },
+    {
+      code: 6049;
+      name: "InvalidAllocationSigner";
+      msg: "Invalid allocation signer";
+    },
+    {
+      code: 6050;
+      name: "AllocationVoucherExpired";
+      msg: "Allocation voucher expired";
+    },
+    {
+      code: 6051;
+      name: "InvalidReferrer";
+      msg: "Invalid referrer";
+    },
+    {
+      code: 6052;
+      name: "InvalidBasisPoints";
+      msg: "Invalid basis points";
+    },
+    {
+      code: 6053;
+      name: "InvalidAmmPool";
+      msg: "Invalid AMM pool";
+    },
+    {
+      code: 6054;
+      name: "LiquidityAccountsRequired";
+      msg: "Liquidity accounts required";
+    },
+    {
+      code: 6055;
+      name: "InvalidLpLock";
+      msg: "Invalid LP lock";
+    },
+    {
+      code: 6056;
+      name: "InvalidTeamLock";
+      msg: "Invalid team lock";
+    },
+    {
+      code: 6057;
+      name: "LaunchPoolIsGoverned";
+      msg: "Launch pool releases funds by milestones";
+    },
+    {
+      code: 6058;
+      name: "LaunchPoolNotGoverned";
+      msg: "Launch pool is not governed";
+    },
+    {
+      code: 6059;
+      name: "InvalidMilestone";
+      msg: "Invalid milestone";
+    },
+    {
+      code: 6060;
+      name: "MilestoneVotingClosed";
+      msg: "Milestone voting is closed";
+    },
+    {
+      code: 6061;
+      name: "MilestoneVotingOpen";
+      msg: "Milestone voting is still open";
+    },
+    {
+      code: 6062;
+      name: "RefundNotAvailable";
+      msg: "Refund not available";
+    },
+    {
+      code: 6063;
+      name: "AlreadyRefunded";
+      msg: "Already refunded";
+    },
+    {
+      code: 6064;
+      name: "InvalidPendingAuthority";
+      msg: "Invalid pending authority";
+    },
+    {
+      code: 6065;
+      name: "PositionNotSettled";
+      msg: "Position not settled";
+    },
+    {
+      code: 6066;
+      name: "LaunchPoolNotSettled";
+      msg: "Launch pool not settled";
+    },
+    {
+      code: 6067;
+      name: "UnsoldTokensWithdrawn";
+      msg: "Unsold tokens already withdrawn";
+    },
+    {
+      code: 6068;
+      name: "TokenAccountRequired";
+      msg: "Token account required";
+    },
+    {
+      code: 6069;
+      name: "InvalidPoolMetadata";
+      msg: "Invalid pool metadata";
+    },
+    {
+      code: 6070;
+      name: "InvalidMintedSupply";
+      msg: "Minted supply does not match the launch pool";
+    },
+    {
+      code: 6071;
+      name: "TokenMetadataAccountsRequired";
+      msg: "Token metadata accounts required";
+    },
+    {
+      code: 6072;
+      name: "AlreadyClaimed";
+      msg: "Already claimed";
+    },
+    {
+      code: 6073;
+      name: "UnsoldPolicyNotDeclared";
+      msg: "Unsold policy not declared";
+    },
+    {
+      code: 6074;
+      name: "UnsoldPolicyDeclared";
+      msg: "Unsold policy declared at creation";
+    },
+    {
+      code: 6075;
+      name: "InvalidUserPool";
+      msg: "Invalid user pool";
+    }
+  ];
+};
+
+export const IDL: EncodeSolTeam3 = {
+  version: "0.1.0",
+  name: "encode_sol_team3",
+  instructions: [
+    {
+      name: "createNativePool",
+      accounts: [
+        {
+          name: "creatorRegistry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasurer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "unlockDate",
+          type: "i64",
+        },
+        {
+          name: "poolSize",
+          type: "u64",
+        },
+        {
+          name: "minimumTokenAmount",
+          type: "u64",
+        },
+        {
+          name: "maximumTokenAmount",
+          type: "u64",
+        },
+        {
+          name: "rate",
+          type: "u64",
+        },
+        {
+          name: "tokenMintDecimals",
+          type: "u8",
+        },
+        {
+          name: "proceedsVesting",
+          type: {
+            option: {
+              defined: "ProceedsVesting",
+            },
+          },
+        },
+        {
+          name: "unsoldPolicy",
+          type: {
+            option: {
+              defined: "UnsoldPolicy",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "startLaunchPool",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "sourceTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "treasurer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "teamLock",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "teamLockTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "buyTokenWithNative",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeAccount",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "nftTokenAccount",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "nftMetadata",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "nftUsage",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "instructionsSysvar",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "referralStats",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "completeLaunchPool",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "treasurer",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "treasury",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "ammProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "ammPool",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "ammLpMint",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "ammTokenReserve",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "lpLock",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "lpTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "liquidityEscrow",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "escrowTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "claimToken",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasurer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createSaleRound",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "saleRound",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "index",
+          type: "u8",
+        },
+        {
+          name: "rate",
+          type: "u64",
+        },
+        {
+          name: "minimumTokenAmount",
+          type: "u64",
+        },
+        {
+          name: "maximumTokenAmount",
+          type: "u64",
+        },
+        {
+          name: "startTime",
+          type: "i64",
+        },
+        {
+          name: "endTime",
+          type: "i64",
+        },
+        {
+          name: "allocation",
+          type: "u64",
+        },
+        {
+          name: "access",
+          type: {
+            defined: "SaleRoundAccess",
+          },
+        },
+      ],
+    },
+    {
+      name: "addWalletsToWhitelist",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "saleRound",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "whitelist",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "wallets",
+          type: {
+            vec: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "removeWalletFromWhitelist",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "saleRound",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "whitelist",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "wallet",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "buyTokenInRound",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "saleRound",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "whitelist",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeAccount",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "nftTokenAccount",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "nftMetadata",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "nftUsage",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "instructionsSysvar",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "referralStats",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "advanceSaleRound",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "currentRound",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nextRound",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "signer",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initializeStaking",
+      accounts: [
+        {
+          name: "stakingConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "programData",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "tierThresholds",
+          type: {
+            array: [
+              {
+                defined: "TierThreshold",
+              },
+              3,
+            ],
+          },
+        },
+      ],
+    },
+    {
+      name: "updateStakingTiers",
+      accounts: [
+        {
+          name: "stakingConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "tierThresholds",
+          type: {
+            array: [
+              {
+                defined: "TierThreshold",
+              },
+              3,
+            ],
+          },
+        },
+      ],
+    },
+    {
+      name: "stake",
+      accounts: [
+        {
+          name: "stakingConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+        {
+          name: "lockDuration",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "unstake",
+      accounts: [
+        {
+          name: "stakingConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "configureTierAllocations",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "tierCaps",
+          type: {
+            array: ["u64", 3],
+          },
+        },
+      ],
+    },
+    {
+      name: "configureNftGate",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "collection",
+          type: {
+            option: "publicKey",
+          },
+        },
+        {
+          name: "creators",
+          type: {
+            vec: "publicKey",
+          },
+        },
+        {
+          name: "singleUse",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "configureAllocationSigner",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "allocationSigner",
+          type: {
+            option: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "registerReferrer",
+      accounts: [
+        {
+          name: "referrer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "wallet",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createReferralStats",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "referralStats",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "referrer",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "userPool",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "wallet",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "configureReferral",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "referralBps",
+          type: "u16",
+        },
+      ],
+    },
+    {
+      name: "claimReferralReward",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "referralStats",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "wallet",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "configureLiquidity",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ammProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ammPool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "lpMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "lpLock",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lpLockTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "liquidityEscrow",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "escrowTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "liquidityBps",
+          type: "u16",
+        },
+        {
+          name: "listingRate",
+          type: "u64",
+        },
+        {
+          name: "liquidityTokenReserve",
+          type: "u64",
+        },
+        {
+          name: "lpUnlockDate",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "withdrawLp",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "lpLock",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "lpLockTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destination",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "extendLock",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "lpLock",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "unlockDate",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "withdrawProceeds",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "configureGovernance",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "quorumBps",
+          type: "u16",
+        },
+      ],
+    },
+    {
+      name: "proposeMilestone",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "milestone",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+        {
+          name: "deadline",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "voteMilestone",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "milestone",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "vote",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "voter",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "approve",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "executeMilestone",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "milestone",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "reclaimFunds",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "proposeAuthority",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "newAuthority",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "acceptAuthority",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasurer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newAuthority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeUserPool",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeLaunchPool",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasurer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "vault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "poolMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "teamLock",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "lpLock",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "creator",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "finalizeUnsoldTokens",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasurer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authorityTokenAccount",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "ammTokenReserve",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "withdrawUnsoldTokens",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasurer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destination",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "burn",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "configureTeamLock",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "teamLock",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "teamLockTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+        {
+          name: "cliffDate",
+          type: "i64",
+        },
+        {
+          name: "vestingDuration",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "withdrawTeamTokens",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "teamLock",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "teamLockTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "destination",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "updatePoolMetadata",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "poolMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "name",
+          type: "string",
+        },
+        {
+          name: "symbol",
+          type: "string",
+        },
+        {
+          name: "uri",
+          type: "string",
+        },
+        {
+          name: "website",
+          type: {
+            option: "string",
+          },
+        },
+        {
+          name: "socials",
+          type: {
+            vec: "string",
+          },
+        },
+        {
+          name: "contentHash",
+          type: {
+            array: ["u8", 32],
+          },
+        },
+      ],
+    },
+    {
+      name: "migrateLaunchPool",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasurer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createTokenMint",
+      accounts: [
+        {
+          name: "tokenMint",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "mintAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "decimals",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "mintPoolSupply",
+      accounts: [
+        {
+          name: "launchPool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasurer",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasury",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+          isOptional: true,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rent",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "eventAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "program",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "liquidityAmount",
+          type: "u64",
+        },
+        {
+          name: "teamAmount",
+          type: "u64",
+        },
+        {
+          name: "tokenMetadata",
+          type: {
+            option: {
+              defined: "TokenMetadata",
+            },
+          },
+        },
+        {
+          name: "revokeAuthorities",
+          type: "bool",
+        },
+      ],
+    },
+  ],
+  accounts: [
+    {
+      name: "creatorRegistry",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "poolCount",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "launchPool",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "unlockDate",
+            type: "i64",
+          },
+          {
+            name: "poolSize",
+            type: "u64",
+          },
+          {
+            name: "minimumTokenAmount",
+            type: "u64",
+          },
+          {
+            name: "maximumTokenAmount",
+            type: "u64",
+          },
+          {
+            name: "rate",
+            type: "u64",
+          },
+          {
+            name: "poolSizeRemaining",
+            type: "u64",
+          },
+          {
+            name: "tokenMint",
+            type: "publicKey",
+          },
+          {
+            name: "tokenMintDecimals",
+            type: "u8",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "vaultAmount",
+            type: "u64",
+          },
+          {
+            name: "isVesting",
+            type: "bool",
+          },
+          {
+            name: "currency",
+            type: {
+              defined: "CurrencyType",
+            },
+          },
+          {
+            name: "poolType",
+            type: {
+              defined: "LaunchPoolType",
+            },
+          },
+          {
+            name: "status",
+            type: {
+              defined: "LaunchPoolState",
+            },
+          },
+          {
+            name: "roundCount",
+            type: "u8",
+          },
+          {
+            name: "currentRound",
+            type: "u8",
+          },
+          {
+            name: "roundsAllocation",
+            type: "u64",
+          },
+          {
+            name: "isTiered",
+            type: "bool",
+          },
+          {
+            name: "tierCaps",
+            type: {
+              array: ["u64", 3],
+            },
+          },
+          {
+            name: "nftGate",
+            type: {
+              defined: "NftGate",
+            },
+          },
+          {
+            name: "allocationSigner",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "referralBps",
+            type: "u16",
+          },
+          {
+            name: "referralRewardTotal",
+            type: "u64",
+          },
+          {
+            name: "liquidityBps",
+            type: "u16",
+          },
+          {
+            name: "listingRate",
+            type: "u64",
+          },
+          {
+            name: "liquidityTokenReserve",
+            type: "u64",
+          },
+          {
+            name: "ammProgram",
+            type: "publicKey",
+          },
+          {
+            name: "ammPool",
+            type: "publicKey",
+          },
+          {
+            name: "liquiditySolAmount",
+            type: "u64",
+          },
+          {
+            name: "liquidityTokenAmount",
+            type: "u64",
+          },
+          {
+            name: "teamLockAmount",
+            type: "u64",
+          },
+          {
+            name: "proceedsVesting",
+            type: {
+              option: {
+                defined: "ProceedsVesting",
+              },
+            },
+          },
+          {
+            name: "proceedsWithdrawn",
+            type: "u64",
+          },
+          {
+            name: "completedAt",
+            type: "i64",
+          },
+          {
+            name: "isGoverned",
+            type: "bool",
+          },
+          {
+            name: "quorumBps",
+            type: "u16",
+          },
+          {
+            name: "milestoneCount",
+            type: "u8",
+          },
+          {
+            name: "hasOpenMilestone",
+            type: "bool",
+          },
+          {
+            name: "milestoneReleased",
+            type: "u64",
+          },
+          {
+            name: "isRefunding",
+            type: "bool",
+          },
+          {
+            name: "refundableAmount",
+            type: "u64",
+          },
+          {
+            name: "creator",
+            type: "publicKey",
+          },
+          {
+            name: "pendingAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "index",
+            type: "u64",
+          },
+          {
+            name: "bumps",
+            type: {
+              defined: "LaunchPoolBumps",
+            },
+          },
+          {
+            name: "openPositions",
+            type: "u32",
+          },
+          {
+            name: "unsoldWithdrawn",
+            type: "bool",
+          },
+          {
+            name: "unsoldPolicy",
+            type: {
+              option: {
+                defined: "UnsoldPolicy",
+              },
+            },
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "mintedSupply",
+            type: "u64",
+          },
+          {
+            name: "refundedSoldAmount",
+            type: "u64",
+          },
+          {
+            name: "refundedTotal",
+            type: "u64",
+          },
+          {
+            name: "referralRewardClaimed",
+            type: "u64",
+          },
+          {
+            name: "claimedSoldAmount",
+            type: "u64",
+          },
+          {
+            name: "refundSoldAmount",
+            type: "u64",
+          },
+          {
+            name: "reserved",
+            type: {
+              array: ["u8", 79],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "lpLock",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "launchPool",
+            type: "publicKey",
+          },
+          {
+            name: "lpMint",
+            type: "publicKey",
+          },
+          {
+            name: "lpTokenAccount",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "unlockDate",
+            type: "i64",
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "milestone",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "launchPool",
+            type: "publicKey",
+          },
+          {
+            name: "index",
+            type: "u8",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "deadline",
+            type: "i64",
+          },
+          {
+            name: "votesFor",
+            type: "u64",
+          },
+          {
+            name: "votesAgainst",
+            type: "u64",
+          },
+          {
+            name: "status",
+            type: {
+              defined: "MilestoneState",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "vote",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "milestone",
+            type: "publicKey",
+          },
+          {
+            name: "voter",
+            type: "publicKey",
+          },
+          {
+            name: "weight",
+            type: "u64",
+          },
+          {
+            name: "approve",
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "nftUsage",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "launchPool",
+            type: "publicKey",
+          },
+          {
+            name: "nftMint",
+            type: "publicKey",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "poolMetadata",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "launchPool",
+            type: "publicKey",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "symbol",
+            type: "string",
+          },
+          {
+            name: "uri",
+            type: "string",
+          },
+          {
+            name: "website",
+            type: {
+              option: "string",
+            },
+          },
+          {
+            name: "socials",
+            type: {
+              vec: "string",
+            },
+          },
+          {
+            name: "contentHash",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "referrer",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "wallet",
+            type: "publicKey",
+          },
+          {
+            name: "registeredAt",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "referralStats",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "launchPool",
+            type: "publicKey",
+          },
+          {
+            name: "referrer",
+            type: "publicKey",
+          },
+          {
+            name: "referredCount",
+            type: "u32",
+          },
+          {
+            name: "referredAmount",
+            type: "u64",
+          },
+          {
+            name: "referredCurrencyAmount",
+            type: "u64",
+          },
+          {
+            name: "reward",
+            type: "u64",
+          },
+          {
+            name: "claimed",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "saleRound",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "launchPool",
+            type: "publicKey",
+          },
+          {
+            name: "index",
+            type: "u8",
+          },
+          {
+            name: "rate",
+            type: "u64",
+          },
+          {
+            name: "minimumTokenAmount",
+            type: "u64",
+          },
+          {
+            name: "maximumTokenAmount",
+            type: "u64",
+          },
+          {
+            name: "startTime",
+            type: "i64",
+          },
+          {
+            name: "endTime",
+            type: "i64",
+          },
+          {
+            name: "allocation",
+            type: "u64",
+          },
+          {
+            name: "sold",
+            type: "u64",
+          },
+          {
+            name: "access",
+            type: {
+              defined: "SaleRoundAccess",
+            },
+          },
+          {
+            name: "isClosed",
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "whitelist",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "saleRound",
+            type: "publicKey",
+          },
+          {
+            name: "wallets",
+            type: {
+              vec: "publicKey",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "stakingConfig",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "stakeMint",
+            type: "publicKey",
+          },
+          {
+            name: "totalStaked",
+            type: "u64",
+          },
+          {
+            name: "tierThresholds",
+            type: {
+              array: [
+                {
+                  defined: "TierThreshold",
+                },
+                3,
+              ],
+            },
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "stakeAccount",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "owner",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "lockStart",
+            type: "i64",
+          },
+          {
+            name: "lockEnd",
+            type: "i64",
+          },
+          {
+            name: "tier",
+            type: {
+              defined: "StakeTier",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "teamLock",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "launchPool",
+            type: "publicKey",
+          },
+          {
+            name: "tokenAccount",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "withdrawn",
+            type: "u64",
+          },
+          {
+            name: "schedule",
+            type: {
+              defined: "VestingSchedule",
+            },
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "treasurer",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "launchPool",
+            type: "publicKey",
+          },
+          {
+            name: "tokenMint",
+            type: "publicKey",
+          },
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "reserved",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "userPool",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "currencyAmount",
+            type: "u64",
+          },
+          {
+            name: "claimed",
+            type: "u64",
+          },
+          {
+            name: "roundAmounts",
+            type: {
+              array: ["u64", 3],
+            },
+          },
+          {
+            name: "refunded",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "reserved",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "TokenMetadata",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "symbol",
+            type: "string",
+          },
+          {
+            name: "uri",
+            type: "string",
+          },
+        ],
+      },
+    },
+    {
+      name: "AllocationVoucher",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "pool",
+            type: "publicKey",
+          },
+          {
+            name: "buyer",
+            type: "publicKey",
+          },
+          {
+            name: "maxAmount",
+            type: "u64",
+          },
+          {
+            name: "expiry",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "LaunchPoolBumps",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "launchpoolBump",
+            type: "u8",
+          },
+          {
+            name: "treasurerBump",
+            type: "u8",
+          },
+          {
+            name: "vaultBump",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "NftGate",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "isEnabled",
+            type: "bool",
+          },
+          {
+            name: "collection",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "creators",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "singleUse",
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "TierThreshold",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "minimumAmount",
+            type: "u64",
+          },
+          {
+            name: "minimumLockDuration",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "VestingSchedule",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "cliffDate",
+            type: "i64",
+          },
+          {
+            name: "endDate",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "ProceedsVesting",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "completionBps",
+            type: "u16",
+          },
+          {
+            name: "period",
+            type: "i64",
+          },
+          {
+            name: "periods",
+            type: "u16",
+          },
+        ],
+      },
+    },
+    {
+      name: "CurrencyType",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "SOL",
+          },
+          {
+            name: "USDC",
+          },
+        ],
+      },
+    },
+    {
+      name: "LaunchPoolType",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "FairLaunch",
+          },
+          {
+            name: "WhiteList",
+          },
+        ],
+      },
+    },
+    {
+      name: "LaunchPoolState",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Pending",
+          },
+          {
+            name: "Active",
+          },
+          {
+            name: "Completed",
+          },
+          {
+            name: "Cancelled",
+          },
+        ],
+      },
+    },
+    {
+      name: "UnsoldPolicy",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "RefundToCreator",
+          },
+          {
+            name: "Burn",
+          },
+          {
+            name: "SendToLiquidity",
+          },
+        ],
+      },
+    },
+    {
+      name: "MilestoneState",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Voting",
+          },
+          {
+            name: "Executed",
+          },
+          {
+            name: "Rejected",
+          },
+        ],
+      },
+    },
+    {
+      name: "SaleRoundAccess",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Public",
+          },
+          {
+            name: "Whitelist",
+          },
+        ],
+      },
+    },
+    {
+      name: "StakeTier",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Unranked",
+          },
+          {
+            name: "Bronze",
+          },
+          {
+            name: "Silver",
+          },
+          {
+            name: "Gold",
+          },
+        ],
+      },
+    },
+  ],
+  events: [
+    {
+      name: "AdvanceSaleRoundEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "signer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "closedRound",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "currentRound",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "rolledOver",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "BuyTokenInRoundEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "round",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "currencyAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "tokenAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "roundTokenAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "vaultAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "BuyTokenWithNativeEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "currencyAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "tokenAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "vaultAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ClaimTokenEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "user",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "claimed",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "CloseUserPoolEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "user",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "openPositions",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "CloseLaunchPoolEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "CompleteLaunchPoolEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "vaultAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "soldAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "liquiditySolAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "liquidityTokenAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "ConfigureAllocationSignerEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "allocationSigner",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6012;
-      name: "MinimumTokenAmountNotReached";
-      msg: "Minimum token amount not reached";
+      name: "ConfigureLiquidityEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "ammPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "liquidityBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "listingRate",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "liquidityTokenReserve",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "lpUnlockDate",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6013;
-      name: "InvalidCreator";
-      msg: "Invalid creator";
+      name: "ConfigureNftGateEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "collection",
+          type: {
+            option: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "creators",
+          type: {
+            vec: "publicKey",
+          },
+          index: false,
+        },
+        {
+          name: "singleUse",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6014;
-      name: "PoolSizeRemainingNotEnough";
-      msg: "Pool size remaining not enough";
+      name: "ConfigureTierAllocationsEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tierCaps",
+          type: {
+            array: ["u64", 3],
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6015;
-      name: "InvalidTreasurer";
-      msg: "Invalid treasurer";
+      name: "CreateLaunchPoolEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "creator",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "index",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "poolSize",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "rate",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "unlockDate",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "unsoldPolicy",
+          type: {
+            option: {
+              defined: "UnsoldPolicy",
+            },
+          },
+          index: false,
+        },
+        {
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6016;
-      name: "InvalidVault";
-      msg: "Invalid vault";
+      name: "CreateSaleRoundEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "index",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "rate",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "allocation",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "startTime",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "endTime",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "access",
+          type: {
+            defined: "SaleRoundAccess",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6017;
-      name: "InvalidLaunchPool";
-      msg: "Invalid launch pool";
+      name: "FinalizeUnsoldTokensEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "unsoldPolicy",
+          type: {
+            defined: "UnsoldPolicy",
+          },
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "treasurerAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6018;
-      name: "WhitelistFulled";
-      msg: "White list is full";
+      name: "WithdrawLpEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6019;
-      name: "WalletAlreadyAdded";
-      msg: "Wallet already added";
+      name: "ExtendLpLockEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "unlockDate",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6020;
-      name: "WalletNotInList";
-      msg: "Wallet not in list";
+      name: "LaunchPoolMigratedEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "payer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "fromVersion",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "toVersion",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "userPoolCount",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6021;
-      name: "NumberCastError";
-      msg: "Unable to cast number into BigInt";
+      name: "ConfigureGovernanceEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "quorumBps",
+          type: "u16",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6022;
-      name: "InvalidWhitelist";
-      msg: "Invalid whitelist";
+      name: "ProposeMilestoneEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "index",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "deadline",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "status",
+          type: {
+            defined: "MilestoneState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6023;
-      name: "InvalidLaunchPoolType";
-      msg: "Invalid launch pool type";
+      name: "VoteMilestoneEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "voter",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "index",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "weight",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "approve",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6024;
-      name: "WalletsMustNotBeEmpty";
-      msg: "Wallets must not be empty";
+      name: "ExecuteMilestoneEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "index",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "votesFor",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "votesAgainst",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "status",
+          type: {
+            defined: "MilestoneState",
+          },
+          index: false,
+        },
+        {
+          name: "refundableAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6025;
-      name: "WhitelistNotEnoughSpace";
-      msg: "Whitelist not enough space";
+      name: "ReclaimFundsEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "user",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6026;
-      name: "LaunchPoolAlreadyCompleted";
-      msg: "Launch pool already completed";
+      name: "ReferredBuyEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "buyer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "referrer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "currencyAmount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "reward",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6027;
-      name: "UserNotInWhiteList";
-      msg: "User not in whitelist";
+      name: "ReferralRewardClaimedEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "referrer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6028;
-      name: "Overflow";
-      msg: "Calculation overflow";
+      name: "RegisterReferrerEvent",
+      fields: [
+        {
+          name: "referrer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "wallet",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6029;
-      name: "InvalidVestingPlan";
-      msg: "Invalid vesting plan";
+      name: "CreateReferralStatsEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "referrer",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "isRegistered",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      code: 6030;
-      name: "InvalidScheduleSize";
-      msg: "Invalid schedule size";
-    }
-  ];
-};
-
-export const IDL: EncodeSolTeam3 = {
-  version: "0.1.0",
-  name: "encode_sol_team3",
-  instructions: [
-    {
-      name: "createNativePool",
-      accounts: [
+      name: "ConfigureReferralEvent",
+      fields: [
         {
           name: "launchPool",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenMint",
-          isMut: false,
-          isSigner: false,
+          name: "authority",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "treasurer",
-          isMut: true,
-          isSigner: false,
+          name: "referralBps",
+          type: "u16",
+          index: false,
         },
         {
-          name: "treasury",
-          isMut: true,
-          isSigner: false,
+          name: "timestamp",
+          type: "i64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "InitializeStakingEvent",
+      fields: [
         {
-          name: "authority",
-          isMut: true,
-          isSigner: true,
+          name: "stakingConfig",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "authority",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "stakeMint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "tierThresholds",
+          type: {
+            array: [
+              {
+                defined: "TierThreshold",
+              },
+              3,
+            ],
+          },
+          index: false,
         },
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
+          name: "timestamp",
+          type: "i64",
+          index: false,
         },
       ],
-      args: [
+    },
+    {
+      name: "UpdateStakingTiersEvent",
+      fields: [
         {
-          name: "unlockDate",
+          name: "stakingConfig",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "tierThresholds",
+          type: {
+            array: [
+              {
+                defined: "TierThreshold",
+              },
+              3,
+            ],
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
           type: "i64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "StakeEvent",
+      fields: [
         {
-          name: "poolSize",
-          type: "u64",
+          name: "stakingConfig",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "minimumTokenAmount",
-          type: "u64",
+          name: "user",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "maximumTokenAmount",
+          name: "amount",
           type: "u64",
+          index: false,
         },
         {
-          name: "rate",
+          name: "staked",
           type: "u64",
+          index: false,
         },
         {
-          name: "tokenMintDecimals",
-          type: "u8",
+          name: "lockEnd",
+          type: "i64",
+          index: false,
+        },
+        {
+          name: "tier",
+          type: {
+            defined: "StakeTier",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
         },
       ],
     },
     {
-      name: "startLaunchPool",
-      accounts: [
+      name: "UnstakeEvent",
+      fields: [
         {
-          name: "launchPool",
-          isMut: true,
-          isSigner: false,
+          name: "stakingConfig",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenMint",
-          isMut: false,
-          isSigner: false,
+          name: "user",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "sourceTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "amount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "treasurer",
-          isMut: true,
-          isSigner: false,
+          name: "staked",
+          type: "u64",
+          index: false,
         },
         {
-          name: "treasury",
-          isMut: true,
-          isSigner: false,
+          name: "tier",
+          type: {
+            defined: "StakeTier",
+          },
+          index: false,
         },
         {
-          name: "authority",
-          isMut: true,
-          isSigner: true,
+          name: "timestamp",
+          type: "i64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "StartLaunchPoolEvent",
+      fields: [
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "authority",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
+          name: "tokenAmount",
+          type: "u64",
+          index: false,
         },
-      ],
-      args: [],
-    },
-    {
-      name: "buyTokenWithNative",
-      accounts: [
         {
-          name: "launchPool",
-          isMut: true,
-          isSigner: false,
+          name: "teamLockAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "tokenMint",
-          isMut: false,
-          isSigner: false,
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
         },
         {
-          name: "userPool",
-          isMut: true,
-          isSigner: false,
+          name: "timestamp",
+          type: "i64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "ConfigureTeamLockEvent",
+      fields: [
         {
-          name: "vault",
-          isMut: true,
-          isSigner: false,
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+          name: "authority",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "amount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "cliffDate",
+          type: "i64",
+          index: false,
         },
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
+          name: "endDate",
+          type: "i64",
+          index: false,
         },
-      ],
-      args: [
         {
-          name: "amount",
-          type: "u64",
+          name: "timestamp",
+          type: "i64",
+          index: false,
         },
       ],
     },
     {
-      name: "completeLaunchPool",
-      accounts: [
+      name: "WithdrawTeamTokensEvent",
+      fields: [
         {
           name: "launchPool",
-          isMut: true,
-          isSigner: false,
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenMint",
-          isMut: false,
-          isSigner: false,
+          name: "authority",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "authority",
-          isMut: true,
-          isSigner: true,
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "withdrawn",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
         },
       ],
-      args: [],
     },
     {
-      name: "claimToken",
-      accounts: [
+      name: "CreateTokenMintEvent",
+      fields: [
         {
-          name: "launchPool",
-          isMut: true,
-          isSigner: false,
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "tokenMint",
-          isMut: false,
-          isSigner: false,
+          name: "authority",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "treasurer",
-          isMut: true,
-          isSigner: false,
+          name: "decimals",
+          type: "u8",
+          index: false,
         },
         {
-          name: "treasury",
-          isMut: true,
-          isSigner: false,
+          name: "timestamp",
+          type: "i64",
+          index: false,
         },
+      ],
+    },
+    {
+      name: "MintPoolSupplyEvent",
+      fields: [
         {
-          name: "userPool",
-          isMut: true,
-          isSigner: false,
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "userTokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "tokenMint",
+          type: "publicKey",
+          index: false,
         },
         {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+          name: "amount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "liquidityAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "teamAmount",
+          type: "u64",
+          index: false,
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "authoritiesRevoked",
+          type: "bool",
+          index: false,
         },
         {
-          name: "rent",
-          isMut: false,
-          isSigner: false,
+          name: "timestamp",
+          type: "i64",
+          index: false,
         },
       ],
-      args: [],
-    },
-  ],
-  accounts: [
-    {
-      name: "launchPool",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "unlockDate",
-            type: "i64",
-          },
-          {
-            name: "poolSize",
-            type: "u64",
-          },
-          {
-            name: "minimumTokenAmount",
-            type: "u64",
-          },
-          {
-            name: "maximumTokenAmount",
-            type: "u64",
-          },
-          {
-            name: "rate",
-            type: "u64",
-          },
-          {
-            name: "poolSizeRemaining",
-            type: "u64",
-          },
-          {
-            name: "tokenMint",
-            type: "publicKey",
-          },
-          {
-            name: "tokenMintDecimals",
-            type: "u8",
-          },
-          {
-            name: "authority",
-            type: "publicKey",
-          },
-          {
-            name: "vaultAmount",
-            type: "u64",
-          },
-          {
-            name: "isVesting",
-            type: "bool",
-          },
-          {
-            name: "currency",
-            type: {
-              defined: "CurrencyType",
-            },
-          },
-          {
-            name: "poolType",
-            type: {
-              defined: "LaunchPoolType",
-            },
-          },
-          {
-            name: "status",
-            type: {
-              defined: "LaunchPoolState",
-            },
-          },
-        ],
-      },
-    },
-    {
-      name: "treasurer",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "authority",
-            type: "publicKey",
-          },
-          {
-            name: "launchPool",
-            type: "publicKey",
-          },
-          {
-            name: "tokenMint",
-            type: "publicKey",
-          },
-          {
-            name: "amount",
-            type: "u64",
-          },
-        ],
-      },
-    },
-    {
-      name: "userPool",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "amount",
-            type: "u64",
-          },
-          {
-            name: "currencyAmount",
-            type: "u64",
-          },
-          {
-            name: "claimed",
-            type: "u64",
-          },
-        ],
-      },
     },
-  ],
-  types: [
     {
-      name: "LaunchPoolBumps",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "launchpoolBump",
-            type: "u8",
-          },
-          {
-            name: "treasurerBump",
-            type: "u8",
-          },
-          {
-            name: "vaultBump",
-            type: "u8",
+      name: "ProposeAuthorityEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "pendingAuthority",
+          type: {
+            option: "publicKey",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "CurrencyType",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "SOL",
-          },
-          {
-            name: "USDC",
-          },
-        ],
-      },
+      name: "AcceptAuthorityEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "LaunchPoolType",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "FairLaunch",
-          },
-          {
-            name: "WhiteList",
+      name: "AddWalletsToWhitelistEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "index",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "wallets",
+          type: {
+            vec: "publicKey",
           },
-        ],
-      },
+          index: false,
+        },
+        {
+          name: "walletCount",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
     {
-      name: "LaunchPoolState",
-      type: {
-        kind: "enum",
-        variants: [
-          {
-            name: "Pending",
-          },
-          {
-            name: "Active",
-          },
-          {
-            name: "Completed",
-          },
-          {
-            name: "Cancelled",
-          },
-        ],
-      },
+      name: "RemoveWalletFromWhitelistEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "index",
+          type: "u8",
+          index: false,
+        },
+        {
+          name: "wallet",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "walletCount",
+          type: "u32",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
     },
-  ],
-  events: [
     {
-      name: "BuyTokenWithNativeEvent",
+      name: "WithdrawProceedsEvent",
       fields: [
         {
-          name: "buyer",
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
           type: "publicKey",
           index: false,
         },
@@ -1101,15 +9965,64 @@ export const IDL: EncodeSolTeam3 = {
           index: false,
         },
         {
-          name: "tokenAmount",
+          name: "proceedsWithdrawn",
           type: "u64",
           index: false,
         },
         {
-          name: "vaultAmount",
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "WithdrawUnsoldTokensEvent",
+      fields: [
+        {
+          name: "launchPool",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "authority",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "burned",
+          type: "bool",
+          index: false,
+        },
+        {
+          name: "treasurerAmount",
           type: "u64",
           index: false,
         },
+        {
+          name: "status",
+          type: {
+            defined: "LaunchPoolState",
+          },
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
       ],
     },
   ],
@@ -1269,5 +10182,230 @@ export const IDL: EncodeSolTeam3 = {
       name: "InvalidScheduleSize",
       msg: "Invalid schedule size",
     },
+    {
+      code: 6031,
+      name: "InvalidSaleRound",
+      msg: "Invalid sale round",
+    },
+    {
+      code: 6032,
+      name: "TooManySaleRounds",
+      msg: "Too many sale rounds",
+    },
+    {
+      code: 6033,
+      name: "SaleRoundAllocationExceeded",
+      msg: "Sale round allocation exceeds pool size",
+    },
+    {
+      code: 6034,
+      name: "InvalidSaleRoundTime",
+      msg: "Invalid sale round time window",
+    },
+    {
+      code: 6035,
+      name: "SaleRoundNotOpen",
+      msg: "Sale round is not open",
+    },
+    {
+      code: 6036,
+      name: "SaleRoundAllocationNotEnough",
+      msg: "Sale round allocation not enough",
+    },
+    {
+      code: 6037,
+      name: "LaunchPoolHasSaleRounds",
+      msg: "Launch pool is sold in rounds",
+    },
+    {
+      code: 6038,
+      name: "InvalidStakeTiers",
+      msg: "Invalid stake tiers",
+    },
+    {
+      code: 6039,
+      name: "InvalidStakeMint",
+      msg: "Invalid stake mint",
+    },
+    {
+      code: 6040,
+      name: "InvalidStakeAccount",
+      msg: "Invalid stake account",
+    },
+    {
+      code: 6041,
+      name: "StakeTierRequired",
+      msg: "Stake tier required",
+    },
+    {
+      code: 6042,
+      name: "InvalidNftGate",
+      msg: "Invalid NFT gate",
+    },
+    {
+      code: 6043,
+      name: "NftRequired",
+      msg: "NFT required to buy",
+    },
+    {
+      code: 6044,
+      name: "InvalidNftAccount",
+      msg: "Invalid NFT account",
+    },
+    {
+      code: 6045,
+      name: "NftNotInCollection",
+      msg: "NFT not in allowed collection",
+    },
+    {
+      code: 6046,
+      name: "NftAlreadyUsed",
+      msg: "NFT already used",
+    },
+    {
+      code: 6047,
+      name: "AllocationVoucherRequired",
+      msg: "Allocation voucher required",
+    },
+    {
+      code: 6048,
+      name: "InvalidAllocationVoucher",
+      msg: "Invalid allocation voucher",
+    },
+    {
+      code: 6049,
+      name: "InvalidAllocationSigner",
+      msg: "Invalid allocation signer",
+    },
+    {
+      code: 6050,
+      name: "AllocationVoucherExpired",
+      msg: "Allocation voucher expired",
+    },
+    {
+      code: 6051,
+      name: "InvalidReferrer",
+      msg: "Invalid referrer",
+    },
+    {
+      code: 6052,
+      name: "InvalidBasisPoints",
+      msg: "Invalid basis points",
+    },
+    {
+      code: 6053,
+      name: "InvalidAmmPool",
+      msg: "Invalid AMM pool",
+    },
+    {
+      code: 6054,
+      name: "LiquidityAccountsRequired",
+      msg: "Liquidity accounts required",
+    },
+    {
+      code: 6055,
+      name: "InvalidLpLock",
+      msg: "Invalid LP lock",
+    },
+    {
+      code: 6056,
+      name: "InvalidTeamLock",
+      msg: "Invalid team lock",
+    },
+    {
+      code: 6057,
+      name: "LaunchPoolIsGoverned",
+      msg: "Launch pool releases funds by milestones",
+    },
+    {
+      code: 6058,
+      name: "LaunchPoolNotGoverned",
+      msg: "Launch pool is not governed",
+    },
+    {
+      code: 6059,
+      name: "InvalidMilestone",
+      msg: "Invalid milestone",
+    },
+    {
+      code: 6060,
+      name: "MilestoneVotingClosed",
+      msg: "Milestone voting is closed",
+    },
+    {
+      code: 6061,
+      name: "MilestoneVotingOpen",
+      msg: "Milestone voting is still open",
+    },
+    {
+      code: 6062,
+      name: "RefundNotAvailable",
+      msg: "Refund not available",
+    },
+    {
+      code: 6063,
+      name: "AlreadyRefunded",
+      msg: "Already refunded",
+    },
+    {
+      code: 6064,
+      name: "InvalidPendingAuthority",
+      msg: "Invalid pending authority",
+    },
+    {
+      code: 6065,
+      name: "PositionNotSettled",
+      msg: "Position not settled",
+    },
+    {
+      code: 6066,
+      name: "LaunchPoolNotSettled",
+      msg: "Launch pool not settled",
+    },
+    {
+      code: 6067,
+      name: "UnsoldTokensWithdrawn",
+      msg: "Unsold tokens already withdrawn",
+    },
+    {
+      code: 6068,
+      name: "TokenAccountRequired",
+      msg: "Token account required",
+    },
+    {
+      code: 6069,
+      name: "InvalidPoolMetadata",
+      msg: "Invalid pool metadata",
+    },
+    {
+      code: 6070,
+      name: "InvalidMintedSupply",
+      msg: "Minted supply does not match the launch pool",
+    },
+    {
+      code: 6071,
+      name: "TokenMetadataAccountsRequired",
+      msg: "Token metadata accounts required",
+    },
+    {
+      code: 6072,
+      name: "AlreadyClaimed",
+      msg: "Already claimed",
+    },
+    {
+      code: 6073,
+      name: "UnsoldPolicyNotDeclared",
+      msg: "Unsold policy not declared",
+    },
+    {
+      code: 6074,
+      name: "UnsoldPolicyDeclared",
+      msg: "Unsold policy declared at creation",
+    },
+    {
+      code: 6075,
+      name: "InvalidUserPool",
+      msg: "Invalid user pool",
+    },
   ],
 };
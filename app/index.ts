@@ -6,6 +6,8 @@ import { EncodeSolTeam3 } from "./artifacts/encode_sol_team3";
 import dayjs from "dayjs";
 import {
   createTokenMint,
+  findCreatorRegistryAccount,
+  findEventAuthorityAccount,
   findLaunchPoolAccount,
   findMintTokenAccount,
   findTreasurerAccount,
@@ -48,17 +50,47 @@ const RPC_ENDPOINT = "https://api.devnet.solana.com";
 
   const mint = await createTokenMint(creator_wallet, creator_wallet.publicKey);
 
-  await createNativeFairlaunchPool(program, creator_wallet, mint);
-  await startLaunchPool(program, creator_wallet, mint);
-  await buyToken(program, creator_wallet.publicKey, mint, bob_wallet, 50);
-  await completeLaunchPool(program, creator_wallet, mint);
-  await claimToken(program, creator_wallet.publicKey, mint, bob_wallet);
+  const pool_index = await nextPoolIndex(program, creator_wallet.publicKey);
+
+  await createNativeFairlaunchPool(program, creator_wallet, mint, pool_index);
+  await startLaunchPool(program, creator_wallet, mint, pool_index);
+  await buyToken(
+    program,
+    creator_wallet.publicKey,
+    mint,
+    pool_index,
+    bob_wallet,
+    50
+  );
+  await completeLaunchPool(program, creator_wallet, mint, pool_index);
+  await claimToken(
+    program,
+    creator_wallet.publicKey,
+    mint,
+    pool_index,
+    bob_wallet
+  );
 })();
 
+export async function nextPoolIndex(
+  program: Program<EncodeSolTeam3>,
+  creator: PublicKey
+) {
+  const [creator_registry] = findCreatorRegistryAccount(
+    creator,
+    program.programId
+  );
+  const registry = await program.account.creatorRegistry.fetchNullable(
+    creator_registry
+  );
+  return registry ? registry.poolCount : new BN(0);
+}
+
 export async function createNativeFairlaunchPool(
   program: Program<EncodeSolTeam3>,
   creator: Wallet,
   mint: PublicKey,
+  pool_index: BN,
   max = 100,
   min = 50,
   rate = new BN(50)
@@ -67,9 +99,13 @@ export async function createNativeFairlaunchPool(
   const pool_size = new BN(100 * LAMPORTS_PER_SOL);
   const minimum_token_amount = new BN(min * LAMPORTS_PER_SOL);
   const maximum_token_amount = new BN(max * LAMPORTS_PER_SOL);
+  const [creator_registry] = findCreatorRegistryAccount(
+    creator.publicKey,
+    PROGRAM_ID
+  );
   const [launch_pool] = findLaunchPoolAccount(
     creator.publicKey,
-    mint,
+    pool_index,
     PROGRAM_ID
   );
   console.log(
@@ -87,9 +123,12 @@ export async function createNativeFairlaunchPool(
       minimum_token_amount,
       maximum_token_amount,
       rate,
-      9
+      9,
+      null,
+      { refundToCreator: {} }
     )
     .accounts({
+      creatorRegistry: creator_registry,
       launchPool: launch_pool,
       authority: creator.publicKey,
       tokenMint: mint,
@@ -99,6 +138,8 @@ export async function createNativeFairlaunchPool(
       systemProgram: web3.SystemProgram.programId,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      eventAuthority: findEventAuthorityAccount(PROGRAM_ID)[0],
+      program: PROGRAM_ID,
     })
     .signers([creator.payer])
     .rpc();
@@ -110,11 +151,12 @@ export async function createNativeFairlaunchPool(
 export async function startLaunchPool(
   program: Program<EncodeSolTeam3>,
   creator: Wallet,
-  mint: PublicKey
+  mint: PublicKey,
+  pool_index: BN
 ) {
   const [launch_pool] = findLaunchPoolAccount(
     creator.publicKey,
-    mint,
+    pool_index,
     program.programId
   );
   const source_token_account = await findMintTokenAccount(
@@ -140,10 +182,14 @@ export async function startLaunchPool(
       sourceTokenAccount: source_token_account,
       treasurer: treasurer,
       treasury: treasury,
+      teamLock: null,
+      teamLockTokenAccount: null,
       authority: creator.publicKey,
       tokenProgram: TOKEN_PROGRAM_ID,
       rent: web3.SYSVAR_RENT_PUBKEY,
       systemProgram: web3.SystemProgram.programId,
+      eventAuthority: findEventAuthorityAccount(program.programId)[0],
+      program: program.programId,
     })
     .signers([creator.payer])
     .rpc();
@@ -155,10 +201,15 @@ export async function buyToken(
   program: Program<EncodeSolTeam3>,
   creator: PublicKey,
   mint: PublicKey,
+  pool_index: BN,
   buyer: Wallet,
   amount: number
 ) {
-  const [launch_pool] = findLaunchPoolAccount(creator, mint, program.programId);
+  const [launch_pool] = findLaunchPoolAccount(
+    creator,
+    pool_index,
+    program.programId
+  );
 
   const [user_pool] = findUserPoolAccount(
     buyer.publicKey,
@@ -181,9 +232,17 @@ export async function buyToken(
       user: buyer.publicKey,
       vault,
       tokenMint: mint,
+      stakeAccount: null,
+      nftTokenAccount: null,
+      nftMetadata: null,
+      nftUsage: null,
+      instructionsSysvar: null,
+      referralStats: null,
       tokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: web3.SystemProgram.programId,
       rent: web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityAccount(program.programId)[0],
+      program: program.programId,
     })
     .signers([buyer.payer])
     .rpc();
@@ -198,11 +257,12 @@ export async function buyToken(
 export async function completeLaunchPool(
   program: Program<EncodeSolTeam3>,
   creator: Wallet,
-  mint: PublicKey
+  mint: PublicKey,
+  pool_index: BN
 ) {
   const [launch_pool] = findLaunchPoolAccount(
     creator.publicKey,
-    mint,
+    pool_index,
     program.programId
   );
 
@@ -216,6 +276,21 @@ export async function completeLaunchPool(
       launchPool: launch_pool,
       authority: creator.publicKey,
       tokenMint: mint,
+      treasurer: null,
+      treasury: null,
+      vault: null,
+      ammProgram: null,
+      ammPool: null,
+      ammLpMint: null,
+      ammTokenReserve: null,
+      lpLock: null,
+      lpTokenAccount: null,
+      liquidityEscrow: null,
+      escrowTokenAccount: null,
+      systemProgram: null,
+      tokenProgram: null,
+      eventAuthority: findEventAuthorityAccount(program.programId)[0],
+      program: program.programId,
     })
     .signers([creator.payer])
     .rpc();
@@ -228,9 +303,14 @@ export async function claimToken(
   program: Program<EncodeSolTeam3>,
   creator: PublicKey,
   mint: PublicKey,
+  pool_index: BN,
   buyer: Wallet
 ) {
-  const [launch_pool] = findLaunchPoolAccount(creator, mint, program.programId);
+  const [launch_pool] = findLaunchPoolAccount(
+    creator,
+    pool_index,
+    program.programId
+  );
   const [treasurer, treasurerBump] = findTreasurerAccount(
     launch_pool,
     mint,
@@ -269,6 +349,8 @@ export async function claimToken(
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: web3.SystemProgram.programId,
       rent: web3.SYSVAR_RENT_PUBKEY,
+      eventAuthority: findEventAuthorityAccount(program.programId)[0],
+      program: program.programId,
     })
     .signers([buyer.payer])
     .rpc();
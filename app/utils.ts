@@ -1,6 +1,11 @@
-import { Wallet, web3 } from "@coral-xyz/anchor";
+import { BN, Wallet, web3 } from "@coral-xyz/anchor";
 import { associatedAddress } from "@coral-xyz/anchor/dist/cjs/utils/token";
-import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import {
+  Ed25519Program,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+} from "@solana/web3.js";
 import {
   createMint,
   getOrCreateAssociatedTokenAccount,
@@ -17,13 +22,47 @@ export function findVestingPlanAccount(pool: PublicKey, programId: PublicKey) {
   );
 }
 
+export function findCreatorRegistryAccount(
+  creator: PublicKey,
+  programId: PublicKey
+) {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("creatorregistry"), creator.toBuffer()],
+    programId
+  );
+}
+
 export function findLaunchPoolAccount(
   creator: PublicKey,
-  mint: PublicKey,
+  index: BN,
+  programId: PublicKey
+) {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("launchpool"),
+      creator.toBuffer(),
+      index.toArrayLike(Buffer, "le", 8),
+    ],
+    programId
+  );
+}
+
+// every pool a creator has launched, given the poolCount of its creator registry
+export function findCreatorPoolAccounts(
+  creator: PublicKey,
+  poolCount: BN,
   programId: PublicKey
 ) {
+  return Array.from(
+    { length: poolCount.toNumber() },
+    (_, index) => findLaunchPoolAccount(creator, new BN(index), programId)[0]
+  );
+}
+
+// the authority anchor signs emit_cpi! events with
+export function findEventAuthorityAccount(programId: PublicKey) {
   return PublicKey.findProgramAddressSync(
-    [Buffer.from("launchpool"), creator.toBuffer(), mint.toBuffer()],
+    [Buffer.from("__event_authority")],
     programId
   );
 }
@@ -115,6 +154,26 @@ export function findUserPoolAccount(
   );
 }
 
+// the voucher message is (pool, buyer, max_amount, expiry) as laid out by the program
+export function createAllocationVoucherInstruction(
+  signer: Keypair,
+  pool: PublicKey,
+  buyer: PublicKey,
+  maxAmount: bigint,
+  expiry: bigint
+) {
+  const message = Buffer.alloc(80);
+  pool.toBuffer().copy(message, 0);
+  buyer.toBuffer().copy(message, 32);
+  message.writeBigUInt64LE(maxAmount, 64);
+  message.writeBigInt64LE(expiry, 72);
+
+  return Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: signer.secretKey,
+    message,
+  });
+}
+
 export function delay(ms: number): Promise<void> {
   console.log(`delaying ${ms} ms ...`);
   return new Promise<void>((resolve) => setTimeout(resolve, ms));
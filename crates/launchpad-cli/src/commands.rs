@@ -0,0 +1,391 @@
+use std::{collections::BTreeSet, fs, path::PathBuf, str::FromStr};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::{associated_token::get_associated_token_address, token::Mint};
+use anyhow::{anyhow, bail, Result};
+use launchpad_client::{
+    find_creator_registry, find_lp_lock,
+    instructions::{self, CreateNativePoolArgs, PoolMetadataArgs},
+    state::{
+        status_name, unsold_policy_name, CreatorRegistry, LpLock, PoolMetadata, ProceedsVesting,
+        UnsoldPolicy, UserPool,
+    },
+    transaction_events, PoolAddresses, PoolEvent,
+};
+use serde_json::{json, Map, Value};
+use solana_client::{
+    rpc_client::GetConfirmedSignaturesForAddress2Config, rpc_config::RpcTransactionConfig,
+};
+use solana_sdk::{hash::hash, signature::Signature};
+use solana_transaction_status::UiTransactionEncoding;
+
+use crate::config::Config;
+
+fn report(fields: Value) -> Map<String, Value> {
+    match fields {
+        Value::Object(fields) => fields,
+        _ => Map::new(),
+    }
+}
+
+pub struct CreatePool {
+    pub mint: Pubkey,
+    pub unlock_date: i64,
+    pub pool_size: u64,
+    pub minimum_token_amount: u64,
+    pub maximum_token_amount: u64,
+    pub rate: u64,
+    pub proceeds_vesting: Option<ProceedsVesting>,
+    pub unsold_policy: UnsoldPolicy,
+}
+
+pub fn create_pool(config: &Config, args: CreatePool) -> Result<()> {
+    let creator = config.signer();
+    // the next pool of a creator is seeded with the registry's pool count
+    let index = config
+        .account::<CreatorRegistry>(&find_creator_registry(&creator).0)?
+        .map_or(0, |registry| registry.pool_count);
+    let mint: Mint = config
+        .account(&args.mint)?
+        .ok_or_else(|| anyhow!("mint {} not found", args.mint))?;
+    let pool = PoolAddresses::new(creator, index, args.mint);
+
+    let instruction = instructions::create_native_pool(
+        &pool,
+        CreateNativePoolArgs {
+            unlock_date: args.unlock_date,
+            pool_size: args.pool_size,
+            minimum_token_amount: args.minimum_token_amount,
+            maximum_token_amount: args.maximum_token_amount,
+            rate: args.rate,
+            token_mint_decimals: mint.decimals,
+            proceeds_vesting: args.proceeds_vesting,
+            unsold_policy: Some(args.unsold_policy),
+        },
+    );
+
+    config.process(
+        &[instruction],
+        report(json!({
+            "launch_pool": pool.launch_pool.to_string(),
+            "index": index,
+            "treasury": pool.treasury.to_string(),
+            "vault": pool.vault.to_string(),
+        })),
+    )
+}
+
+pub fn start(config: &Config, address: Pubkey, source: Option<Pubkey>) -> Result<()> {
+    let (launch_pool, pool) = config.launch_pool(&address)?;
+    // a supply minted at creation is already in the treasury
+    let source = (launch_pool.minted_supply == 0).then(|| {
+        source.unwrap_or_else(|| get_associated_token_address(&config.signer(), &pool.token_mint))
+    });
+
+    let instruction = instructions::start_launch_pool(
+        &pool,
+        config.signer(),
+        source,
+        launch_pool.team_lock_amount > 0,
+    );
+
+    config.process(
+        &[instruction],
+        report(json!({
+            "launch_pool": address.to_string(),
+            "token_amount": launch_pool.pool_size + launch_pool.liquidity_token_reserve,
+            "team_lock_amount": launch_pool.team_lock_amount,
+        })),
+    )
+}
+
+pub fn buy(config: &Config, address: Pubkey, amount: u64) -> Result<()> {
+    let (launch_pool, pool) = config.launch_pool(&address)?;
+    let lamports = launchpad_client::quote(&launch_pool, amount)
+        .ok_or_else(|| anyhow!("{} tokens cannot be priced at the pool's rate", amount))?;
+    let instruction = instructions::buy_token_with_native(&pool, config.signer(), amount);
+
+    config.process(
+        &[instruction],
+        report(json!({
+            "launch_pool": address.to_string(),
+            "user_pool": pool.user_pool(&config.signer()).to_string(),
+            "token_amount": amount,
+            "lamports": lamports,
+        })),
+    )
+}
+
+pub fn complete(config: &Config, address: Pubkey, amm_token_reserve: Option<Pubkey>) -> Result<()> {
+    let (launch_pool, pool) = config.launch_pool(&address)?;
+
+    let instruction = if launch_pool.seeds_liquidity() {
+        let amm_token_reserve = amm_token_reserve
+            .ok_or_else(|| anyhow!("the pool seeds liquidity, pass --amm-token-reserve"))?;
+        let lp_lock: LpLock = config
+            .account(&find_lp_lock(&address).0)?
+            .ok_or_else(|| anyhow!("LP lock of {} not found", address))?;
+
+        instructions::complete_launch_pool_with_liquidity(
+            &pool,
+            &launch_pool,
+            config.signer(),
+            lp_lock.lp_mint,
+            amm_token_reserve,
+        )
+    } else {
+        instructions::complete_launch_pool(&pool, config.signer())
+    };
+
+    config.process(
+        &[instruction],
+        report(json!({
+            "launch_pool": address.to_string(),
+            "vault_amount": launch_pool.vault_amount,
+            "sold_amount": launch_pool.sold_amount(),
+        })),
+    )
+}
+
+pub fn claim(config: &Config, address: Pubkey) -> Result<()> {
+    let (_, pool) = config.launch_pool(&address)?;
+    let user_pool: UserPool = config
+        .account(&pool.user_pool(&config.signer()))?
+        .ok_or_else(|| anyhow!("{} has no position in {}", config.signer(), address))?;
+
+    config.process(
+        &[instructions::claim_token(&pool, config.signer())],
+        report(json!({
+            "launch_pool": address.to_string(),
+            "token_amount": user_pool.amount - user_pool.claimed,
+        })),
+    )
+}
+
+pub fn withdraw_proceeds(config: &Config, address: Pubkey) -> Result<()> {
+    let (launch_pool, pool) = config.launch_pool(&address)?;
+    let now = config.rpc.get_block_time(config.rpc.get_slot()?)?;
+
+    config.process(
+        &[instructions::withdraw_proceeds(&pool, config.signer())],
+        report(json!({
+            "launch_pool": address.to_string(),
+            "released": launch_pool.released_proceeds(now),
+            "withdrawn": launch_pool.proceeds_withdrawn,
+        })),
+    )
+}
+
+pub fn refund(config: &Config, address: Pubkey) -> Result<()> {
+    let (_, pool) = config.launch_pool(&address)?;
+
+    config.process(
+        &[instructions::reclaim_funds(&pool, config.signer())],
+        report(json!({ "launch_pool": address.to_string() })),
+    )
+}
+
+pub fn finalize_unsold(
+    config: &Config,
+    address: Pubkey,
+    destination: Option<Pubkey>,
+) -> Result<()> {
+    let (launch_pool, pool) = config.launch_pool(&address)?;
+
+    let policy = launch_pool.unsold_policy.ok_or_else(|| {
+        anyhow!("the pool declared no unsold policy, its authority withdraws the unsold tokens")
+    })?;
+    let destination = match policy {
+        UnsoldPolicy::RefundToCreator => Some(destination.unwrap_or_else(|| {
+            get_associated_token_address(&launch_pool.authority, &pool.token_mint)
+        })),
+        UnsoldPolicy::SendToLiquidity => Some(destination.ok_or_else(|| {
+            anyhow!("the pool sends unsold tokens to liquidity, pass --destination")
+        })?),
+        UnsoldPolicy::Burn => None,
+    };
+
+    config.process(
+        &[instructions::finalize_unsold_tokens(
+            &pool,
+            policy,
+            destination,
+        )],
+        report(json!({
+            "launch_pool": address.to_string(),
+            "policy": unsold_policy_name(launch_pool.unsold_policy),
+            "unsold_amount": launch_pool.unsold_amount(),
+        })),
+    )
+}
+
+pub fn close_position(config: &Config, address: Pubkey) -> Result<()> {
+    let (_, pool) = config.launch_pool(&address)?;
+
+    config.process(
+        &[instructions::close_user_pool(&pool, config.signer())],
+        report(json!({
+            "launch_pool": address.to_string(),
+            "user_pool": pool.user_pool(&config.signer()).to_string(),
+        })),
+    )
+}
+
+pub fn close_pool(config: &Config, address: Pubkey) -> Result<()> {
+    let (_, pool) = config.launch_pool(&address)?;
+
+    config.process(
+        &[instructions::close_launch_pool(&pool, config.signer())],
+        report(json!({ "launch_pool": address.to_string() })),
+    )
+}
+
+pub struct SetMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub website: Option<String>,
+    pub socials: Vec<String>,
+    pub document: PathBuf,
+}
+
+// the content hash is the sha256 of the local copy of the document published at the uri
+pub fn set_metadata(config: &Config, address: Pubkey, args: SetMetadata) -> Result<()> {
+    let (_, pool) = config.launch_pool(&address)?;
+    let content_hash = hash(&fs::read(&args.document)?).to_bytes();
+
+    config.process(
+        &[instructions::update_pool_metadata(
+            &pool,
+            config.signer(),
+            PoolMetadataArgs {
+                name: args.name,
+                symbol: args.symbol,
+                uri: args.uri,
+                website: args.website,
+                socials: args.socials,
+                content_hash,
+            },
+        )],
+        report(json!({
+            "launch_pool": address.to_string(),
+            "pool_metadata": pool.pool_metadata().to_string(),
+            "content_hash": hex(&content_hash),
+        })),
+    )
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub fn show_pool(config: &Config, address: Pubkey) -> Result<()> {
+    let (launch_pool, pool) = config.launch_pool(&address)?;
+    let metadata = config
+        .account::<PoolMetadata>(&pool.pool_metadata())?
+        .map(|metadata| {
+            json!({
+                "name": metadata.name,
+                "symbol": metadata.symbol,
+                "uri": metadata.uri,
+                "website": metadata.website,
+                "socials": metadata.socials,
+                "content_hash": hex(&metadata.content_hash),
+            })
+        });
+
+    config.print(&json!({
+        "launch_pool": address.to_string(),
+        "creator": launch_pool.creator.to_string(),
+        "authority": launch_pool.authority.to_string(),
+        "index": launch_pool.index,
+        "token_mint": launch_pool.token_mint.to_string(),
+        "status": status_name(launch_pool.status),
+        "pool_size": launch_pool.pool_size,
+        "pool_size_remaining": launch_pool.pool_size_remaining,
+        "sold_amount": launch_pool.sold_amount(),
+        "rate": launch_pool.rate,
+        "minimum_token_amount": launch_pool.minimum_token_amount,
+        "maximum_token_amount": launch_pool.maximum_token_amount,
+        "unlock_date": launch_pool.unlock_date,
+        "vault_amount": launch_pool.vault_amount,
+        "open_positions": launch_pool.open_positions,
+        "unsold_policy": unsold_policy_name(launch_pool.unsold_policy),
+        "completed_at": launch_pool.completed_at,
+        "treasury": pool.treasury.to_string(),
+        "vault": pool.vault.to_string(),
+        "metadata": metadata,
+    }));
+
+    Ok(())
+}
+
+// UserPool accounts do not store their owner, so buyers are recovered from the
+// purchase events in the pool's transaction history
+pub fn list_buyers(config: &Config, address: Pubkey, limit: usize) -> Result<()> {
+    let (_, pool) = config.launch_pool(&address)?;
+
+    let signatures = config.rpc.get_signatures_for_address_with_config(
+        &address,
+        GetConfirmedSignaturesForAddress2Config {
+            limit: Some(limit),
+            commitment: Some(config.rpc.commitment()),
+            ..Default::default()
+        },
+    )?;
+
+    let mut buyers = BTreeSet::new();
+    for status in signatures.iter().filter(|status| status.err.is_none()) {
+        let transaction = config.rpc.get_transaction_with_config(
+            &Signature::from_str(&status.signature)?,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(config.rpc.commitment()),
+                max_supported_transaction_version: Some(0),
+            },
+        )?;
+
+        for event in transaction_events(&transaction) {
+            match event {
+                PoolEvent::Buy(event) if event.launch_pool == address => buyers.insert(event.buyer),
+                PoolEvent::BuyInRound(event) if event.launch_pool == address => {
+                    buyers.insert(event.buyer)
+                }
+                _ => false,
+            };
+        }
+    }
+
+    let mut rows = Vec::new();
+    for buyer in buyers {
+        // closed positions have been fully settled and are skipped
+        if let Some(user_pool) = config.account::<UserPool>(&pool.user_pool(&buyer))? {
+            rows.push(json!({
+                "buyer": buyer.to_string(),
+                "amount": user_pool.amount,
+                "currency_amount": user_pool.currency_amount,
+                "claimed": user_pool.claimed,
+                "refunded": user_pool.refunded,
+            }));
+        }
+    }
+
+    config.print(&Value::Array(rows));
+    Ok(())
+}
+
+pub fn proceeds_vesting(
+    completion_bps: Option<u16>,
+    period: Option<i64>,
+    periods: Option<u16>,
+) -> Result<Option<ProceedsVesting>> {
+    match (completion_bps, period, periods) {
+        (None, None, None) => Ok(None),
+        (Some(completion_bps), Some(period), Some(periods)) => Ok(Some(ProceedsVesting {
+            completion_bps,
+            period,
+            periods,
+        })),
+        _ => bail!("--vesting-completion-bps, --vesting-period and --vesting-periods go together"),
+    }
+}
@@ -0,0 +1,139 @@
+use anchor_lang::{prelude::Pubkey, AccountDeserialize};
+use anyhow::{anyhow, bail, Context, Result};
+use launchpad_client::{state::LaunchPool, PoolAddresses};
+use serde_json::{json, Map, Value};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::Instruction,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+
+// everything a command needs: the cluster, the signer and how to report
+pub struct Config {
+    pub rpc: RpcClient,
+    pub payer: Keypair,
+    pub json: bool,
+    pub dry_run: bool,
+}
+
+impl Config {
+    pub fn new(url: &str, keypair: &str, json: bool, dry_run: bool) -> Result<Self> {
+        let path = match keypair.strip_prefix("~/") {
+            Some(rest) => format!("{}/{}", std::env::var("HOME")?, rest),
+            None => keypair.to_string(),
+        };
+        let payer =
+            read_keypair_file(&path).map_err(|err| anyhow!("reading keypair {}: {}", path, err))?;
+
+        Ok(Self {
+            rpc: RpcClient::new_with_commitment(url.to_string(), CommitmentConfig::confirmed()),
+            payer,
+            json,
+            dry_run,
+        })
+    }
+
+    pub fn signer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    // fetches and decodes a program account, None if it does not exist
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> Result<Option<T>> {
+        let account = self
+            .rpc
+            .get_account_with_commitment(address, self.rpc.commitment())?
+            .value;
+
+        account
+            .map(|account| T::try_deserialize(&mut &account.data[..]))
+            .transpose()
+            .with_context(|| format!("decoding account {}", address))
+    }
+
+    // fetches a launch pool along with every address derived from it
+    pub fn launch_pool(&self, address: &Pubkey) -> Result<(LaunchPool, PoolAddresses)> {
+        let launch_pool: LaunchPool = self
+            .account(address)?
+            .ok_or_else(|| anyhow!("launch pool {} not found", address))?;
+        let pool = PoolAddresses::new(
+            launch_pool.creator,
+            launch_pool.index,
+            launch_pool.token_mint,
+        );
+
+        if pool.launch_pool != *address {
+            bail!("{} is not a launch pool of this program", address);
+        }
+
+        Ok((launch_pool, pool))
+    }
+
+    // sends the instructions in one transaction, or only simulates them on --dry-run
+    pub fn process(
+        &self,
+        instructions: &[Instruction],
+        mut report: Map<String, Value>,
+    ) -> Result<()> {
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.signer()),
+            &[&self.payer],
+            self.rpc.get_latest_blockhash()?,
+        );
+
+        if self.dry_run {
+            let result = self.rpc.simulate_transaction(&transaction)?.value;
+            report.insert("dry_run".into(), json!(true));
+            report.insert("units_consumed".into(), json!(result.units_consumed));
+            report.insert("logs".into(), json!(result.logs.unwrap_or_default()));
+            if let Some(err) = result.err {
+                report.insert("error".into(), json!(err.to_string()));
+                self.print(&Value::Object(report));
+                bail!("simulation failed: {}", err);
+            }
+        } else {
+            let signature = self.rpc.send_and_confirm_transaction(&transaction)?;
+            report.insert("signature".into(), json!(signature.to_string()));
+        }
+
+        self.print(&Value::Object(report));
+        Ok(())
+    }
+
+    // prints JSON with --json, otherwise one "key: value" line per field
+    pub fn print(&self, value: &Value) {
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(value).unwrap());
+            return;
+        }
+
+        match value {
+            Value::Object(fields) => {
+                for (key, value) in fields {
+                    match value {
+                        Value::Array(lines) if key == "logs" => {
+                            println!("{}:", key);
+                            for line in lines {
+                                println!("  {}", line.as_str().unwrap_or_default());
+                            }
+                        }
+                        Value::String(value) => println!("{}: {}", key, value),
+                        value => println!("{}: {}", key, value),
+                    }
+                }
+            }
+            Value::Array(rows) => {
+                for row in rows {
+                    let fields = row.as_object().into_iter().flatten();
+                    let line: Vec<String> = fields
+                        .map(|(key, value)| format!("{}={}", key, value))
+                        .collect();
+                    println!("{}", line.join(" "));
+                }
+            }
+            value => println!("{}", value),
+        }
+    }
+}
@@ -0,0 +1,228 @@
+mod commands;
+mod config;
+
+use std::path::PathBuf;
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use clap::{Parser, Subcommand, ValueEnum};
+use launchpad_client::state::UnsoldPolicy;
+
+use crate::{
+    commands::{CreatePool, SetMetadata},
+    config::Config,
+};
+
+/// Drive the launchpad program from the command line
+#[derive(Parser)]
+#[command(name = "launchpad", version)]
+struct Cli {
+    /// RPC endpoint of the cluster
+    #[arg(
+        long,
+        short = 'u',
+        global = true,
+        env = "LAUNCHPAD_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+    /// Keypair file that signs and pays for transactions
+    #[arg(
+        long,
+        short = 'k',
+        global = true,
+        env = "LAUNCHPAD_KEYPAIR",
+        default_value = "~/.config/solana/id.json"
+    )]
+    keypair: String,
+    /// Print JSON instead of text
+    #[arg(long, global = true)]
+    json: bool,
+    /// Simulate the transaction and print the compute units it uses instead of sending it
+    #[arg(long, global = true)]
+    dry_run: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum UnsoldPolicyArg {
+    Refund,
+    Burn,
+    Liquidity,
+}
+
+impl From<UnsoldPolicyArg> for UnsoldPolicy {
+    fn from(val: UnsoldPolicyArg) -> Self {
+        match val {
+            UnsoldPolicyArg::Refund => UnsoldPolicy::RefundToCreator,
+            UnsoldPolicyArg::Burn => UnsoldPolicy::Burn,
+            UnsoldPolicyArg::Liquidity => UnsoldPolicy::SendToLiquidity,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a native fair launch pool for an existing mint
+    CreatePool {
+        #[arg(long)]
+        mint: Pubkey,
+        /// Unix timestamp after which buyers can claim
+        #[arg(long)]
+        unlock_date: i64,
+        #[arg(long)]
+        pool_size: u64,
+        #[arg(long)]
+        minimum_token_amount: u64,
+        #[arg(long)]
+        maximum_token_amount: u64,
+        #[arg(long)]
+        rate: u64,
+        /// What happens to unsold tokens once the pool completes
+        #[arg(long, value_enum, default_value = "refund")]
+        unsold_policy: UnsoldPolicyArg,
+        /// Basis points of the proceeds released on completion
+        #[arg(long)]
+        vesting_completion_bps: Option<u16>,
+        /// Seconds between two releases of the remaining proceeds
+        #[arg(long)]
+        vesting_period: Option<i64>,
+        /// Number of releases of the remaining proceeds
+        #[arg(long)]
+        vesting_periods: Option<u16>,
+    },
+    /// Deposit the sale supply and open the pool
+    Start {
+        pool: Pubkey,
+        /// Token account the supply is taken from, the signer's associated account by default
+        #[arg(long)]
+        source: Option<Pubkey>,
+    },
+    /// Buy tokens with SOL
+    Buy { pool: Pubkey, amount: u64 },
+    /// Close the sale
+    Complete {
+        pool: Pubkey,
+        /// Token reserve of the AMM pool, for pools that seed liquidity
+        #[arg(long)]
+        amm_token_reserve: Option<Pubkey>,
+    },
+    /// Claim the signer's tokens
+    Claim { pool: Pubkey },
+    /// Withdraw the released sale proceeds
+    WithdrawProceeds { pool: Pubkey },
+    /// Reclaim the signer's SOL from a refunding pool
+    Refund { pool: Pubkey },
+    /// Settle the unsold tokens as the pool's policy dictates
+    FinalizeUnsold {
+        pool: Pubkey,
+        /// Token account receiving the unsold tokens
+        #[arg(long)]
+        destination: Option<Pubkey>,
+    },
+    /// Close the signer's settled position and reclaim its rent
+    ClosePosition { pool: Pubkey },
+    /// Close a settled pool and reclaim its rent
+    ClosePool { pool: Pubkey },
+    /// Describe a pending pool for buyers
+    SetMetadata {
+        pool: Pubkey,
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        symbol: String,
+        /// URI of the JSON document describing the sale
+        #[arg(long)]
+        uri: String,
+        #[arg(long)]
+        website: Option<String>,
+        /// Social link, repeat the flag for several
+        #[arg(long = "social")]
+        socials: Vec<String>,
+        /// Local copy of the document at the URI, hashed so readers can verify it
+        #[arg(long)]
+        document: PathBuf,
+    },
+    /// Print the state of a pool
+    ShowPool { pool: Pubkey },
+    /// Print every buyer of a pool with their position
+    ListBuyers {
+        pool: Pubkey,
+        /// Number of recent transactions of the pool to scan
+        #[arg(long, default_value_t = 1000)]
+        limit: usize,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let config = Config::new(&cli.url, &cli.keypair, cli.json, cli.dry_run)?;
+
+    match cli.command {
+        Command::CreatePool {
+            mint,
+            unlock_date,
+            pool_size,
+            minimum_token_amount,
+            maximum_token_amount,
+            rate,
+            unsold_policy,
+            vesting_completion_bps,
+            vesting_period,
+            vesting_periods,
+        } => commands::create_pool(
+            &config,
+            CreatePool {
+                mint,
+                unlock_date,
+                pool_size,
+                minimum_token_amount,
+                maximum_token_amount,
+                rate,
+                proceeds_vesting: commands::proceeds_vesting(
+                    vesting_completion_bps,
+                    vesting_period,
+                    vesting_periods,
+                )?,
+                unsold_policy: unsold_policy.into(),
+            },
+        ),
+        Command::Start { pool, source } => commands::start(&config, pool, source),
+        Command::Buy { pool, amount } => commands::buy(&config, pool, amount),
+        Command::Complete {
+            pool,
+            amm_token_reserve,
+        } => commands::complete(&config, pool, amm_token_reserve),
+        Command::Claim { pool } => commands::claim(&config, pool),
+        Command::WithdrawProceeds { pool } => commands::withdraw_proceeds(&config, pool),
+        Command::Refund { pool } => commands::refund(&config, pool),
+        Command::FinalizeUnsold { pool, destination } => {
+            commands::finalize_unsold(&config, pool, destination)
+        }
+        Command::ClosePosition { pool } => commands::close_position(&config, pool),
+        Command::ClosePool { pool } => commands::close_pool(&config, pool),
+        Command::SetMetadata {
+            pool,
+            name,
+            symbol,
+            uri,
+            website,
+            socials,
+            document,
+        } => commands::set_metadata(
+            &config,
+            pool,
+            SetMetadata {
+                name,
+                symbol,
+                uri,
+                website,
+                socials,
+                document,
+            },
+        ),
+        Command::ShowPool { pool } => commands::show_pool(&config, pool),
+        Command::ListBuyers { pool, limit } => commands::list_buyers(&config, pool, limit),
+    }
+}
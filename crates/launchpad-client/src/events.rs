@@ -0,0 +1,167 @@
+use anchor_lang::{event::EVENT_IX_TAG_LE, prelude::Pubkey, AnchorDeserialize, Discriminator};
+pub use encode_sol_team3::instructions::{
+    AcceptAuthorityEvent, AddWalletsToWhitelistEvent, AdvanceSaleRoundEvent, BuyTokenInRoundEvent,
+    BuyTokenWithNativeEvent, ClaimTokenEvent, CloseLaunchPoolEvent, CloseUserPoolEvent,
+    CompleteLaunchPoolEvent, ConfigureAllocationSignerEvent, ConfigureGovernanceEvent,
+    ConfigureLiquidityEvent, ConfigureNftGateEvent, ConfigureReferralEvent, ConfigureTeamLockEvent,
+    ConfigureTierAllocationsEvent, CreateLaunchPoolEvent, CreateReferralStatsEvent,
+    CreateSaleRoundEvent, CreateTokenMintEvent, ExecuteMilestoneEvent, ExtendLpLockEvent,
+    FinalizeUnsoldTokensEvent, InitializeStakingEvent, LaunchPoolMigratedEvent,
+    MintPoolSupplyEvent, ProposeAuthorityEvent, ProposeMilestoneEvent, ReclaimFundsEvent,
+    ReferralRewardClaimedEvent, ReferredBuyEvent, RegisterReferrerEvent,
+    RemoveWalletFromWhitelistEvent, StakeEvent, StartLaunchPoolEvent, UnstakeEvent,
+    UpdateStakingTiersEvent, VoteMilestoneEvent, WithdrawLpEvent, WithdrawProceedsEvent,
+    WithdrawTeamTokensEvent, WithdrawUnsoldTokensEvent,
+};
+
+// events of a pool, as emitted with emit_cpi!; the staking, referrer registration and mint
+// creation events belong to no launch pool and are keyed by the staking config, referrer
+// account or token mint
+pub enum PoolEvent {
+    Create(CreateLaunchPoolEvent),
+    MintPoolSupply(MintPoolSupplyEvent),
+    ConfigureGovernance(ConfigureGovernanceEvent),
+    ConfigureReferral(ConfigureReferralEvent),
+    ConfigureTierAllocations(ConfigureTierAllocationsEvent),
+    ConfigureNftGate(ConfigureNftGateEvent),
+    ConfigureAllocationSigner(ConfigureAllocationSignerEvent),
+    ConfigureLiquidity(ConfigureLiquidityEvent),
+    ConfigureTeamLock(ConfigureTeamLockEvent),
+    Start(StartLaunchPoolEvent),
+    Buy(BuyTokenWithNativeEvent),
+    BuyInRound(BuyTokenInRoundEvent),
+    ReferredBuy(ReferredBuyEvent),
+    CreateReferralStats(CreateReferralStatsEvent),
+    CreateSaleRound(CreateSaleRoundEvent),
+    AddWalletsToWhitelist(AddWalletsToWhitelistEvent),
+    RemoveWalletFromWhitelist(RemoveWalletFromWhitelistEvent),
+    AdvanceSaleRound(AdvanceSaleRoundEvent),
+    Complete(CompleteLaunchPoolEvent),
+    Claim(ClaimTokenEvent),
+    WithdrawProceeds(WithdrawProceedsEvent),
+    ReferralRewardClaimed(ReferralRewardClaimedEvent),
+    ProposeMilestone(ProposeMilestoneEvent),
+    VoteMilestone(VoteMilestoneEvent),
+    ExecuteMilestone(ExecuteMilestoneEvent),
+    ReclaimFunds(ReclaimFundsEvent),
+    ProposeAuthority(ProposeAuthorityEvent),
+    AcceptAuthority(AcceptAuthorityEvent),
+    WithdrawTeamTokens(WithdrawTeamTokensEvent),
+    WithdrawLp(WithdrawLpEvent),
+    ExtendLpLock(ExtendLpLockEvent),
+    FinalizeUnsoldTokens(FinalizeUnsoldTokensEvent),
+    WithdrawUnsoldTokens(WithdrawUnsoldTokensEvent),
+    CloseUserPool(CloseUserPoolEvent),
+    CloseLaunchPool(CloseLaunchPoolEvent),
+    MigrateLaunchPool(LaunchPoolMigratedEvent),
+    CreateTokenMint(CreateTokenMintEvent),
+    RegisterReferrer(RegisterReferrerEvent),
+    InitializeStaking(InitializeStakingEvent),
+    UpdateStakingTiers(UpdateStakingTiersEvent),
+    Stake(StakeEvent),
+    Unstake(UnstakeEvent),
+}
+
+impl PoolEvent {
+    // the launch pool of the event, or the staking config or referrer account it is keyed by
+    pub fn launch_pool(&self) -> Pubkey {
+        match self {
+            PoolEvent::Create(event) => event.launch_pool,
+            PoolEvent::MintPoolSupply(event) => event.launch_pool,
+            PoolEvent::ConfigureGovernance(event) => event.launch_pool,
+            PoolEvent::ConfigureReferral(event) => event.launch_pool,
+            PoolEvent::ConfigureTierAllocations(event) => event.launch_pool,
+            PoolEvent::ConfigureNftGate(event) => event.launch_pool,
+            PoolEvent::ConfigureAllocationSigner(event) => event.launch_pool,
+            PoolEvent::ConfigureLiquidity(event) => event.launch_pool,
+            PoolEvent::ConfigureTeamLock(event) => event.launch_pool,
+            PoolEvent::Start(event) => event.launch_pool,
+            PoolEvent::Buy(event) => event.launch_pool,
+            PoolEvent::BuyInRound(event) => event.launch_pool,
+            PoolEvent::ReferredBuy(event) => event.launch_pool,
+            PoolEvent::CreateReferralStats(event) => event.launch_pool,
+            PoolEvent::CreateSaleRound(event) => event.launch_pool,
+            PoolEvent::AddWalletsToWhitelist(event) => event.launch_pool,
+            PoolEvent::RemoveWalletFromWhitelist(event) => event.launch_pool,
+            PoolEvent::AdvanceSaleRound(event) => event.launch_pool,
+            PoolEvent::Complete(event) => event.launch_pool,
+            PoolEvent::Claim(event) => event.launch_pool,
+            PoolEvent::WithdrawProceeds(event) => event.launch_pool,
+            PoolEvent::ReferralRewardClaimed(event) => event.launch_pool,
+            PoolEvent::ProposeMilestone(event) => event.launch_pool,
+            PoolEvent::VoteMilestone(event) => event.launch_pool,
+            PoolEvent::ExecuteMilestone(event) => event.launch_pool,
+            PoolEvent::ReclaimFunds(event) => event.launch_pool,
+            PoolEvent::ProposeAuthority(event) => event.launch_pool,
+            PoolEvent::AcceptAuthority(event) => event.launch_pool,
+            PoolEvent::WithdrawTeamTokens(event) => event.launch_pool,
+            PoolEvent::WithdrawLp(event) => event.launch_pool,
+            PoolEvent::ExtendLpLock(event) => event.launch_pool,
+            PoolEvent::FinalizeUnsoldTokens(event) => event.launch_pool,
+            PoolEvent::WithdrawUnsoldTokens(event) => event.launch_pool,
+            PoolEvent::CloseUserPool(event) => event.launch_pool,
+            PoolEvent::CloseLaunchPool(event) => event.launch_pool,
+            PoolEvent::MigrateLaunchPool(event) => event.launch_pool,
+            PoolEvent::CreateTokenMint(event) => event.token_mint,
+            PoolEvent::RegisterReferrer(event) => event.referrer,
+            PoolEvent::InitializeStaking(event) => event.staking_config,
+            PoolEvent::UpdateStakingTiers(event) => event.staking_config,
+            PoolEvent::Stake(event) => event.staking_config,
+            PoolEvent::Unstake(event) => event.staking_config,
+        }
+    }
+}
+
+// decodes one event from the data of a self-CPI into the program's event authority
+pub fn decode_event<E: Discriminator + AnchorDeserialize>(data: &[u8]) -> Option<E> {
+    let data = data.strip_prefix(&EVENT_IX_TAG_LE[..])?;
+    let data = data.strip_prefix(&E::DISCRIMINATOR[..])?;
+    E::try_from_slice(data).ok()
+}
+
+// decodes inner instruction data into a pool event, None for anything else
+pub fn decode_pool_event(data: &[u8]) -> Option<PoolEvent> {
+    decode_event(data)
+        .map(PoolEvent::Create)
+        .or_else(|| decode_event(data).map(PoolEvent::MintPoolSupply))
+        .or_else(|| decode_event(data).map(PoolEvent::ConfigureGovernance))
+        .or_else(|| decode_event(data).map(PoolEvent::ConfigureReferral))
+        .or_else(|| decode_event(data).map(PoolEvent::ConfigureTierAllocations))
+        .or_else(|| decode_event(data).map(PoolEvent::ConfigureNftGate))
+        .or_else(|| decode_event(data).map(PoolEvent::ConfigureAllocationSigner))
+        .or_else(|| decode_event(data).map(PoolEvent::ConfigureLiquidity))
+        .or_else(|| decode_event(data).map(PoolEvent::ConfigureTeamLock))
+        .or_else(|| decode_event(data).map(PoolEvent::Start))
+        .or_else(|| decode_event(data).map(PoolEvent::Buy))
+        .or_else(|| decode_event(data).map(PoolEvent::BuyInRound))
+        .or_else(|| decode_event(data).map(PoolEvent::ReferredBuy))
+        .or_else(|| decode_event(data).map(PoolEvent::CreateReferralStats))
+        .or_else(|| decode_event(data).map(PoolEvent::CreateSaleRound))
+        .or_else(|| decode_event(data).map(PoolEvent::AddWalletsToWhitelist))
+        .or_else(|| decode_event(data).map(PoolEvent::RemoveWalletFromWhitelist))
+        .or_else(|| decode_event(data).map(PoolEvent::AdvanceSaleRound))
+        .or_else(|| decode_event(data).map(PoolEvent::Complete))
+        .or_else(|| decode_event(data).map(PoolEvent::Claim))
+        .or_else(|| decode_event(data).map(PoolEvent::WithdrawProceeds))
+        .or_else(|| decode_event(data).map(PoolEvent::ReferralRewardClaimed))
+        .or_else(|| decode_event(data).map(PoolEvent::ProposeMilestone))
+        .or_else(|| decode_event(data).map(PoolEvent::VoteMilestone))
+        .or_else(|| decode_event(data).map(PoolEvent::ExecuteMilestone))
+        .or_else(|| decode_event(data).map(PoolEvent::ReclaimFunds))
+        .or_else(|| decode_event(data).map(PoolEvent::ProposeAuthority))
+        .or_else(|| decode_event(data).map(PoolEvent::AcceptAuthority))
+        .or_else(|| decode_event(data).map(PoolEvent::WithdrawTeamTokens))
+        .or_else(|| decode_event(data).map(PoolEvent::WithdrawLp))
+        .or_else(|| decode_event(data).map(PoolEvent::ExtendLpLock))
+        .or_else(|| decode_event(data).map(PoolEvent::FinalizeUnsoldTokens))
+        .or_else(|| decode_event(data).map(PoolEvent::WithdrawUnsoldTokens))
+        .or_else(|| decode_event(data).map(PoolEvent::CloseUserPool))
+        .or_else(|| decode_event(data).map(PoolEvent::CloseLaunchPool))
+        .or_else(|| decode_event(data).map(PoolEvent::MigrateLaunchPool))
+        .or_else(|| decode_event(data).map(PoolEvent::CreateTokenMint))
+        .or_else(|| decode_event(data).map(PoolEvent::RegisterReferrer))
+        .or_else(|| decode_event(data).map(PoolEvent::InitializeStaking))
+        .or_else(|| decode_event(data).map(PoolEvent::UpdateStakingTiers))
+        .or_else(|| decode_event(data).map(PoolEvent::Stake))
+        .or_else(|| decode_event(data).map(PoolEvent::Unstake))
+}
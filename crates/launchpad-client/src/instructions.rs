@@ -0,0 +1,1039 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        system_program, sysvar,
+    },
+    InstructionData, ToAccountMetas,
+};
+use anchor_spl::{associated_token, token};
+use encode_sol_team3::{
+    accounts,
+    constants::{CONTENT_HASH_SIZE, STAKE_TIERS},
+    instruction,
+    instructions::TokenMetadata,
+    state::{LaunchPool, ProceedsVesting, SaleRoundAccess, TierThreshold, UnsoldPolicy},
+    ID,
+};
+
+use crate::pda::{
+    escrow_token_account, find_creator_registry, find_event_authority, find_liquidity_escrow,
+    find_lp_lock, find_mint_authority, find_program_data, find_referral_stats, find_referrer,
+    find_stake_account, find_staking_config, find_team_lock, find_token_metadata, find_vote,
+    find_whitelist, lp_lock_token_account, stake_vault, team_lock_token_account, PoolAddresses,
+};
+
+// builds any instruction of the program from its generated accounts and args
+pub fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub struct CreateNativePoolArgs {
+    pub unlock_date: i64,
+    pub pool_size: u64,
+    pub minimum_token_amount: u64,
+    pub maximum_token_amount: u64,
+    pub rate: u64,
+    pub token_mint_decimals: u8,
+    pub proceeds_vesting: Option<ProceedsVesting>,
+    pub unsold_policy: Option<UnsoldPolicy>,
+}
+
+// pool must be derived with the creator's next index, CreatorRegistry::pool_count
+pub fn create_native_pool(pool: &PoolAddresses, args: CreateNativePoolArgs) -> Instruction {
+    build(
+        accounts::CreateFairlaunchPool {
+            creator_registry: find_creator_registry(&pool.creator).0,
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            treasurer: pool.treasurer,
+            treasury: pool.treasury,
+            authority: pool.creator,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::CreateNativePool {
+            unlock_date: args.unlock_date,
+            pool_size: args.pool_size,
+            minimum_token_amount: args.minimum_token_amount,
+            maximum_token_amount: args.maximum_token_amount,
+            rate: args.rate,
+            token_mint_decimals: args.token_mint_decimals,
+            proceeds_vesting: args.proceeds_vesting,
+            unsold_policy: args.unsold_policy,
+        },
+    )
+}
+
+// pools with a team lock fund it on start, so its accounts must be passed,
+// a pool whose supply was minted at creation takes no source token account
+pub fn start_launch_pool(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    source_token_account: Option<Pubkey>,
+    has_team_lock: bool,
+) -> Instruction {
+    let (team_lock, team_lock_token_account) = if has_team_lock {
+        (
+            Some(find_team_lock(&pool.launch_pool).0),
+            Some(team_lock_token_account(&pool.launch_pool, &pool.token_mint)),
+        )
+    } else {
+        (None, None)
+    };
+
+    build(
+        accounts::StartLaunchPool {
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            source_token_account,
+            treasurer: pool.treasurer,
+            treasury: pool.treasury,
+            team_lock,
+            team_lock_token_account,
+            authority,
+            token_program: token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::StartLaunchPool {},
+    )
+}
+
+// a plain buy, without stake tier, NFT gate, allocation voucher or referrer
+pub fn buy_token_with_native(pool: &PoolAddresses, user: Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::BuyTokenWithNative {
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            user_pool: pool.user_pool(&user),
+            vault: pool.vault,
+            stake_account: None,
+            nft_token_account: None,
+            nft_metadata: None,
+            nft_usage: None,
+            instructions_sysvar: None,
+            referral_stats: None,
+            user,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::BuyTokenWithNative { amount },
+    )
+}
+
+// completes a pool that does not seed AMM liquidity
+pub fn complete_launch_pool(pool: &PoolAddresses, authority: Pubkey) -> Instruction {
+    build(
+        accounts::CompleteLaunchPool {
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            authority,
+            treasurer: None,
+            treasury: None,
+            vault: None,
+            amm_program: None,
+            amm_pool: None,
+            amm_lp_mint: None,
+            amm_token_reserve: None,
+            lp_lock: None,
+            lp_token_account: None,
+            liquidity_escrow: None,
+            escrow_token_account: None,
+            system_program: None,
+            token_program: None,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::CompleteLaunchPool {},
+    )
+}
+
+// completes a pool that seeds AMM liquidity configured with configure_liquidity
+pub fn complete_launch_pool_with_liquidity(
+    pool: &PoolAddresses,
+    launch_pool: &LaunchPool,
+    authority: Pubkey,
+    amm_lp_mint: Pubkey,
+    amm_token_reserve: Pubkey,
+) -> Instruction {
+    let (lp_lock, _) = find_lp_lock(&pool.launch_pool);
+    let (liquidity_escrow, _) = find_liquidity_escrow(&pool.launch_pool);
+
+    build(
+        accounts::CompleteLaunchPool {
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            authority,
+            treasurer: Some(pool.treasurer),
+            treasury: Some(pool.treasury),
+            vault: Some(pool.vault),
+            amm_program: Some(launch_pool.amm_program),
+            amm_pool: Some(launch_pool.amm_pool),
+            amm_lp_mint: Some(amm_lp_mint),
+            amm_token_reserve: Some(amm_token_reserve),
+            lp_lock: Some(lp_lock),
+            lp_token_account: Some(lp_lock_token_account(&pool.launch_pool, &amm_lp_mint)),
+            liquidity_escrow: Some(liquidity_escrow),
+            escrow_token_account: Some(escrow_token_account(&pool.launch_pool, &pool.token_mint)),
+            system_program: Some(system_program::ID),
+            token_program: Some(token::ID),
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::CompleteLaunchPool {},
+    )
+}
+
+pub fn claim_token(pool: &PoolAddresses, user: Pubkey) -> Instruction {
+    build(
+        accounts::ClaimToken {
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            treasurer: pool.treasurer,
+            treasury: pool.treasury,
+            user_pool: pool.user_pool(&user),
+            user_token_account: associated_token::get_associated_token_address(
+                &user,
+                &pool.token_mint,
+            ),
+            user,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ClaimToken {},
+    )
+}
+
+pub fn withdraw_proceeds(pool: &PoolAddresses, authority: Pubkey) -> Instruction {
+    build(
+        accounts::WithdrawProceeds {
+            launch_pool: pool.launch_pool,
+            vault: pool.vault,
+            authority,
+            system_program: system_program::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::WithdrawProceeds {},
+    )
+}
+
+// destination is the authority's token account or the AMM reserve, as the pool's policy requires
+pub fn finalize_unsold_tokens(
+    pool: &PoolAddresses,
+    policy: UnsoldPolicy,
+    destination: Option<Pubkey>,
+) -> Instruction {
+    let (authority_token_account, amm_token_reserve) = match policy {
+        UnsoldPolicy::RefundToCreator => (destination, None),
+        UnsoldPolicy::SendToLiquidity => (None, destination),
+        UnsoldPolicy::Burn => (None, None),
+    };
+
+    build(
+        accounts::FinalizeUnsoldTokens {
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            treasurer: pool.treasurer,
+            treasury: pool.treasury,
+            authority_token_account,
+            amm_token_reserve,
+            token_program: token::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::FinalizeUnsoldTokens {},
+    )
+}
+
+// for pools created without an unsold policy, destination is only needed when not burning
+pub fn withdraw_unsold_tokens(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    destination: Option<Pubkey>,
+    burn: bool,
+) -> Instruction {
+    build(
+        accounts::WithdrawUnsoldTokens {
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            treasurer: pool.treasurer,
+            treasury: pool.treasury,
+            destination,
+            authority,
+            token_program: token::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::WithdrawUnsoldTokens { burn },
+    )
+}
+
+pub fn close_user_pool(pool: &PoolAddresses, user: Pubkey) -> Instruction {
+    build(
+        accounts::CloseUserPool {
+            launch_pool: pool.launch_pool,
+            user_pool: pool.user_pool(&user),
+            user,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::CloseUserPool {},
+    )
+}
+
+// the pool metadata is closed with the pool when it was ever set
+pub fn close_launch_pool(pool: &PoolAddresses, authority: Pubkey) -> Instruction {
+    build(
+        accounts::CloseLaunchPool {
+            launch_pool: pool.launch_pool,
+            treasurer: pool.treasurer,
+            treasury: pool.treasury,
+            vault: pool.vault,
+            pool_metadata: pool.pool_metadata(),
+            team_lock: find_team_lock(&pool.launch_pool).0,
+            lp_lock: find_lp_lock(&pool.launch_pool).0,
+            creator: pool.creator,
+            authority,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::CloseLaunchPool {},
+    )
+}
+
+// refunds a buyer of a governed pool whose milestone was rejected
+pub fn reclaim_funds(pool: &PoolAddresses, user: Pubkey) -> Instruction {
+    build(
+        accounts::ReclaimFunds {
+            launch_pool: pool.launch_pool,
+            user_pool: pool.user_pool(&user),
+            vault: pool.vault,
+            user,
+            system_program: system_program::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ReclaimFunds {},
+    )
+}
+
+pub struct PoolMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub website: Option<String>,
+    pub socials: Vec<String>,
+    pub content_hash: [u8; CONTENT_HASH_SIZE],
+}
+
+// creates the pool metadata on the first call, later calls replace it while the pool is Pending
+pub fn update_pool_metadata(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    args: PoolMetadataArgs,
+) -> Instruction {
+    build(
+        accounts::UpdatePoolMetadata {
+            launch_pool: pool.launch_pool,
+            pool_metadata: pool.pool_metadata(),
+            authority,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        instruction::UpdatePoolMetadata {
+            name: args.name,
+            symbol: args.symbol,
+            uri: args.uri,
+            website: args.website,
+            socials: args.socials,
+            content_hash: args.content_hash,
+        },
+    )
+}
+
+// grows the pool, its treasurer and the user pools of the given buyers to the current account layouts
+pub fn migrate_launch_pool(pool: &PoolAddresses, payer: Pubkey, users: &[Pubkey]) -> Instruction {
+    let mut instruction = build(
+        accounts::MigrateLaunchPool {
+            launch_pool: pool.launch_pool,
+            treasurer: pool.treasurer,
+            payer,
+            system_program: system_program::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::MigrateLaunchPool {},
+    );
+    instruction.accounts.extend(users.iter().flat_map(|user| {
+        [
+            AccountMeta::new_readonly(*user, false),
+            AccountMeta::new(pool.user_pool(user), false),
+        ]
+    }));
+    instruction
+}
+
+// the mint's keypair signs, its mint and freeze authority is the creator's mint authority PDA
+pub fn create_token_mint(creator: Pubkey, token_mint: Pubkey, decimals: u8) -> Instruction {
+    build(
+        accounts::CreateTokenMint {
+            token_mint,
+            mint_authority: find_mint_authority(&creator, &token_mint).0,
+            authority: creator,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::CreateTokenMint { decimals },
+    )
+}
+
+pub struct MintPoolSupplyArgs {
+    pub liquidity_amount: u64,
+    pub team_amount: u64,
+    pub token_metadata: Option<TokenMetadata>,
+    pub revoke_authorities: bool,
+}
+
+// the metadata accounts are only passed when the token metadata is set
+pub fn mint_pool_supply(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    args: MintPoolSupplyArgs,
+) -> Instruction {
+    let (metadata, token_metadata_program) = match args.token_metadata {
+        Some(_) => (
+            Some(find_token_metadata(&pool.token_mint).0),
+            Some(anchor_spl::metadata::ID),
+        ),
+        None => (None, None),
+    };
+
+    build(
+        accounts::MintPoolSupply {
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            mint_authority: find_mint_authority(&pool.creator, &pool.token_mint).0,
+            treasurer: pool.treasurer,
+            treasury: pool.treasury,
+            metadata,
+            token_metadata_program,
+            authority,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::MintPoolSupply {
+            liquidity_amount: args.liquidity_amount,
+            team_amount: args.team_amount,
+            token_metadata: args.token_metadata,
+            revoke_authorities: args.revoke_authorities,
+        },
+    )
+}
+
+// creates the mint, the pool and its supply in one transaction, signed by the creator and the
+// new mint's keypair; the liquidity reserve and team lock must match the supply before start
+pub fn create_pool_with_new_mint(
+    pool: &PoolAddresses,
+    args: CreateNativePoolArgs,
+    supply: MintPoolSupplyArgs,
+) -> Vec<Instruction> {
+    vec![
+        create_token_mint(pool.creator, pool.token_mint, args.token_mint_decimals),
+        create_native_pool(pool, args),
+        mint_pool_supply(pool, pool.creator, supply),
+    ]
+}
+
+pub struct SaleRoundArgs {
+    pub rate: u64,
+    pub minimum_token_amount: u64,
+    pub maximum_token_amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub allocation: u64,
+    pub access: SaleRoundAccess,
+}
+
+// rounds are created in order, index is the pool's round_count
+pub fn create_sale_round(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    index: u8,
+    args: SaleRoundArgs,
+) -> Instruction {
+    build(
+        accounts::CreateSaleRound {
+            launch_pool: pool.launch_pool,
+            sale_round: pool.sale_round(index),
+            authority,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::CreateSaleRound {
+            index,
+            rate: args.rate,
+            minimum_token_amount: args.minimum_token_amount,
+            maximum_token_amount: args.maximum_token_amount,
+            start_time: args.start_time,
+            end_time: args.end_time,
+            allocation: args.allocation,
+            access: args.access,
+        },
+    )
+}
+
+pub fn add_wallets_to_whitelist(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    round: u8,
+    wallets: Vec<Pubkey>,
+) -> Instruction {
+    let sale_round = pool.sale_round(round);
+
+    build(
+        accounts::AddWalletsToWhitelist {
+            launch_pool: pool.launch_pool,
+            sale_round,
+            whitelist: find_whitelist(&sale_round).0,
+            authority,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::AddWalletsToWhitelist { wallets },
+    )
+}
+
+pub fn remove_wallet_from_whitelist(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    round: u8,
+    wallet: Pubkey,
+) -> Instruction {
+    let sale_round = pool.sale_round(round);
+
+    build(
+        accounts::RemoveWalletFromWhitelist {
+            launch_pool: pool.launch_pool,
+            sale_round,
+            whitelist: find_whitelist(&sale_round).0,
+            authority,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::RemoveWalletFromWhitelist { wallet },
+    )
+}
+
+// a plain buy in the pool's current round, its whitelist is passed when the round has one
+pub fn buy_token_in_round(
+    pool: &PoolAddresses,
+    round: u8,
+    user: Pubkey,
+    amount: u64,
+    with_whitelist: bool,
+) -> Instruction {
+    let sale_round = pool.sale_round(round);
+
+    build(
+        accounts::BuyTokenInRound {
+            launch_pool: pool.launch_pool,
+            sale_round,
+            whitelist: with_whitelist.then(|| find_whitelist(&sale_round).0),
+            token_mint: pool.token_mint,
+            user_pool: pool.user_pool(&user),
+            vault: pool.vault,
+            stake_account: None,
+            nft_token_account: None,
+            nft_metadata: None,
+            nft_usage: None,
+            instructions_sysvar: None,
+            referral_stats: None,
+            user,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::BuyTokenInRound { amount },
+    )
+}
+
+// closes the current round and opens the next one
+pub fn advance_sale_round(pool: &PoolAddresses, signer: Pubkey, round: u8) -> Instruction {
+    build(
+        accounts::AdvanceSaleRound {
+            launch_pool: pool.launch_pool,
+            current_round: pool.sale_round(round),
+            next_round: pool.sale_round(round + 1),
+            signer,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::AdvanceSaleRound {},
+    )
+}
+
+// only the program's upgrade authority may initialize staking
+pub fn initialize_staking(
+    authority: Pubkey,
+    stake_mint: Pubkey,
+    tier_thresholds: [TierThreshold; STAKE_TIERS],
+) -> Instruction {
+    build(
+        accounts::InitializeStaking {
+            staking_config: find_staking_config().0,
+            stake_mint,
+            stake_vault: stake_vault(&stake_mint),
+            program_data: find_program_data().0,
+            authority,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::InitializeStaking { tier_thresholds },
+    )
+}
+
+pub fn update_staking_tiers(
+    authority: Pubkey,
+    tier_thresholds: [TierThreshold; STAKE_TIERS],
+) -> Instruction {
+    build(
+        accounts::UpdateStakingTiers {
+            staking_config: find_staking_config().0,
+            authority,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::UpdateStakingTiers { tier_thresholds },
+    )
+}
+
+// stakes from the user's associated token account of the stake mint
+pub fn stake(user: Pubkey, stake_mint: Pubkey, amount: u64, lock_duration: i64) -> Instruction {
+    build(
+        accounts::Stake {
+            staking_config: find_staking_config().0,
+            stake_mint,
+            stake_vault: stake_vault(&stake_mint),
+            stake_account: find_stake_account(&user).0,
+            user_token_account: associated_token::get_associated_token_address(&user, &stake_mint),
+            user,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::Stake {
+            amount,
+            lock_duration,
+        },
+    )
+}
+
+pub fn unstake(user: Pubkey, stake_mint: Pubkey, amount: u64) -> Instruction {
+    build(
+        accounts::Unstake {
+            staking_config: find_staking_config().0,
+            stake_mint,
+            stake_vault: stake_vault(&stake_mint),
+            stake_account: find_stake_account(&user).0,
+            user_token_account: associated_token::get_associated_token_address(&user, &stake_mint),
+            user,
+            token_program: token::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::Unstake { amount },
+    )
+}
+
+pub fn configure_tier_allocations(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    tier_caps: [u64; STAKE_TIERS],
+) -> Instruction {
+    build(
+        accounts::ConfigureTierAllocations {
+            launch_pool: pool.launch_pool,
+            authority,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ConfigureTierAllocations { tier_caps },
+    )
+}
+
+pub fn configure_nft_gate(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    collection: Option<Pubkey>,
+    creators: Vec<Pubkey>,
+    single_use: bool,
+) -> Instruction {
+    build(
+        accounts::ConfigureNftGate {
+            launch_pool: pool.launch_pool,
+            authority,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ConfigureNftGate {
+            collection,
+            creators,
+            single_use,
+        },
+    )
+}
+
+pub fn configure_allocation_signer(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    allocation_signer: Option<Pubkey>,
+) -> Instruction {
+    build(
+        accounts::ConfigureAllocationSigner {
+            launch_pool: pool.launch_pool,
+            authority,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ConfigureAllocationSigner { allocation_signer },
+    )
+}
+
+pub fn register_referrer(wallet: Pubkey) -> Instruction {
+    build(
+        accounts::RegisterReferrer {
+            referrer: find_referrer(&wallet).0,
+            wallet,
+            system_program: system_program::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::RegisterReferrer {},
+    )
+}
+
+// a registered referrer is backed by its referrer account, any other wallet by its own position
+pub fn create_referral_stats(
+    pool: &PoolAddresses,
+    wallet: Pubkey,
+    registered: bool,
+) -> Instruction {
+    build(
+        accounts::CreateReferralStats {
+            launch_pool: pool.launch_pool,
+            referral_stats: find_referral_stats(&pool.launch_pool, &wallet).0,
+            referrer: registered.then(|| find_referrer(&wallet).0),
+            user_pool: (!registered).then(|| pool.user_pool(&wallet)),
+            wallet,
+            system_program: system_program::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::CreateReferralStats {},
+    )
+}
+
+pub fn configure_referral(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    referral_bps: u16,
+) -> Instruction {
+    build(
+        accounts::ConfigureReferral {
+            launch_pool: pool.launch_pool,
+            authority,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ConfigureReferral { referral_bps },
+    )
+}
+
+pub fn claim_referral_reward(pool: &PoolAddresses, wallet: Pubkey) -> Instruction {
+    build(
+        accounts::ClaimReferralReward {
+            launch_pool: pool.launch_pool,
+            referral_stats: find_referral_stats(&pool.launch_pool, &wallet).0,
+            vault: pool.vault,
+            wallet,
+            system_program: system_program::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ClaimReferralReward {},
+    )
+}
+
+pub struct ConfigureLiquidityArgs {
+    pub liquidity_bps: u16,
+    pub listing_rate: u64,
+    pub liquidity_token_reserve: u64,
+    pub lp_unlock_date: i64,
+}
+
+// amm_pool and lp_mint belong to the AMM pool of the token mint the liquidity is seeded into
+pub fn configure_liquidity(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    amm_program: Pubkey,
+    amm_pool: Pubkey,
+    lp_mint: Pubkey,
+    args: ConfigureLiquidityArgs,
+) -> Instruction {
+    build(
+        accounts::ConfigureLiquidity {
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            amm_program,
+            amm_pool,
+            lp_mint,
+            lp_lock: find_lp_lock(&pool.launch_pool).0,
+            lp_lock_token_account: lp_lock_token_account(&pool.launch_pool, &lp_mint),
+            liquidity_escrow: find_liquidity_escrow(&pool.launch_pool).0,
+            escrow_token_account: escrow_token_account(&pool.launch_pool, &pool.token_mint),
+            authority,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ConfigureLiquidity {
+            liquidity_bps: args.liquidity_bps,
+            listing_rate: args.listing_rate,
+            liquidity_token_reserve: args.liquidity_token_reserve,
+            lp_unlock_date: args.lp_unlock_date,
+        },
+    )
+}
+
+pub fn withdraw_lp(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    lp_mint: Pubkey,
+    destination: Pubkey,
+) -> Instruction {
+    build(
+        accounts::WithdrawLp {
+            launch_pool: pool.launch_pool,
+            lp_lock: find_lp_lock(&pool.launch_pool).0,
+            lp_lock_token_account: lp_lock_token_account(&pool.launch_pool, &lp_mint),
+            destination,
+            authority,
+            token_program: token::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::WithdrawLp {},
+    )
+}
+
+// the LP lock can only be extended, never shortened
+pub fn extend_lock(pool: &PoolAddresses, authority: Pubkey, unlock_date: i64) -> Instruction {
+    build(
+        accounts::ExtendLpLock {
+            launch_pool: pool.launch_pool,
+            lp_lock: find_lp_lock(&pool.launch_pool).0,
+            authority,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ExtendLock { unlock_date },
+    )
+}
+
+pub fn configure_governance(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    quorum_bps: u16,
+) -> Instruction {
+    build(
+        accounts::ConfigureGovernance {
+            launch_pool: pool.launch_pool,
+            authority,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ConfigureGovernance { quorum_bps },
+    )
+}
+
+// milestones are proposed in order, index is the pool's milestone_count
+pub fn propose_milestone(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    index: u8,
+    amount: u64,
+    deadline: i64,
+) -> Instruction {
+    build(
+        accounts::ProposeMilestone {
+            launch_pool: pool.launch_pool,
+            milestone: pool.milestone(index),
+            authority,
+            system_program: system_program::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ProposeMilestone { amount, deadline },
+    )
+}
+
+pub fn vote_milestone(
+    pool: &PoolAddresses,
+    voter: Pubkey,
+    index: u8,
+    approve: bool,
+) -> Instruction {
+    let milestone = pool.milestone(index);
+
+    build(
+        accounts::VoteMilestone {
+            launch_pool: pool.launch_pool,
+            milestone,
+            user_pool: pool.user_pool(&voter),
+            vote: find_vote(&milestone, &voter).0,
+            voter,
+            system_program: system_program::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::VoteMilestone { approve },
+    )
+}
+
+// anyone executes a milestone after its deadline, authority is the pool's and receives the release
+pub fn execute_milestone(pool: &PoolAddresses, authority: Pubkey, index: u8) -> Instruction {
+    build(
+        accounts::ExecuteMilestone {
+            launch_pool: pool.launch_pool,
+            milestone: pool.milestone(index),
+            vault: pool.vault,
+            authority,
+            system_program: system_program::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ExecuteMilestone {},
+    )
+}
+
+pub fn propose_authority(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    new_authority: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ProposeAuthority {
+            launch_pool: pool.launch_pool,
+            authority,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ProposeAuthority { new_authority },
+    )
+}
+
+pub fn accept_authority(pool: &PoolAddresses, new_authority: Pubkey) -> Instruction {
+    build(
+        accounts::AcceptAuthority {
+            launch_pool: pool.launch_pool,
+            treasurer: pool.treasurer,
+            new_authority,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::AcceptAuthority {},
+    )
+}
+
+// the team tokens are funded when the pool starts
+pub fn configure_team_lock(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    amount: u64,
+    cliff_date: i64,
+    vesting_duration: i64,
+) -> Instruction {
+    build(
+        accounts::ConfigureTeamLock {
+            launch_pool: pool.launch_pool,
+            token_mint: pool.token_mint,
+            team_lock: find_team_lock(&pool.launch_pool).0,
+            team_lock_token_account: team_lock_token_account(&pool.launch_pool, &pool.token_mint),
+            authority,
+            system_program: system_program::ID,
+            token_program: token::ID,
+            associated_token_program: associated_token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::ConfigureTeamLock {
+            amount,
+            cliff_date,
+            vesting_duration,
+        },
+    )
+}
+
+pub fn withdraw_team_tokens(
+    pool: &PoolAddresses,
+    authority: Pubkey,
+    destination: Pubkey,
+) -> Instruction {
+    build(
+        accounts::WithdrawTeamTokens {
+            launch_pool: pool.launch_pool,
+            team_lock: find_team_lock(&pool.launch_pool).0,
+            team_lock_token_account: team_lock_token_account(&pool.launch_pool, &pool.token_mint),
+            destination,
+            authority,
+            token_program: token::ID,
+            event_authority: find_event_authority().0,
+            program: ID,
+        },
+        instruction::WithdrawTeamTokens {},
+    )
+}
@@ -0,0 +1,21 @@
+//! Client for the launchpad program.
+//!
+//! Every instruction of the program can be built with [`instructions::build`]
+//! from the typed `accounts` / `instruction` structs generated by Anchor, which
+//! are re-exported here. The pool lifecycle, sale rounds, staking, referrals,
+//! governance, locks and buyer gates also have builders in [`instructions`]
+//! that derive all PDAs from the creator, pool index and mint. Buys that
+//! present a stake account, NFT, voucher or referrer still go through `build`.
+
+pub mod events;
+pub mod instructions;
+pub mod pda;
+pub mod quote;
+pub mod state;
+pub mod transaction;
+
+pub use encode_sol_team3::{accounts, instruction, ID as PROGRAM_ID};
+pub use events::{decode_pool_event, PoolEvent};
+pub use pda::*;
+pub use quote::*;
+pub use transaction::{transaction_events, transaction_signature};
@@ -0,0 +1,237 @@
+use anchor_lang::{prelude::Pubkey, solana_program::bpf_loader_upgradeable};
+use encode_sol_team3::{
+    constants::{
+        CREATOR_REGISTRY_SEED, LAUNCH_POOL_SEED, LIQUIDITY_ESCROW_SEED, LP_LOCK_SEED,
+        MILESTONE_SEED, MINT_AUTHORITY_SEED, NFT_USAGE_SEED, POOL_METADATA_SEED,
+        REFERRAL_STATS_SEED, REFERRER_SEED, SALE_ROUND_SEED, STAKE_ACCOUNT_SEED,
+        STAKING_CONFIG_SEED, TEAM_LOCK_SEED, TREASURER_SEED, USER_POOL_SEED, VAULT_SEED, VOTE_SEED,
+        WHITELIST_SEED,
+    },
+    ID,
+};
+
+// seed of the authority Anchor signs emit_cpi! events with
+pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+
+pub fn find_creator_registry(creator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CREATOR_REGISTRY_SEED, creator.as_ref()], &ID)
+}
+
+pub fn find_launch_pool(creator: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LAUNCH_POOL_SEED, creator.as_ref(), &index.to_le_bytes()],
+        &ID,
+    )
+}
+
+// pools of the first account layout were derived from their authority and mint
+pub fn find_first_layout_launch_pool(authority: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[LAUNCH_POOL_SEED, authority.as_ref(), token_mint.as_ref()],
+        &ID,
+    )
+}
+
+// every pool a creator has launched, given the pool_count of its CreatorRegistry
+pub fn find_creator_pools(creator: &Pubkey, pool_count: u64) -> Vec<Pubkey> {
+    (0..pool_count)
+        .map(|index| find_launch_pool(creator, index).0)
+        .collect()
+}
+
+pub fn find_treasurer(launch_pool: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TREASURER_SEED, launch_pool.as_ref(), token_mint.as_ref()],
+        &ID,
+    )
+}
+
+pub fn find_vault(launch_pool: &Pubkey, creator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED, launch_pool.as_ref(), creator.as_ref()], &ID)
+}
+
+pub fn find_user_pool(user: &Pubkey, launch_pool: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_POOL_SEED,
+            user.as_ref(),
+            launch_pool.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_lp_lock(launch_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LP_LOCK_SEED, launch_pool.as_ref()], &ID)
+}
+
+// hands the AMM the liquidity amounts when the pool completes
+pub fn find_liquidity_escrow(launch_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LIQUIDITY_ESCROW_SEED, launch_pool.as_ref()], &ID)
+}
+
+pub fn find_team_lock(launch_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TEAM_LOCK_SEED, launch_pool.as_ref()], &ID)
+}
+
+pub fn find_sale_round(launch_pool: &Pubkey, index: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SALE_ROUND_SEED, launch_pool.as_ref(), &[index]], &ID)
+}
+
+pub fn find_whitelist(sale_round: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WHITELIST_SEED, sale_round.as_ref()], &ID)
+}
+
+pub fn find_milestone(launch_pool: &Pubkey, index: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MILESTONE_SEED, launch_pool.as_ref(), &[index]], &ID)
+}
+
+pub fn find_vote(milestone: &Pubkey, voter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOTE_SEED, milestone.as_ref(), voter.as_ref()], &ID)
+}
+
+pub fn find_referrer(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REFERRER_SEED, wallet.as_ref()], &ID)
+}
+
+pub fn find_referral_stats(launch_pool: &Pubkey, referrer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[REFERRAL_STATS_SEED, launch_pool.as_ref(), referrer.as_ref()],
+        &ID,
+    )
+}
+
+pub fn find_nft_usage(launch_pool: &Pubkey, nft_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[NFT_USAGE_SEED, launch_pool.as_ref(), nft_mint.as_ref()],
+        &ID,
+    )
+}
+
+pub fn find_pool_metadata(launch_pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POOL_METADATA_SEED, launch_pool.as_ref()], &ID)
+}
+
+// signs for a mint created by create_token_mint, only the creator's pools can mint with it
+pub fn find_mint_authority(creator: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[MINT_AUTHORITY_SEED, creator.as_ref(), token_mint.as_ref()],
+        &ID,
+    )
+}
+
+// the metaplex metadata account of a mint, owned by the token metadata program
+pub fn find_token_metadata(token_mint: &Pubkey) -> (Pubkey, u8) {
+    anchor_spl::metadata::mpl_token_metadata::accounts::Metadata::find_pda(token_mint)
+}
+
+pub fn find_staking_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKING_CONFIG_SEED], &ID)
+}
+
+pub fn find_stake_account(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STAKE_ACCOUNT_SEED, user.as_ref()], &ID)
+}
+
+// the program's ProgramData account, whose upgrade authority may initialize staking
+pub fn find_program_data() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID)
+}
+
+pub fn find_event_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], &ID)
+}
+
+// the treasury is the treasurer's associated token account
+pub fn treasury(launch_pool: &Pubkey, token_mint: &Pubkey) -> Pubkey {
+    let (treasurer, _) = find_treasurer(launch_pool, token_mint);
+    anchor_spl::associated_token::get_associated_token_address(&treasurer, token_mint)
+}
+
+// the stake vault is the staking config's associated token account of the stake mint
+pub fn stake_vault(stake_mint: &Pubkey) -> Pubkey {
+    let (staking_config, _) = find_staking_config();
+    anchor_spl::associated_token::get_associated_token_address(&staking_config, stake_mint)
+}
+
+pub fn team_lock_token_account(launch_pool: &Pubkey, token_mint: &Pubkey) -> Pubkey {
+    let (team_lock, _) = find_team_lock(launch_pool);
+    anchor_spl::associated_token::get_associated_token_address(&team_lock, token_mint)
+}
+
+pub fn lp_lock_token_account(launch_pool: &Pubkey, lp_mint: &Pubkey) -> Pubkey {
+    let (lp_lock, _) = find_lp_lock(launch_pool);
+    anchor_spl::associated_token::get_associated_token_address(&lp_lock, lp_mint)
+}
+
+pub fn escrow_token_account(launch_pool: &Pubkey, token_mint: &Pubkey) -> Pubkey {
+    let (liquidity_escrow, _) = find_liquidity_escrow(launch_pool);
+    anchor_spl::associated_token::get_associated_token_address(&liquidity_escrow, token_mint)
+}
+
+// every address of one pool, derived once
+#[derive(Clone, Copy, Debug)]
+pub struct PoolAddresses {
+    pub creator: Pubkey,
+    pub token_mint: Pubkey,
+    pub launch_pool: Pubkey,
+    pub treasurer: Pubkey,
+    pub treasury: Pubkey,
+    pub vault: Pubkey,
+}
+
+impl PoolAddresses {
+    pub fn new(creator: Pubkey, index: u64, token_mint: Pubkey) -> Self {
+        let (launch_pool, _) = find_launch_pool(&creator, index);
+        let (treasurer, _) = find_treasurer(&launch_pool, &token_mint);
+        let (vault, _) = find_vault(&launch_pool, &creator);
+
+        Self {
+            creator,
+            token_mint,
+            launch_pool,
+            treasurer,
+            treasury: anchor_spl::associated_token::get_associated_token_address(
+                &treasurer,
+                &token_mint,
+            ),
+            vault,
+        }
+    }
+
+    // the addresses of a pool of the first layout, to migrate it
+    pub fn first_layout(authority: Pubkey, token_mint: Pubkey) -> Self {
+        let (launch_pool, _) = find_first_layout_launch_pool(&authority, &token_mint);
+        let (treasurer, _) = find_treasurer(&launch_pool, &token_mint);
+        let (vault, _) = find_vault(&launch_pool, &authority);
+
+        Self {
+            creator: authority,
+            token_mint,
+            launch_pool,
+            treasurer,
+            treasury: anchor_spl::associated_token::get_associated_token_address(
+                &treasurer,
+                &token_mint,
+            ),
+            vault,
+        }
+    }
+
+    pub fn user_pool(&self, user: &Pubkey) -> Pubkey {
+        find_user_pool(user, &self.launch_pool, &self.token_mint).0
+    }
+
+    pub fn pool_metadata(&self) -> Pubkey {
+        find_pool_metadata(&self.launch_pool).0
+    }
+
+    pub fn sale_round(&self, index: u8) -> Pubkey {
+        find_sale_round(&self.launch_pool, index).0
+    }
+
+    pub fn milestone(&self, index: u8) -> Pubkey {
+        find_milestone(&self.launch_pool, index).0
+    }
+}
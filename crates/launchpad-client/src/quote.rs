@@ -0,0 +1,16 @@
+use encode_sol_team3::state::{LaunchPool, SaleRound};
+
+// lamports a buyer pays for amount tokens, exactly as buy_token_with_native charges,
+// None when the pool has no rate or the price overflows
+pub fn quote(launch_pool: &LaunchPool, amount: u64) -> Option<u64> {
+    launch_pool.checked_user_must_pay_at_rate(amount, launch_pool.rate)
+}
+
+// lamports a buyer pays for amount tokens in a sale round
+pub fn quote_in_round(
+    launch_pool: &LaunchPool,
+    sale_round: &SaleRound,
+    amount: u64,
+) -> Option<u64> {
+    launch_pool.checked_user_must_pay_at_rate(amount, sale_round.rate)
+}
@@ -0,0 +1,48 @@
+use anchor_lang::{AccountDeserialize, Result};
+pub use encode_sol_team3::state::{
+    CreatorRegistry, LaunchPool, LaunchPoolState, LpLock, Milestone, PoolMetadata, ProceedsVesting,
+    SaleRound, TeamLock, Treasurer, UnsoldPolicy, UserPool,
+};
+
+// decodes any program account from its raw data, checking the discriminator
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn decode_launch_pool(data: &[u8]) -> Result<LaunchPool> {
+    decode(data)
+}
+
+pub fn decode_treasurer(data: &[u8]) -> Result<Treasurer> {
+    decode(data)
+}
+
+pub fn decode_user_pool(data: &[u8]) -> Result<UserPool> {
+    decode(data)
+}
+
+pub fn decode_pool_metadata(data: &[u8]) -> Result<PoolMetadata> {
+    decode(data)
+}
+
+pub fn decode_creator_registry(data: &[u8]) -> Result<CreatorRegistry> {
+    decode(data)
+}
+
+pub fn status_name(status: LaunchPoolState) -> &'static str {
+    match status {
+        LaunchPoolState::Pending => "pending",
+        LaunchPoolState::Active => "active",
+        LaunchPoolState::Completed => "completed",
+        LaunchPoolState::Cancelled => "cancelled",
+    }
+}
+
+pub fn unsold_policy_name(policy: Option<UnsoldPolicy>) -> &'static str {
+    match policy {
+        Some(UnsoldPolicy::RefundToCreator) => "refund",
+        Some(UnsoldPolicy::Burn) => "burn",
+        Some(UnsoldPolicy::SendToLiquidity) => "liquidity",
+        None => "authority",
+    }
+}
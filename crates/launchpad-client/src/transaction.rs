@@ -0,0 +1,45 @@
+use solana_transaction_status::{
+    option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta, UiInstruction,
+};
+
+use crate::events::{decode_pool_event, PoolEvent};
+
+// decodes the events the program emitted through self-CPIs in a fetched transaction,
+// in the order they were emitted
+pub fn transaction_events(
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Vec<PoolEvent> {
+    let transaction = &transaction.transaction;
+    let (Some(meta), Some(decoded)) = (&transaction.meta, transaction.transaction.decode()) else {
+        return Vec::new();
+    };
+    let OptionSerializer::Some(inner_instructions) = &meta.inner_instructions else {
+        return Vec::new();
+    };
+    let keys = decoded.message.static_account_keys();
+
+    inner_instructions
+        .iter()
+        .flat_map(|inner| &inner.instructions)
+        .filter_map(|instruction| match instruction {
+            UiInstruction::Compiled(instruction)
+                if keys.get(instruction.program_id_index as usize) == Some(&crate::PROGRAM_ID) =>
+            {
+                bs58::decode(&instruction.data).into_vec().ok()
+            }
+            _ => None,
+        })
+        .filter_map(|data| decode_pool_event(&data))
+        .collect()
+}
+
+// the first signature identifies the transaction
+pub fn transaction_signature(
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Option<String> {
+    let decoded = transaction.transaction.transaction.decode()?;
+    decoded
+        .signatures
+        .first()
+        .map(|signature| signature.to_string())
+}
@@ -0,0 +1,13 @@
+//! Indexer for the launchpad program.
+//!
+//! Transactions are read from a JSON-lines file of `getTransaction` responses
+//! or fetched from an RPC node, and the events the program emits through
+//! `emit_cpi!` are materialised into SQLite: pools, purchases, claims and
+//! per-user positions. [`query`] answers the dashboard's questions from there.
+
+pub mod query;
+pub mod source;
+pub mod store;
+
+pub use source::{fetch_rpc, oldest_signatures, read_jsonl, IndexedTransaction};
+pub use store::Store;
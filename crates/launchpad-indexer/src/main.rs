@@ -0,0 +1,92 @@
+use std::path::PathBuf;
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, Result};
+use clap::{Parser, Subcommand};
+use launchpad_indexer::{fetch_rpc, query, read_jsonl, Store};
+use serde::Serialize;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+
+/// Index launchpad events into SQLite and query them
+#[derive(Parser)]
+#[command(name = "launchpad-indexer", version)]
+struct Cli {
+    /// SQLite database file
+    #[arg(
+        long,
+        global = true,
+        env = "LAUNCHPAD_DB",
+        default_value = "launchpad.sqlite"
+    )]
+    db: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Index a JSON-lines file of getTransaction responses
+    IngestFile { path: PathBuf },
+    /// Index the program's transactions newer than the last indexed one
+    IngestRpc {
+        #[arg(
+            long,
+            short = 'u',
+            env = "LAUNCHPAD_URL",
+            default_value = "http://127.0.0.1:8899"
+        )]
+        url: String,
+        /// Maximum number of transactions to fetch
+        #[arg(long, default_value_t = 10_000)]
+        limit: usize,
+    },
+    /// Print the totals of a pool
+    Pool { pool: Pubkey },
+    /// Print the largest buyers of a pool
+    TopBuyers {
+        pool: Pubkey,
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
+    },
+    /// Print vault_amount after every purchase of a pool
+    VaultSeries { pool: Pubkey },
+}
+
+fn print(value: &impl Serialize) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let mut store = Store::open(&cli.db)?;
+
+    match cli.command {
+        Command::IngestFile { path } => {
+            let transactions = read_jsonl(&path)?;
+            let indexed = store.ingest_all(&transactions)?;
+            println!("indexed {} of {} transactions", indexed, transactions.len());
+        }
+        Command::IngestRpc { url, limit } => {
+            let rpc = RpcClient::new_with_commitment(url, CommitmentConfig::confirmed());
+            let until = store.last_signature()?;
+            let transactions = fetch_rpc(&rpc, until.as_deref(), limit)?;
+            let indexed = store.ingest_all(&transactions)?;
+            println!("indexed {} of {} transactions", indexed, transactions.len());
+        }
+        Command::Pool { pool } => {
+            let totals = query::pool_totals(store.connection(), &pool)?
+                .ok_or_else(|| anyhow!("pool {} is not indexed", pool))?;
+            print(&totals)?;
+        }
+        Command::TopBuyers { pool, limit } => {
+            print(&query::top_buyers(store.connection(), &pool, limit)?)?;
+        }
+        Command::VaultSeries { pool } => {
+            print(&query::vault_series(store.connection(), &pool)?)?;
+        }
+    }
+
+    Ok(())
+}
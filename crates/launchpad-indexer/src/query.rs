@@ -0,0 +1,108 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct PoolTotals {
+    pub address: String,
+    pub status: String,
+    pub pool_size: u64,
+    pub sold_amount: u64,
+    pub vault_amount: u64,
+    pub proceeds_withdrawn: u64,
+    pub buyers: u64,
+    pub purchases: u64,
+    pub claimed_amount: u64,
+    pub refunded_amount: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Buyer {
+    pub user: String,
+    pub amount: u64,
+    pub currency_amount: u64,
+    pub claimed: u64,
+    pub refunded: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct VaultPoint {
+    pub slot: u64,
+    pub timestamp: i64,
+    pub vault_amount: u64,
+}
+
+// None if the pool's creation has not been indexed
+pub fn pool_totals(conn: &Connection, launch_pool: &Pubkey) -> Result<Option<PoolTotals>> {
+    Ok(conn
+        .query_row(
+            "SELECT p.address, p.status, p.pool_size, p.sold_amount, p.vault_amount,
+                    p.proceeds_withdrawn,
+                    (SELECT COUNT(*) FROM positions WHERE launch_pool = p.address),
+                    (SELECT COUNT(*) FROM purchases WHERE launch_pool = p.address),
+                    (SELECT COALESCE(SUM(claimed), 0) FROM positions WHERE launch_pool = p.address),
+                    (SELECT COALESCE(SUM(refunded), 0) FROM positions WHERE launch_pool = p.address)
+             FROM pools p WHERE p.address = ?1",
+            params![launch_pool.to_string()],
+            |row| {
+                Ok(PoolTotals {
+                    address: row.get(0)?,
+                    status: row.get(1)?,
+                    pool_size: row.get(2)?,
+                    sold_amount: row.get(3)?,
+                    vault_amount: row.get(4)?,
+                    proceeds_withdrawn: row.get(5)?,
+                    buyers: row.get(6)?,
+                    purchases: row.get(7)?,
+                    claimed_amount: row.get(8)?,
+                    refunded_amount: row.get(9)?,
+                })
+            },
+        )
+        .optional()?)
+}
+
+// buyers ranked by what they paid
+pub fn top_buyers(conn: &Connection, launch_pool: &Pubkey, limit: usize) -> Result<Vec<Buyer>> {
+    let mut statement = conn.prepare(
+        "SELECT user, amount, currency_amount, claimed, refunded FROM positions
+         WHERE launch_pool = ?1
+         ORDER BY currency_amount DESC, amount DESC, user
+         LIMIT ?2",
+    )?;
+    let buyers = statement
+        .query_map(params![launch_pool.to_string(), limit], |row| {
+            Ok(Buyer {
+                user: row.get(0)?,
+                amount: row.get(1)?,
+                currency_amount: row.get(2)?,
+                claimed: row.get(3)?,
+                refunded: row.get(4)?,
+            })
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+
+    Ok(buyers)
+}
+
+// vault_amount after every purchase, in order
+pub fn vault_series(conn: &Connection, launch_pool: &Pubkey) -> Result<Vec<VaultPoint>> {
+    let mut statement = conn.prepare(
+        "SELECT p.slot, p.timestamp, p.vault_amount FROM purchases p
+         JOIN transactions t ON t.signature = p.signature
+         WHERE p.launch_pool = ?1
+         ORDER BY p.slot, t.id, p.seq",
+    )?;
+    let points = statement
+        .query_map(params![launch_pool.to_string()], |row| {
+            Ok(VaultPoint {
+                slot: row.get(0)?,
+                timestamp: row.get(1)?,
+                vault_amount: row.get(2)?,
+            })
+        })?
+        .collect::<rusqlite::Result<_>>()?;
+
+    Ok(points)
+}
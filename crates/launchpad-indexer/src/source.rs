@@ -0,0 +1,135 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+    str::FromStr,
+};
+
+use anyhow::{Context, Result};
+use launchpad_client::{transaction_events, transaction_signature, PoolEvent, PROGRAM_ID};
+use solana_client::{
+    rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient},
+    rpc_config::RpcTransactionConfig,
+    rpc_response::RpcConfirmedTransactionStatusWithSignature,
+};
+use solana_sdk::signature::Signature;
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding};
+
+// the program events of one successful transaction
+pub struct IndexedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub events: Vec<PoolEvent>,
+}
+
+impl IndexedTransaction {
+    // None for failed transactions, which emit nothing
+    pub fn from_encoded(transaction: &EncodedConfirmedTransactionWithStatusMeta) -> Option<Self> {
+        let meta = transaction.transaction.meta.as_ref()?;
+        if meta.err.is_some() {
+            return None;
+        }
+
+        Some(Self {
+            signature: transaction_signature(transaction)?,
+            slot: transaction.slot,
+            block_time: transaction.block_time,
+            events: transaction_events(transaction),
+        })
+    }
+}
+
+// reads one getTransaction response per line, as saved from the RPC
+pub fn read_jsonl(path: &Path) -> Result<Vec<IndexedTransaction>> {
+    let file = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    let mut transactions = Vec::new();
+
+    for (number, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let transaction: EncodedConfirmedTransactionWithStatusMeta = serde_json::from_str(&line)
+            .with_context(|| format!("{}:{}", path.display(), number + 1))?;
+        transactions.extend(IndexedTransaction::from_encoded(&transaction));
+    }
+
+    Ok(transactions)
+}
+
+// the RPC returns at most this many signatures per page, newest first
+pub const SIGNATURES_PAGE_SIZE: usize = 1000;
+
+// pages back to until and keeps the oldest limit successful signatures, oldest first,
+// so a backlog longer than limit is filled from where the index stopped
+pub fn oldest_signatures(
+    limit: usize,
+    mut fetch_page: impl FnMut(
+        Option<Signature>,
+    ) -> Result<Vec<RpcConfirmedTransactionStatusWithSignature>>,
+) -> Result<Vec<Signature>> {
+    let mut signatures = Vec::new();
+    let mut before = None;
+
+    loop {
+        let page = fetch_page(before)?;
+        let Some(last) = page.last() else {
+            break;
+        };
+
+        before = Some(Signature::from_str(&last.signature)?);
+        let full = page.len() == SIGNATURES_PAGE_SIZE;
+        signatures.extend(
+            page.into_iter()
+                .filter(|status| status.err.is_none())
+                .map(|status| status.signature),
+        );
+        if !full {
+            break;
+        }
+    }
+
+    signatures
+        .iter()
+        .rev()
+        .take(limit)
+        .map(|signature| Ok(Signature::from_str(signature)?))
+        .collect()
+}
+
+// fetches up to limit of the program's transactions after until, oldest first
+pub fn fetch_rpc(
+    rpc: &RpcClient,
+    until: Option<&str>,
+    limit: usize,
+) -> Result<Vec<IndexedTransaction>> {
+    let until = until.map(Signature::from_str).transpose()?;
+    let signatures = oldest_signatures(limit, |before| {
+        Ok(rpc.get_signatures_for_address_with_config(
+            &PROGRAM_ID,
+            GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: Some(SIGNATURES_PAGE_SIZE),
+                commitment: Some(rpc.commitment()),
+            },
+        )?)
+    })?;
+
+    let mut transactions = Vec::new();
+    for signature in &signatures {
+        let transaction = rpc.get_transaction_with_config(
+            signature,
+            RpcTransactionConfig {
+                encoding: Some(UiTransactionEncoding::Base64),
+                commitment: Some(rpc.commitment()),
+                max_supported_transaction_version: Some(0),
+            },
+        )?;
+        transactions.extend(IndexedTransaction::from_encoded(&transaction));
+    }
+
+    Ok(transactions)
+}
@@ -0,0 +1,381 @@
+use std::path::Path;
+
+use anyhow::Result;
+use launchpad_client::{
+    state::{status_name, unsold_policy_name},
+    PoolEvent,
+};
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+use crate::source::IndexedTransaction;
+
+const SCHEMA: &str = "
+-- id follows the ingest order, which breaks ties between transactions of one slot
+CREATE TABLE IF NOT EXISTS transactions (
+    id INTEGER PRIMARY KEY,
+    signature TEXT NOT NULL UNIQUE,
+    slot INTEGER NOT NULL,
+    block_time INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS pools (
+    address TEXT PRIMARY KEY,
+    creator TEXT NOT NULL,
+    token_mint TEXT NOT NULL,
+    pool_index INTEGER NOT NULL,
+    pool_size INTEGER NOT NULL,
+    rate INTEGER NOT NULL,
+    unlock_date INTEGER NOT NULL,
+    unsold_policy TEXT NOT NULL,
+    status TEXT NOT NULL,
+    sold_amount INTEGER NOT NULL DEFAULT 0,
+    vault_amount INTEGER NOT NULL DEFAULT 0,
+    proceeds_withdrawn INTEGER NOT NULL DEFAULT 0,
+    unsold_amount INTEGER,
+    created_at INTEGER NOT NULL,
+    completed_at INTEGER,
+    closed_at INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS purchases (
+    signature TEXT NOT NULL,
+    seq INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    launch_pool TEXT NOT NULL,
+    buyer TEXT NOT NULL,
+    round INTEGER,
+    amount INTEGER NOT NULL,
+    currency_amount INTEGER NOT NULL,
+    vault_amount INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, seq)
+);
+CREATE INDEX IF NOT EXISTS purchases_by_pool ON purchases (launch_pool, slot, seq);
+
+CREATE TABLE IF NOT EXISTS claims (
+    signature TEXT NOT NULL,
+    seq INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    launch_pool TEXT NOT NULL,
+    user TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, seq)
+);
+
+CREATE TABLE IF NOT EXISTS positions (
+    launch_pool TEXT NOT NULL,
+    user TEXT NOT NULL,
+    amount INTEGER NOT NULL DEFAULT 0,
+    currency_amount INTEGER NOT NULL DEFAULT 0,
+    claimed INTEGER NOT NULL DEFAULT 0,
+    refunded INTEGER NOT NULL DEFAULT 0,
+    closed INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (launch_pool, user)
+);
+";
+
+// SQLite database the events are materialised into
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: &Path) -> Result<Self> {
+        Self::new(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::new(Connection::open_in_memory()?)
+    }
+
+    fn new(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    // the newest indexed transaction, where the next RPC fetch resumes
+    pub fn last_signature(&self) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT signature FROM transactions ORDER BY slot DESC, id DESC LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    // applies every event of a transaction atomically, returns false if it was already indexed
+    pub fn ingest(&mut self, transaction: &IndexedTransaction) -> Result<bool> {
+        let db = self.conn.transaction()?;
+
+        let inserted = db.execute(
+            "INSERT OR IGNORE INTO transactions (signature, slot, block_time) VALUES (?1, ?2, ?3)",
+            params![
+                transaction.signature,
+                transaction.slot,
+                transaction.block_time
+            ],
+        )?;
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        for (seq, event) in transaction.events.iter().enumerate() {
+            apply(&db, transaction, seq, event)?;
+        }
+
+        db.commit()?;
+        Ok(true)
+    }
+
+    // returns the number of newly indexed transactions
+    pub fn ingest_all(&mut self, transactions: &[IndexedTransaction]) -> Result<usize> {
+        let mut count = 0;
+        for transaction in transactions {
+            if self.ingest(transaction)? {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+}
+
+fn apply(
+    db: &Transaction,
+    transaction: &IndexedTransaction,
+    seq: usize,
+    event: &PoolEvent,
+) -> Result<()> {
+    let slot = transaction.slot;
+    let signature = &transaction.signature;
+
+    match event {
+        PoolEvent::Create(event) => {
+            db.execute(
+                "INSERT OR REPLACE INTO pools
+                    (address, creator, token_mint, pool_index, pool_size, rate, unlock_date,
+                     unsold_policy, status, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    event.launch_pool.to_string(),
+                    event.creator.to_string(),
+                    event.token_mint.to_string(),
+                    event.index,
+                    event.pool_size,
+                    event.rate,
+                    event.unlock_date,
+                    unsold_policy_name(event.unsold_policy),
+                    status_name(event.status),
+                    event.timestamp,
+                ],
+            )?;
+        }
+        PoolEvent::Start(event) => {
+            db.execute(
+                "UPDATE pools SET status = ?2 WHERE address = ?1",
+                params![event.launch_pool.to_string(), status_name(event.status)],
+            )?;
+        }
+        PoolEvent::Buy(event) => {
+            let launch_pool = event.launch_pool.to_string();
+            let buyer = event.buyer.to_string();
+            db.execute(
+                "INSERT INTO purchases
+                    (signature, seq, slot, launch_pool, buyer, round, amount, currency_amount,
+                     vault_amount, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, NULL, ?6, ?7, ?8, ?9)",
+                params![
+                    signature,
+                    seq,
+                    slot,
+                    launch_pool,
+                    buyer,
+                    event.amount,
+                    event.currency_amount,
+                    event.vault_amount,
+                    event.timestamp,
+                ],
+            )?;
+            record_purchase(
+                db,
+                &launch_pool,
+                &buyer,
+                event.amount,
+                event.currency_amount,
+                event.vault_amount,
+            )?;
+        }
+        PoolEvent::BuyInRound(event) => {
+            let launch_pool = event.launch_pool.to_string();
+            let buyer = event.buyer.to_string();
+            db.execute(
+                "INSERT INTO purchases
+                    (signature, seq, slot, launch_pool, buyer, round, amount, currency_amount,
+                     vault_amount, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    signature,
+                    seq,
+                    slot,
+                    launch_pool,
+                    buyer,
+                    event.round,
+                    event.amount,
+                    event.currency_amount,
+                    event.vault_amount,
+                    event.timestamp,
+                ],
+            )?;
+            record_purchase(
+                db,
+                &launch_pool,
+                &buyer,
+                event.amount,
+                event.currency_amount,
+                event.vault_amount,
+            )?;
+        }
+        PoolEvent::AdvanceSaleRound(event) => {
+            db.execute(
+                "UPDATE pools SET status = ?2 WHERE address = ?1",
+                params![event.launch_pool.to_string(), status_name(event.status)],
+            )?;
+        }
+        PoolEvent::Complete(event) => {
+            db.execute(
+                "UPDATE pools SET status = ?2, sold_amount = ?3, vault_amount = ?4, completed_at = ?5
+                 WHERE address = ?1",
+                params![
+                    event.launch_pool.to_string(),
+                    status_name(event.status),
+                    event.sold_amount,
+                    event.vault_amount,
+                    event.timestamp,
+                ],
+            )?;
+        }
+        PoolEvent::Claim(event) => {
+            let launch_pool = event.launch_pool.to_string();
+            let user = event.user.to_string();
+            db.execute(
+                "INSERT INTO claims (signature, seq, slot, launch_pool, user, amount, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    signature,
+                    seq,
+                    slot,
+                    launch_pool,
+                    user,
+                    event.amount,
+                    event.timestamp
+                ],
+            )?;
+            db.execute(
+                "UPDATE positions SET claimed = ?3 WHERE launch_pool = ?1 AND user = ?2",
+                params![launch_pool, user, event.claimed],
+            )?;
+        }
+        PoolEvent::WithdrawProceeds(event) => {
+            db.execute(
+                "UPDATE pools SET proceeds_withdrawn = ?2 WHERE address = ?1",
+                params![event.launch_pool.to_string(), event.proceeds_withdrawn],
+            )?;
+        }
+        PoolEvent::ReclaimFunds(event) => {
+            db.execute(
+                "UPDATE positions SET refunded = refunded + ?3 WHERE launch_pool = ?1 AND user = ?2",
+                params![event.launch_pool.to_string(), event.user.to_string(), event.amount],
+            )?;
+        }
+        PoolEvent::FinalizeUnsoldTokens(event) => {
+            db.execute(
+                "UPDATE pools SET unsold_amount = ?2 WHERE address = ?1",
+                params![event.launch_pool.to_string(), event.amount],
+            )?;
+        }
+        PoolEvent::WithdrawUnsoldTokens(event) => {
+            db.execute(
+                "UPDATE pools SET unsold_amount = ?2 WHERE address = ?1",
+                params![event.launch_pool.to_string(), event.amount],
+            )?;
+        }
+        PoolEvent::CloseUserPool(event) => {
+            db.execute(
+                "UPDATE positions SET closed = 1 WHERE launch_pool = ?1 AND user = ?2",
+                params![event.launch_pool.to_string(), event.user.to_string()],
+            )?;
+        }
+        PoolEvent::CloseLaunchPool(event) => {
+            db.execute(
+                "UPDATE pools SET status = ?2, closed_at = ?3 WHERE address = ?1",
+                params![
+                    event.launch_pool.to_string(),
+                    status_name(event.status),
+                    event.timestamp
+                ],
+            )?;
+        }
+        // neither pools nor positions keep configuration, round, referral, governance, authority,
+        // lock, minting, layout version or staking state
+        PoolEvent::MintPoolSupply(_)
+        | PoolEvent::ConfigureGovernance(_)
+        | PoolEvent::ConfigureReferral(_)
+        | PoolEvent::ConfigureTierAllocations(_)
+        | PoolEvent::ConfigureNftGate(_)
+        | PoolEvent::ConfigureAllocationSigner(_)
+        | PoolEvent::ConfigureLiquidity(_)
+        | PoolEvent::ConfigureTeamLock(_)
+        | PoolEvent::CreateSaleRound(_)
+        | PoolEvent::AddWalletsToWhitelist(_)
+        | PoolEvent::RemoveWalletFromWhitelist(_)
+        | PoolEvent::ReferredBuy(_)
+        | PoolEvent::CreateReferralStats(_)
+        | PoolEvent::ReferralRewardClaimed(_)
+        | PoolEvent::ProposeMilestone(_)
+        | PoolEvent::VoteMilestone(_)
+        | PoolEvent::ExecuteMilestone(_)
+        | PoolEvent::ProposeAuthority(_)
+        | PoolEvent::AcceptAuthority(_)
+        | PoolEvent::WithdrawTeamTokens(_)
+        | PoolEvent::WithdrawLp(_)
+        | PoolEvent::ExtendLpLock(_)
+        | PoolEvent::MigrateLaunchPool(_)
+        | PoolEvent::CreateTokenMint(_)
+        | PoolEvent::RegisterReferrer(_)
+        | PoolEvent::InitializeStaking(_)
+        | PoolEvent::UpdateStakingTiers(_)
+        | PoolEvent::Stake(_)
+        | PoolEvent::Unstake(_) => {}
+    }
+
+    Ok(())
+}
+
+// a purchase adds to the buyer's position and the pool's running totals
+fn record_purchase(
+    db: &Transaction,
+    launch_pool: &str,
+    buyer: &str,
+    amount: u64,
+    currency_amount: u64,
+    vault_amount: u64,
+) -> Result<()> {
+    db.execute(
+        "INSERT INTO positions (launch_pool, user, amount, currency_amount) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT (launch_pool, user) DO UPDATE SET
+            amount = amount + excluded.amount,
+            currency_amount = currency_amount + excluded.currency_amount",
+        params![launch_pool, buyer, amount, currency_amount],
+    )?;
+    db.execute(
+        "UPDATE pools SET sold_amount = sold_amount + ?2, vault_amount = ?3 WHERE address = ?1",
+        params![launch_pool, amount, vault_amount],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,206 @@
+use std::{path::Path, str::FromStr};
+
+use anchor_lang::prelude::Pubkey;
+use launchpad_indexer::{
+    oldest_signatures, query, read_jsonl, source::SIGNATURES_PAGE_SIZE, IndexedTransaction, Store,
+};
+use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
+use solana_sdk::{
+    instruction::InstructionError, signature::Signature, transaction::TransactionError,
+};
+
+const TOKEN: u64 = 1_000_000_000;
+const SOL: u64 = 1_000_000_000;
+
+// the fixture runs one pool from creation to close, with alice and bob buying in the same
+// slot, a failed transaction, a purchase in another pool and three closes in the last slot
+const POOL: &str = "B29vauY3g1Rp9ijcDj5pUDqjSmxpgyNnb3mwkKpptCgM";
+const OTHER_POOL: &str = "HTg52MaXStTcUPFUKF3hAkHuuv4MVcrPE1f52bfG7Tqq";
+const ALICE: &str = "9hSR6S7WPtxmTojgo6GG3k4yDPecgJY292j7xrsUGWBu";
+const BOB: &str = "GyGKxMyg1p9SsHfm15MkNUu1u9TN2JtTspcdmrtGUdse";
+const CAROL: &str = "EdmxWPmx2WH6WgFfTdu9xfkYf3k1g5wD1zccTVySEEh1";
+
+fn fixture() -> Vec<IndexedTransaction> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/pool_lifecycle.jsonl");
+    read_jsonl(&path).unwrap()
+}
+
+fn indexed() -> Store {
+    let mut store = Store::open_in_memory().unwrap();
+    store.ingest_all(&fixture()).unwrap();
+    store
+}
+
+fn pubkey(address: &str) -> Pubkey {
+    Pubkey::from_str(address).unwrap()
+}
+
+#[test]
+fn failed_transactions_are_skipped() {
+    let transactions = fixture();
+    assert_eq!(transactions.len(), 16);
+    assert!(transactions
+        .iter()
+        .all(|transaction| !transaction.events.is_empty()));
+}
+
+#[test]
+fn ingesting_twice_indexes_each_transaction_once() {
+    let transactions = fixture();
+    let mut store = Store::open_in_memory().unwrap();
+    assert_eq!(store.ingest_all(&transactions).unwrap(), 16);
+    assert_eq!(store.ingest_all(&transactions).unwrap(), 0);
+
+    let totals = query::pool_totals(store.connection(), &pubkey(POOL))
+        .unwrap()
+        .unwrap();
+    assert_eq!(totals.purchases, 3);
+    assert_eq!(totals.sold_amount, 70 * TOKEN);
+}
+
+#[test]
+fn pool_totals_follow_the_pool_to_its_close() {
+    let store = indexed();
+
+    let totals = query::pool_totals(store.connection(), &pubkey(POOL))
+        .unwrap()
+        .unwrap();
+    assert_eq!(totals.status, "completed");
+    assert_eq!(totals.pool_size, 1_000 * TOKEN);
+    assert_eq!(totals.sold_amount, 70 * TOKEN);
+    assert_eq!(totals.vault_amount, SOL * 7 / 10);
+    assert_eq!(totals.proceeds_withdrawn, SOL * 7 / 10);
+    assert_eq!(totals.buyers, 2);
+    assert_eq!(totals.purchases, 3);
+    assert_eq!(totals.claimed_amount, 70 * TOKEN);
+    assert_eq!(totals.refunded_amount, 0);
+
+    let closed_at: Option<i64> = store
+        .connection()
+        .query_row(
+            "SELECT closed_at FROM pools WHERE address = ?1",
+            [POOL],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert!(closed_at.is_some());
+
+    // the other pool only saw carol's purchase
+    let totals = query::pool_totals(store.connection(), &pubkey(OTHER_POOL))
+        .unwrap()
+        .unwrap();
+    assert_eq!(totals.status, "active");
+    assert_eq!(totals.sold_amount, 90 * TOKEN);
+    assert_eq!(totals.buyers, 1);
+
+    assert!(
+        query::pool_totals(store.connection(), &Pubkey::new_unique())
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn top_buyers_are_ranked_by_what_they_paid() {
+    let store = indexed();
+
+    let buyers = query::top_buyers(store.connection(), &pubkey(POOL), 10).unwrap();
+    let users: Vec<_> = buyers.iter().map(|buyer| buyer.user.as_str()).collect();
+    assert_eq!(users, [BOB, ALICE]);
+    assert_eq!(buyers[0].currency_amount, SOL * 4 / 10);
+    assert_eq!(buyers[1].amount, 30 * TOKEN);
+    assert_eq!(buyers[1].currency_amount, SOL * 3 / 10);
+    assert_eq!(buyers[1].claimed, 30 * TOKEN);
+
+    let buyers = query::top_buyers(store.connection(), &pubkey(POOL), 1).unwrap();
+    assert_eq!(buyers.len(), 1);
+    let buyers = query::top_buyers(store.connection(), &pubkey(OTHER_POOL), 10).unwrap();
+    assert_eq!(buyers[0].user, CAROL);
+}
+
+#[test]
+fn vault_series_keeps_the_order_of_purchases_within_a_slot() {
+    let store = indexed();
+
+    let series: Vec<_> = query::vault_series(store.connection(), &pubkey(POOL))
+        .unwrap()
+        .into_iter()
+        .map(|point| (point.slot, point.vault_amount))
+        .collect();
+    assert_eq!(
+        series,
+        [(110, SOL / 10), (110, SOL / 2), (120, SOL * 7 / 10)]
+    );
+}
+
+#[test]
+fn last_signature_breaks_slot_ties_by_ingest_order() {
+    let transactions = fixture();
+    let mut store = Store::open_in_memory().unwrap();
+    assert_eq!(store.last_signature().unwrap(), None);
+
+    // alice's close, bob's close and the pool's close all landed in the last slot
+    let (head, tail) = transactions.split_at(transactions.len() - 1);
+    assert!(head[head.len() - 2..]
+        .iter()
+        .all(|transaction| transaction.slot == tail[0].slot));
+
+    store.ingest_all(head).unwrap();
+    assert_eq!(
+        store.last_signature().unwrap().as_deref(),
+        Some(head[head.len() - 1].signature.as_str())
+    );
+    store.ingest_all(tail).unwrap();
+    assert_eq!(
+        store.last_signature().unwrap().as_deref(),
+        Some(tail[0].signature.as_str())
+    );
+}
+
+fn status(signature: Signature, failed: bool) -> RpcConfirmedTransactionStatusWithSignature {
+    RpcConfirmedTransactionStatusWithSignature {
+        signature: signature.to_string(),
+        slot: 0,
+        err: failed.then_some(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(0),
+        )),
+        memo: None,
+        block_time: None,
+        confirmation_status: None,
+    }
+}
+
+#[test]
+fn paging_reaches_until_before_keeping_the_oldest() {
+    // 2500 signatures since until, newest first, and the oldest one failed
+    let history: Vec<_> = (0..2_500)
+        .map(|index| status(Signature::new_unique(), index == 2_499))
+        .collect();
+    let mut pages = 0;
+
+    let signatures = oldest_signatures(10, |before| {
+        pages += 1;
+        let start = match before {
+            Some(before) => {
+                history
+                    .iter()
+                    .position(|status| status.signature == before.to_string())
+                    .unwrap()
+                    + 1
+            }
+            None => 0,
+        };
+        let end = (start + SIGNATURES_PAGE_SIZE).min(history.len());
+        Ok(history[start..end].to_vec())
+    })
+    .unwrap();
+
+    assert_eq!(pages, 3);
+    let expected: Vec<_> = history[2_489..2_499]
+        .iter()
+        .rev()
+        .map(|status| Signature::from_str(&status.signature).unwrap())
+        .collect();
+    assert_eq!(signatures, expected);
+}
@@ -0,0 +1,205 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::{system_program, sysvar},
+    InstructionData, ToAccountMetas,
+};
+use anchor_spl::{associated_token, token};
+use encode_sol_team3::state::LaunchPool;
+use launchpad_client::{
+    instructions::{self, ConfigureLiquidityArgs},
+    PoolAddresses,
+};
+use mock_amm::{LP_MINT_SEED, POOL_SEED};
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+
+use crate::{associated_token, Harness, PoolFixture, PoolParams, TOKEN};
+
+// what a referral pool pays its referrers of what their buyers pay
+pub const REFERRAL_BPS: u16 = 500;
+// how long the team tokens of a team locked pool vest after the cliff
+pub const TEAM_VESTING_DURATION: i64 = 1_000;
+
+// the mock AMM pool of a mint and its LP mint and token reserve
+pub struct AmmPool {
+    pub pool: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_reserve: Pubkey,
+}
+
+impl Harness {
+    // an active pool configured by the creator before it started, configure builds the
+    // configuration instruction from the pool and its authority
+    pub async fn configured_pool(
+        &mut self,
+        params: PoolParams,
+        configure: impl FnOnce(&PoolAddresses, Pubkey) -> Instruction,
+    ) -> PoolFixture {
+        let fixture = self.create_pool(params).await;
+        let instruction = configure(&fixture.pool, fixture.creator.pubkey());
+        self.process(&[instruction], &[&fixture.creator])
+            .await
+            .unwrap();
+        self.start(&fixture).await.unwrap();
+        fixture
+    }
+
+    // a completed pool past its unlock date, where alice bought 10 tokens
+    pub async fn claimable_pool(&mut self) -> (PoolFixture, Keypair) {
+        let fixture = self.active_pool(PoolParams::default()).await;
+        let alice = self.buyer().await;
+        self.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+        self.complete(&fixture).await.unwrap();
+        self.warp_to(fixture.unlock_date).await;
+        (fixture, alice)
+    }
+
+    // an active pool whose buyers need a voucher of signer
+    pub async fn voucher_pool(&mut self, signer: Pubkey) -> PoolFixture {
+        self.configured_pool(PoolParams::default(), |pool, authority| {
+            instructions::configure_allocation_signer(pool, authority, Some(signer))
+        })
+        .await
+    }
+
+    // a governed pool with a 50% quorum, completed after alice bought 10 tokens and bob 50
+    pub async fn governed_pool(&mut self) -> (PoolFixture, Keypair, Keypair) {
+        let fixture = self
+            .configured_pool(PoolParams::default(), |pool, authority| {
+                instructions::configure_governance(pool, authority, 5_000)
+            })
+            .await;
+        let alice = self.buyer().await;
+        let bob = self.buyer().await;
+        self.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+        self.buy(&fixture, &bob, 50 * TOKEN).await.unwrap();
+        self.complete(&fixture).await.unwrap();
+        (fixture, alice, bob)
+    }
+
+    // an active pool that pays referrers REFERRAL_BPS of what their buyers pay
+    pub async fn referral_pool(&mut self) -> PoolFixture {
+        self.configured_pool(PoolParams::default(), |pool, authority| {
+            instructions::configure_referral(pool, authority, REFERRAL_BPS)
+        })
+        .await
+    }
+
+    // an active pool whose buyers must hold an NFT of the collection or creators
+    pub async fn gated_pool(
+        &mut self,
+        collection: Option<Pubkey>,
+        creators: Vec<Pubkey>,
+        single_use: bool,
+    ) -> PoolFixture {
+        self.configured_pool(PoolParams::default(), |pool, authority| {
+            instructions::configure_nft_gate(pool, authority, collection, creators, single_use)
+        })
+        .await
+    }
+
+    // an active pool that locked 100 team tokens, vesting over TEAM_VESTING_DURATION after
+    // its cliff, and the cliff date
+    pub async fn team_locked_pool(&mut self) -> (PoolFixture, i64) {
+        let fixture = self.create_pool(PoolParams::default()).await;
+        let cliff_date = fixture.unlock_date + 1_000;
+
+        let instructions = [
+            instructions::configure_team_lock(
+                &fixture.pool,
+                fixture.creator.pubkey(),
+                100 * TOKEN,
+                cliff_date,
+                TEAM_VESTING_DURATION,
+            ),
+            instructions::start_launch_pool(
+                &fixture.pool,
+                fixture.creator.pubkey(),
+                Some(fixture.creator_tokens),
+                true,
+            ),
+        ];
+        self.process(&instructions, &[&fixture.creator])
+            .await
+            .unwrap();
+        (fixture, cliff_date)
+    }
+
+    pub async fn create_amm_pool(&mut self, token_mint: Pubkey) -> AmmPool {
+        let (pool, _) =
+            Pubkey::find_program_address(&[POOL_SEED, token_mint.as_ref()], &mock_amm::ID);
+        let (lp_mint, _) =
+            Pubkey::find_program_address(&[LP_MINT_SEED, pool.as_ref()], &mock_amm::ID);
+        let token_reserve = associated_token(&pool, &token_mint);
+
+        let instruction = Instruction {
+            program_id: mock_amm::ID,
+            accounts: mock_amm::accounts::InitializePool {
+                pool,
+                token_mint,
+                lp_mint,
+                token_reserve,
+                payer: self.payer(),
+                system_program: system_program::ID,
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: mock_amm::instruction::InitializePool {}.data(),
+        };
+        self.process(&[instruction], &[]).await.unwrap();
+
+        AmmPool {
+            pool,
+            lp_mint,
+            token_reserve,
+        }
+    }
+
+    // an active pool that lists half its proceeds at 100 tokens per SOL
+    pub async fn liquidity_pool(&mut self) -> (PoolFixture, AmmPool) {
+        let fixture = self.create_pool(PoolParams::default()).await;
+        let amm = self.create_amm_pool(fixture.mint()).await;
+        let configure = instructions::configure_liquidity(
+            &fixture.pool,
+            fixture.creator.pubkey(),
+            mock_amm::ID,
+            amm.pool,
+            amm.lp_mint,
+            ConfigureLiquidityArgs {
+                liquidity_bps: 5_000,
+                listing_rate: 100,
+                liquidity_token_reserve: 100 * TOKEN,
+                lp_unlock_date: fixture.unlock_date + 86_400,
+            },
+        );
+        self.process(&[configure], &[&fixture.creator])
+            .await
+            .unwrap();
+        self.start(&fixture).await.unwrap();
+        (fixture, amm)
+    }
+
+    // a completed liquidity pool whose LP tokens sit in the lock
+    pub async fn locked_liquidity_pool(&mut self) -> (PoolFixture, AmmPool) {
+        let (fixture, amm) = self.liquidity_pool().await;
+        let alice = self.buyer().await;
+        self.buy(&fixture, &alice, 100 * TOKEN).await.unwrap();
+
+        let launch_pool: LaunchPool = self.account(&fixture.launch_pool()).await;
+        let complete = instructions::complete_launch_pool_with_liquidity(
+            &fixture.pool,
+            &launch_pool,
+            fixture.creator.pubkey(),
+            amm.lp_mint,
+            amm.token_reserve,
+        );
+        self.process(&[complete], &[&fixture.creator])
+            .await
+            .unwrap();
+        (fixture, amm)
+    }
+}
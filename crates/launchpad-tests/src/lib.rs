@@ -0,0 +1,666 @@
+//! In-process tests of the launchpad program.
+//!
+//! The program is loaded from `target/deploy/encode_sol_team3.so` after an
+//! `anchor build`, and runs natively inside the test process when no build is
+//! there, which skips the SBF compute and memory limits. The mock AMM and a
+//! stand-in for the token metadata program always run natively next to it.
+//! [`Harness`] wraps a `solana-program-test` bank with helpers to fund
+//! wallets, mint tokens, warp the clock and drive a pool through its
+//! lifecycle, and builds the configured pools each feature is tested on, such
+//! as [`Harness::governed_pool`] or [`Harness::liquidity_pool`].
+//! [`assert_error`] and [`assert_anchor_error`] check the exact custom error
+//! code of a failure, [`Harness::assert_emitted`] the events a natively
+//! running pool emitted and [`Harness::compute_units`] what instructions cost
+//! against the SBF build.
+
+use anchor_lang::{
+    error::ErrorCode,
+    event::EVENT_IX_TAG_LE,
+    prelude::{AccountInfo, Pubkey},
+    solana_program::entrypoint::ProgramResult,
+    AccountDeserialize, AccountSerialize, AnchorSerialize, InstructionData, ToAccountMetas,
+};
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    metadata::mpl_token_metadata::{
+        accounts::Metadata,
+        types::{Collection, Creator, Key, TokenStandard},
+        ID as TOKEN_METADATA_ID,
+    },
+    token::{spl_token, TokenAccount},
+};
+use encode_sol_team3::{
+    errors::MyError,
+    state::{LaunchPool, ProceedsVesting, UnsoldPolicy},
+};
+use launchpad_client::{
+    decode_pool_event, find_program_data, find_token_metadata, instructions, PoolAddresses,
+    PoolEvent, PROGRAM_ID,
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::AccountSharedData,
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    program_pack::Pack,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use spl_associated_token_account::instruction::create_associated_token_account;
+use std::{path::Path, sync::Mutex};
+
+mod fixtures;
+mod token_metadata;
+
+pub use fixtures::*;
+
+// one whole token of the 9 decimals test mint
+pub const TOKEN: u64 = 1_000_000_000;
+pub const DECIMALS: u8 = 9;
+pub const SOL: u64 = 1_000_000_000;
+
+pub type TxResult = Result<(), BanksClientError>;
+
+pub struct PoolParams {
+    pub unlock_in: i64,
+    pub pool_size: u64,
+    pub minimum_token_amount: u64,
+    pub maximum_token_amount: u64,
+    pub rate: u64,
+    pub proceeds_vesting: Option<ProceedsVesting>,
+    pub unsold_policy: Option<UnsoldPolicy>,
+}
+
+impl Default for PoolParams {
+    fn default() -> Self {
+        Self {
+            unlock_in: 3_600,
+            pool_size: 1_000 * TOKEN,
+            minimum_token_amount: TOKEN,
+            maximum_token_amount: 100 * TOKEN,
+            rate: 100,
+            proceeds_vesting: None,
+            unsold_policy: Some(UnsoldPolicy::RefundToCreator),
+        }
+    }
+}
+
+// a created pool, its creator and the creator's supply of the pool token
+pub struct PoolFixture {
+    pub creator: Keypair,
+    pub pool: PoolAddresses,
+    pub creator_tokens: Pubkey,
+    pub unlock_date: i64,
+}
+
+impl PoolFixture {
+    pub fn launch_pool(&self) -> Pubkey {
+        self.pool.launch_pool
+    }
+
+    pub fn mint(&self) -> Pubkey {
+        self.pool.token_mint
+    }
+}
+
+pub struct Harness {
+    pub context: ProgramTestContext,
+    native: bool,
+}
+
+// data of the emit_cpi! self-CPIs seen by the native entry point, as the banks client
+// returns no inner instructions
+static EVENTS: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+
+impl Harness {
+    pub async fn new() -> Self {
+        if std::env::var("SBF_OUT_DIR").is_err() && std::env::var("BPF_OUT_DIR").is_err() {
+            std::env::set_var(
+                "SBF_OUT_DIR",
+                concat!(env!("CARGO_MANIFEST_DIR"), "/../../target/deploy"),
+            );
+        }
+
+        let out_dir = std::env::var("SBF_OUT_DIR")
+            .or_else(|_| std::env::var("BPF_OUT_DIR"))
+            .unwrap();
+        let mut program_test = ProgramTest::default();
+        let native = !Path::new(&out_dir).join("encode_sol_team3.so").exists();
+        program_test.prefer_bpf(!native);
+        program_test.add_program(
+            "encode_sol_team3",
+            PROGRAM_ID,
+            processor!(process_instruction),
+        );
+        program_test.add_program(
+            "mock_amm",
+            mock_amm::ID,
+            processor!(process_amm_instruction),
+        );
+        program_test.add_program(
+            "mpl_token_metadata",
+            TOKEN_METADATA_ID,
+            processor!(token_metadata::process_instruction),
+        );
+
+        Self {
+            context: program_test.start_with_context().await,
+            native,
+        }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.context.payer.pubkey()
+    }
+
+    // signs the instructions with the harness payer paying fees, on a fresh blockhash
+    // so that repeating an identical transaction is never rejected as a duplicate
+    async fn transaction(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Transaction {
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+        let mut all_signers = vec![&self.context.payer];
+        all_signers.extend_from_slice(signers);
+
+        Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.context.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        )
+    }
+
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> TxResult {
+        let transaction = self.transaction(instructions, signers).await;
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+    }
+
+    // compute units the instructions consume in a simulation that must succeed, none when
+    // the program runs natively and nothing is metered
+    pub async fn compute_units(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Option<u64> {
+        if self.native {
+            return None;
+        }
+        let transaction = self.transaction(instructions, signers).await;
+        let simulation = self
+            .context
+            .banks_client
+            .simulate_transaction(transaction)
+            .await
+            .unwrap();
+        simulation.result.unwrap().unwrap();
+        simulation
+            .simulation_details
+            .map(|details| details.units_consumed)
+    }
+
+    // events emitted for a launch pool, oldest first, including those of transactions that
+    // failed after emitting them; only a natively running program records any
+    pub fn events(&self, launch_pool: &Pubkey) -> Vec<PoolEvent> {
+        EVENTS
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|data| decode_pool_event(data))
+            .filter(|event| event.launch_pool() == *launch_pool)
+            .collect()
+    }
+
+    // checks that the pool emitted an event matching f, skipped against the SBF build
+    #[track_caller]
+    pub fn assert_emitted(&self, launch_pool: &Pubkey, f: impl Fn(&PoolEvent) -> bool) {
+        if self.native {
+            assert!(
+                self.events(launch_pool).iter().any(f),
+                "no matching event emitted for {}",
+                launch_pool
+            );
+        }
+    }
+
+    pub async fn send(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        data: impl InstructionData,
+        signers: &[&Keypair],
+    ) -> TxResult {
+        self.process(&[instructions::build(accounts, data)], signers)
+            .await
+    }
+
+    pub async fn wallet(&mut self, lamports: u64) -> Keypair {
+        let wallet = Keypair::new();
+        let payer = self.payer();
+        self.process(
+            &[system_instruction::transfer(
+                &payer,
+                &wallet.pubkey(),
+                lamports,
+            )],
+            &[],
+        )
+        .await
+        .unwrap();
+        wallet
+    }
+
+    pub async fn create_mint(&mut self) -> Pubkey {
+        let mint = Keypair::new();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let payer = self.payer();
+
+        self.process(
+            &[
+                system_instruction::create_account(
+                    &payer,
+                    &mint.pubkey(),
+                    rent.minimum_balance(spl_token::state::Mint::LEN),
+                    spl_token::state::Mint::LEN as u64,
+                    &spl_token::ID,
+                ),
+                spl_token::instruction::initialize_mint(
+                    &spl_token::ID,
+                    &mint.pubkey(),
+                    &payer,
+                    None,
+                    DECIMALS,
+                )
+                .unwrap(),
+            ],
+            &[&mint],
+        )
+        .await
+        .unwrap();
+        mint.pubkey()
+    }
+
+    // creates the owner's associated token account and mints amount into it
+    pub async fn mint_to(&mut self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+        let payer = self.payer();
+        let account = get_associated_token_address(owner, mint);
+        let mut instructions = Vec::new();
+
+        if self
+            .context
+            .banks_client
+            .get_account(account)
+            .await
+            .unwrap()
+            .is_none()
+        {
+            instructions.push(create_associated_token_account(
+                &payer,
+                owner,
+                mint,
+                &spl_token::ID,
+            ));
+        }
+        if amount > 0 {
+            instructions.push(
+                spl_token::instruction::mint_to(
+                    &spl_token::ID,
+                    mint,
+                    &account,
+                    &payer,
+                    &[],
+                    amount,
+                )
+                .unwrap(),
+            );
+        }
+
+        self.process(&instructions, &[]).await.unwrap();
+        account
+    }
+
+    // an NFT held by owner, with metadata written as the token metadata program would
+    pub async fn create_nft(
+        &mut self,
+        owner: &Pubkey,
+        collection: Option<Collection>,
+        creators: Option<Vec<Creator>>,
+    ) -> (Pubkey, Pubkey) {
+        let mint = self.create_mint().await;
+        let token_account = self.mint_to(&mint, owner, 1).await;
+        let metadata = Metadata {
+            key: Key::MetadataV1,
+            update_authority: self.payer(),
+            mint,
+            name: "Gate".to_string(),
+            symbol: "GATE".to_string(),
+            uri: String::new(),
+            seller_fee_basis_points: 0,
+            creators,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: Some(TokenStandard::NonFungible),
+            collection,
+            uses: None,
+            collection_details: None,
+            programmable_config: None,
+        };
+        let data = metadata.try_to_vec().unwrap();
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let mut account = AccountSharedData::new(
+            rent.minimum_balance(data.len()),
+            data.len(),
+            &TOKEN_METADATA_ID,
+        );
+        account.set_data_from_slice(&data);
+        self.context
+            .set_account(&find_token_metadata(&mint).0, &account);
+        (mint, token_account)
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        self.try_account(address)
+            .await
+            .unwrap_or_else(|| panic!("account {} not found", address))
+    }
+
+    pub async fn try_account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> Option<T> {
+        let account = self
+            .context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()?;
+        Some(T::try_deserialize(&mut &account.data[..]).unwrap())
+    }
+
+    // rewrites a program account in place, to reach states no instruction leads to
+    pub async fn write_account<T: AccountSerialize>(&mut self, address: &Pubkey, value: &T) {
+        let mut account = self
+            .context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .unwrap_or_else(|| panic!("account {} not found", address));
+        let mut data = Vec::new();
+        value.try_serialize(&mut data).unwrap();
+        account.data[..data.len()].copy_from_slice(&data);
+        self.context.set_account(address, &account.into());
+    }
+
+    pub async fn exists(&mut self, address: &Pubkey) -> bool {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.context
+            .banks_client
+            .get_balance(*address)
+            .await
+            .unwrap()
+    }
+
+    pub async fn token_balance(&mut self, address: &Pubkey) -> u64 {
+        self.account::<TokenAccount>(address).await.amount
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        let clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp
+    }
+
+    pub async fn warp_to(&mut self, unix_timestamp: i64) {
+        let mut clock: Clock = self.context.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = unix_timestamp;
+        self.context.set_sysvar(&clock);
+    }
+
+    // the program runs from a test loader, so its ProgramData account is written directly
+    pub async fn set_upgrade_authority(&mut self, authority: &Pubkey) {
+        let state = UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: Some(*authority),
+        };
+        let rent = self.context.banks_client.get_rent().await.unwrap();
+        let len = UpgradeableLoaderState::size_of_programdata_metadata();
+        let account = AccountSharedData::new_data_with_space(
+            rent.minimum_balance(len),
+            &state,
+            len,
+            &bpf_loader_upgradeable::ID,
+        )
+        .unwrap();
+        self.context.set_account(&find_program_data().0, &account);
+    }
+
+    pub async fn warp_by(&mut self, seconds: i64) {
+        let now = self.now().await;
+        self.warp_to(now + seconds).await;
+    }
+
+    // a funded creator, a fresh mint and the creator's first pool, still Pending
+    pub async fn create_pool(&mut self, params: PoolParams) -> PoolFixture {
+        let mint = self.create_mint().await;
+        self.create_pool_with_mint(mint, params).await
+    }
+
+    // a pool of a new creator for an existing mint
+    pub async fn create_pool_with_mint(&mut self, mint: Pubkey, params: PoolParams) -> PoolFixture {
+        let creator = self.wallet(10 * SOL).await;
+        let creator_tokens = self
+            .mint_to(&mint, &creator.pubkey(), 2 * params.pool_size)
+            .await;
+        let unlock_date = self.now().await + params.unlock_in;
+        let pool = PoolAddresses::new(creator.pubkey(), 0, mint);
+
+        self.process(
+            &[create_pool_instruction(&pool, unlock_date, &params)],
+            &[&creator],
+        )
+        .await
+        .unwrap();
+
+        PoolFixture {
+            creator,
+            pool,
+            creator_tokens,
+            unlock_date,
+        }
+    }
+
+    pub async fn active_pool(&mut self, params: PoolParams) -> PoolFixture {
+        let fixture = self.create_pool(params).await;
+        self.start(&fixture).await.unwrap();
+        fixture
+    }
+
+    pub async fn active_pool_with_mint(&mut self, mint: Pubkey, params: PoolParams) -> PoolFixture {
+        let fixture = self.create_pool_with_mint(mint, params).await;
+        self.start(&fixture).await.unwrap();
+        fixture
+    }
+
+    pub async fn start(&mut self, fixture: &PoolFixture) -> TxResult {
+        let instruction = instructions::start_launch_pool(
+            &fixture.pool,
+            fixture.creator.pubkey(),
+            Some(fixture.creator_tokens),
+            false,
+        );
+        self.process(&[instruction], &[&fixture.creator]).await
+    }
+
+    pub async fn buyer(&mut self) -> Keypair {
+        self.wallet(100 * SOL).await
+    }
+
+    pub async fn buy(&mut self, fixture: &PoolFixture, user: &Keypair, amount: u64) -> TxResult {
+        let instruction = instructions::buy_token_with_native(&fixture.pool, user.pubkey(), amount);
+        self.process(&[instruction], &[user]).await
+    }
+
+    pub async fn complete(&mut self, fixture: &PoolFixture) -> TxResult {
+        let instruction =
+            instructions::complete_launch_pool(&fixture.pool, fixture.creator.pubkey());
+        self.process(&[instruction], &[&fixture.creator]).await
+    }
+
+    pub async fn claim(&mut self, fixture: &PoolFixture, user: &Keypair) -> TxResult {
+        let instruction = instructions::claim_token(&fixture.pool, user.pubkey());
+        self.process(&[instruction], &[user]).await
+    }
+
+    pub async fn withdraw_proceeds(&mut self, fixture: &PoolFixture) -> TxResult {
+        let instruction = instructions::withdraw_proceeds(&fixture.pool, fixture.creator.pubkey());
+        self.process(&[instruction], &[&fixture.creator]).await
+    }
+
+    // claims and closes the positions of buyers, returns the unsold supply to the creator and
+    // withdraws the released proceeds of an ungoverned pool, so that only the pool's own accounts can
+    // keep a completed pool open
+    pub async fn settle(&mut self, fixture: &PoolFixture, buyers: &[&Keypair]) {
+        if self.now().await < fixture.unlock_date {
+            self.warp_to(fixture.unlock_date).await;
+        }
+        for buyer in buyers {
+            self.claim(fixture, buyer).await.unwrap();
+            let close = instructions::close_user_pool(&fixture.pool, buyer.pubkey());
+            self.process(&[close], &[buyer]).await.unwrap();
+        }
+
+        let launch_pool: LaunchPool = self.account(&fixture.launch_pool()).await;
+        if !launch_pool.is_governed
+            && launch_pool.proceeds_withdrawn < launch_pool.released_proceeds(self.now().await)
+        {
+            self.withdraw_proceeds(fixture).await.unwrap();
+        }
+        if launch_pool.unsold_amount() == 0 {
+            return;
+        }
+        match launch_pool.unsold_policy {
+            Some(policy) => {
+                let finalize = instructions::finalize_unsold_tokens(
+                    &fixture.pool,
+                    policy,
+                    Some(fixture.creator_tokens),
+                );
+                self.process(&[finalize], &[]).await.unwrap();
+            }
+            None => {
+                let withdraw = instructions::withdraw_unsold_tokens(
+                    &fixture.pool,
+                    fixture.creator.pubkey(),
+                    Some(fixture.creator_tokens),
+                    false,
+                );
+                self.process(&[withdraw], &[&fixture.creator])
+                    .await
+                    .unwrap();
+            }
+        }
+    }
+
+    pub async fn close(&mut self, fixture: &PoolFixture) -> TxResult {
+        let instruction = instructions::close_launch_pool(&fixture.pool, fixture.creator.pubkey());
+        self.process(&[instruction], &[&fixture.creator]).await
+    }
+}
+
+// the native entry point, anchor's entry wants the accounts borrowed for as long as their data
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    if data.starts_with(&EVENT_IX_TAG_LE) {
+        EVENTS.lock().unwrap().push(data.to_vec());
+    }
+    let accounts = Box::leak(accounts.to_vec().into_boxed_slice());
+    encode_sol_team3::entry(program_id, accounts, data)
+}
+
+fn process_amm_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let accounts = Box::leak(accounts.to_vec().into_boxed_slice());
+    mock_amm::entry(program_id, accounts, data)
+}
+
+pub fn create_pool_instruction(
+    pool: &PoolAddresses,
+    unlock_date: i64,
+    params: &PoolParams,
+) -> Instruction {
+    instructions::create_native_pool(
+        pool,
+        instructions::CreateNativePoolArgs {
+            unlock_date,
+            pool_size: params.pool_size,
+            minimum_token_amount: params.minimum_token_amount,
+            maximum_token_amount: params.maximum_token_amount,
+            rate: params.rate,
+            token_mint_decimals: DECIMALS,
+            proceeds_vesting: params.proceeds_vesting,
+            unsold_policy: params.unsold_policy,
+        },
+    )
+}
+
+pub fn associated_token(owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address(owner, mint)
+}
+
+// points every account meta of the instruction that is from at to instead
+pub fn replace_account(mut instruction: Instruction, from: Pubkey, to: Pubkey) -> Instruction {
+    for meta in instruction.accounts.iter_mut() {
+        if meta.pubkey == from {
+            meta.pubkey = to;
+        }
+    }
+    instruction
+}
+
+fn custom_error(result: TxResult) -> u32 {
+    match result.expect_err("transaction should have failed").unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => code,
+        err => panic!("expected a custom program error, got {:?}", err),
+    }
+}
+
+pub fn assert_error(result: TxResult, expected: MyError) {
+    let name = format!("{:?}", expected);
+    assert_eq!(
+        custom_error(result),
+        u32::from(expected),
+        "expected {}",
+        name
+    );
+}
+
+pub fn assert_anchor_error(result: TxResult, expected: ErrorCode) {
+    let name = format!("{:?}", expected);
+    assert_eq!(
+        custom_error(result),
+        u32::from(expected),
+        "expected {}",
+        name
+    );
+}
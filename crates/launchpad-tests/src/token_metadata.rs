@@ -0,0 +1,97 @@
+use anchor_lang::{
+    prelude::{AccountInfo, Pubkey},
+    solana_program::{
+        entrypoint::ProgramResult, program::invoke_signed, program_error::ProgramError,
+        program_option::COption, program_pack::Pack, rent::Rent, system_instruction,
+        sysvar::Sysvar,
+    },
+    AnchorDeserialize, AnchorSerialize,
+};
+use anchor_spl::{
+    metadata::mpl_token_metadata::{
+        accounts::Metadata,
+        instructions::CreateMetadataAccountV3InstructionArgs,
+        types::{Key, TokenStandard},
+        MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH,
+    },
+    token::spl_token,
+};
+
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+
+// the published token metadata program only builds against solana 1.16, so the harness runs
+// this in its place: CreateMetadataAccountV3 as the program handles it, anything else fails
+pub(crate) fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    data: &[u8],
+) -> ProgramResult {
+    let Some((&CREATE_METADATA_ACCOUNT_V3, mut data)) = data.split_first() else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+    let args = CreateMetadataAccountV3InstructionArgs::deserialize(&mut data)?;
+    let [metadata, mint, mint_authority, payer, update_authority, system_program, ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let (address, bump) = Metadata::find_pda(mint.key);
+    if address != *metadata.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if !metadata.data_is_empty() {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+    let mint_state = spl_token::state::Mint::unpack(&mint.try_borrow_data()?)?;
+    if mint_state.mint_authority != COption::Some(*mint_authority.key) {
+        return Err(ProgramError::IllegalOwner);
+    }
+    if !mint_authority.is_signer || !payer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let pad = |value: String, len: usize| {
+        if value.len() > len {
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(format!("{:\0<len$}", value))
+    };
+    let value = Metadata {
+        key: Key::MetadataV1,
+        update_authority: *update_authority.key,
+        mint: *mint.key,
+        name: pad(args.data.name, MAX_NAME_LENGTH)?,
+        symbol: pad(args.data.symbol, MAX_SYMBOL_LENGTH)?,
+        uri: pad(args.data.uri, MAX_URI_LENGTH)?,
+        seller_fee_basis_points: args.data.seller_fee_basis_points,
+        creators: args.data.creators,
+        primary_sale_happened: false,
+        is_mutable: args.is_mutable,
+        edition_nonce: None,
+        token_standard: Some(if mint_state.decimals == 0 {
+            TokenStandard::FungibleAsset
+        } else {
+            TokenStandard::Fungible
+        }),
+        collection: args.data.collection,
+        uses: args.data.uses,
+        collection_details: args.collection_details,
+        programmable_config: None,
+    };
+    let value = value.try_to_vec()?;
+
+    invoke_signed(
+        &system_instruction::create_account(
+            payer.key,
+            metadata.key,
+            Rent::get()?.minimum_balance(value.len()),
+            value.len() as u64,
+            program_id,
+        ),
+        &[payer.clone(), metadata.clone(), system_program.clone()],
+        &[&[b"metadata", program_id.as_ref(), mint.key.as_ref(), &[bump]]],
+    )?;
+    metadata.try_borrow_mut_data()?.copy_from_slice(&value);
+
+    Ok(())
+}
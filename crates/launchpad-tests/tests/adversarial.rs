@@ -0,0 +1,195 @@
+use anchor_lang::error::ErrorCode;
+use encode_sol_team3::{errors::MyError, state::UserPool};
+use launchpad_client::{find_user_pool, instructions};
+use launchpad_tests::{
+    assert_anchor_error, assert_error, associated_token, replace_account, Harness, PoolParams, SOL,
+    TOKEN,
+};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn claim_rejects_the_treasurer_of_another_pool() {
+    let mut harness = Harness::new().await;
+    let (fixture, alice) = harness.claimable_pool().await;
+
+    // a second pool of the same mint has a treasurer and treasury that pass every account check
+    let other = harness
+        .active_pool_with_mint(fixture.mint(), PoolParams::default())
+        .await;
+    let claim = instructions::claim_token(&fixture.pool, alice.pubkey());
+    let claim = replace_account(claim, fixture.pool.treasurer, other.pool.treasurer);
+    let claim = replace_account(claim, fixture.pool.treasury, other.pool.treasury);
+
+    assert_error(
+        harness.process(&[claim], &[&alice]).await,
+        MyError::InvalidTreasurer,
+    );
+    assert_eq!(
+        harness.token_balance(&other.pool.treasury).await,
+        1_000 * TOKEN
+    );
+}
+
+#[tokio::test]
+async fn claim_rejects_a_foreign_user_pool() {
+    let mut harness = Harness::new().await;
+    let (fixture, alice) = harness.claimable_pool().await;
+    let mallory = harness.buyer().await;
+
+    let claim = instructions::claim_token(&fixture.pool, mallory.pubkey());
+    let claim = replace_account(
+        claim,
+        fixture.pool.user_pool(&mallory.pubkey()),
+        fixture.pool.user_pool(&alice.pubkey()),
+    );
+    assert_anchor_error(
+        harness.process(&[claim], &[&mallory]).await,
+        ErrorCode::ConstraintSeeds,
+    );
+
+    let alice_pool: UserPool = harness
+        .account(&fixture.pool.user_pool(&alice.pubkey()))
+        .await;
+    assert_eq!(alice_pool.claimed, 0);
+    harness.claim(&fixture, &alice).await.unwrap();
+}
+
+#[tokio::test]
+async fn close_user_pool_rejects_a_foreign_user_pool() {
+    let mut harness = Harness::new().await;
+    let (fixture, alice) = harness.claimable_pool().await;
+    harness.claim(&fixture, &alice).await.unwrap();
+    let mallory = harness.buyer().await;
+
+    let close = instructions::close_user_pool(&fixture.pool, mallory.pubkey());
+    let close = replace_account(
+        close,
+        fixture.pool.user_pool(&mallory.pubkey()),
+        fixture.pool.user_pool(&alice.pubkey()),
+    );
+    assert_anchor_error(
+        harness.process(&[close], &[&mallory]).await,
+        ErrorCode::ConstraintSeeds,
+    );
+}
+
+#[tokio::test]
+async fn claim_and_complete_reject_another_mint() {
+    let mut harness = Harness::new().await;
+    let (fixture, alice) = harness.claimable_pool().await;
+    let other_mint = harness.create_mint().await;
+
+    // the token account is created for whichever mint is passed, so it has to follow it
+    let claim = instructions::claim_token(&fixture.pool, alice.pubkey());
+    let claim = replace_account(claim, fixture.mint(), other_mint);
+    let claim = replace_account(
+        claim,
+        associated_token(&alice.pubkey(), &fixture.mint()),
+        associated_token(&alice.pubkey(), &other_mint),
+    );
+    assert_error(
+        harness.process(&[claim], &[&alice]).await,
+        MyError::InvalidTokenMint,
+    );
+
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let complete = instructions::complete_launch_pool(&fixture.pool, fixture.creator.pubkey());
+    let complete = replace_account(complete, fixture.mint(), other_mint);
+    assert_error(
+        harness.process(&[complete], &[&fixture.creator]).await,
+        MyError::InvalidTokenMint,
+    );
+}
+
+#[tokio::test]
+async fn buy_rejects_another_mint_and_vault() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let other = harness.active_pool(PoolParams::default()).await;
+    let alice = harness.buyer().await;
+
+    // the user pool is derived from the mint passed, so it is created for the other mint first
+    let buy = instructions::buy_token_with_native(&fixture.pool, alice.pubkey(), 10 * TOKEN);
+    let buy = replace_account(buy, fixture.mint(), other.mint());
+    let buy = replace_account(
+        buy,
+        fixture.pool.user_pool(&alice.pubkey()),
+        find_user_pool(&alice.pubkey(), &fixture.launch_pool(), &other.mint()).0,
+    );
+    assert_error(
+        harness.process(&[buy], &[&alice]).await,
+        MyError::InvalidTokenMint,
+    );
+
+    // paying into another pool's vault must not buy from this one
+    let buy = instructions::buy_token_with_native(&fixture.pool, alice.pubkey(), 10 * TOKEN);
+    let buy = replace_account(buy, fixture.pool.vault, other.pool.vault);
+    assert_anchor_error(
+        harness.process(&[buy], &[&alice]).await,
+        ErrorCode::ConstraintSeeds,
+    );
+}
+
+#[tokio::test]
+async fn withdraw_proceeds_rejects_another_vault() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let other = harness.active_pool(PoolParams::default()).await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.buy(&other, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+
+    let withdraw = instructions::withdraw_proceeds(&fixture.pool, fixture.creator.pubkey());
+    let withdraw = replace_account(withdraw, fixture.pool.vault, other.pool.vault);
+    assert_anchor_error(
+        harness.process(&[withdraw], &[&fixture.creator]).await,
+        ErrorCode::ConstraintSeeds,
+    );
+    assert_eq!(harness.lamports(&other.pool.vault).await, SOL / 10);
+}
+
+#[tokio::test]
+async fn start_rejects_a_treasury_the_treasurer_does_not_own() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+
+    // an account of the pool token that anyone but the treasurer owns would divert the supply
+    let start = instructions::start_launch_pool(
+        &fixture.pool,
+        fixture.creator.pubkey(),
+        Some(fixture.creator_tokens),
+        false,
+    );
+    let mallory = harness.wallet(SOL).await;
+    let mallory_tokens = harness.mint_to(&fixture.mint(), &mallory.pubkey(), 0).await;
+    let fake_treasury = replace_account(start.clone(), fixture.pool.treasury, mallory_tokens);
+    assert_anchor_error(
+        harness.process(&[fake_treasury], &[&fixture.creator]).await,
+        ErrorCode::ConstraintTokenOwner,
+    );
+
+    // a treasury of another mint is rejected before its owner is looked at
+    let other_mint = harness.create_mint().await;
+    let other_tokens = harness.mint_to(&other_mint, &mallory.pubkey(), 0).await;
+    let wrong_mint = replace_account(start.clone(), fixture.pool.treasury, other_tokens);
+    assert_error(
+        harness.process(&[wrong_mint], &[&fixture.creator]).await,
+        MyError::InvalidTokenMint,
+    );
+
+    harness
+        .process(&[start], &[&fixture.creator])
+        .await
+        .unwrap();
+    assert_eq!(
+        harness.token_balance(&fixture.pool.treasury).await,
+        1_000 * TOKEN
+    );
+    assert_eq!(
+        harness
+            .token_balance(&associated_token(&mallory.pubkey(), &fixture.mint()))
+            .await,
+        0
+    );
+}
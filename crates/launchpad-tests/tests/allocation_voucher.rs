@@ -0,0 +1,184 @@
+use anchor_lang::{
+    solana_program::{system_program, sysvar},
+    AnchorSerialize,
+};
+use anchor_spl::token;
+use encode_sol_team3::{errors::MyError, state::AllocationVoucher};
+use launchpad_client::{
+    accounts, find_event_authority, instruction, instructions, PoolEvent, PROGRAM_ID,
+};
+use launchpad_tests::{assert_error, Harness, PoolFixture, TxResult, TOKEN};
+use solana_sdk::{
+    ed25519_program,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+// offsets of the single signature Ed25519 instruction data, as the native program lays it out
+const PUBLIC_KEY_OFFSET: u16 = 16;
+const SIGNATURE_OFFSET: u16 = PUBLIC_KEY_OFFSET + 32;
+const MESSAGE_OFFSET: u16 = SIGNATURE_OFFSET + 64;
+
+// the Ed25519 program instruction that carries signer's signature over the voucher
+fn voucher_instruction(signer: &Keypair, voucher: &AllocationVoucher) -> Instruction {
+    let message = voucher.try_to_vec().unwrap();
+    let signature = signer.sign_message(&message);
+
+    let mut data = vec![1, 0];
+    for value in [
+        SIGNATURE_OFFSET,
+        u16::MAX,
+        PUBLIC_KEY_OFFSET,
+        u16::MAX,
+        MESSAGE_OFFSET,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(signer.pubkey().as_ref());
+    data.extend_from_slice(signature.as_ref());
+    data.extend_from_slice(&message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+fn buy_instruction(fixture: &PoolFixture, user: &Keypair, amount: u64) -> Instruction {
+    instructions::build(
+        accounts::BuyTokenWithNative {
+            launch_pool: fixture.launch_pool(),
+            token_mint: fixture.mint(),
+            user_pool: fixture.pool.user_pool(&user.pubkey()),
+            vault: fixture.pool.vault,
+            stake_account: None,
+            nft_token_account: None,
+            nft_metadata: None,
+            nft_usage: None,
+            instructions_sysvar: Some(sysvar::instructions::ID),
+            referral_stats: None,
+            user: user.pubkey(),
+            system_program: system_program::ID,
+            token_program: token::ID,
+            rent: sysvar::rent::ID,
+            event_authority: find_event_authority().0,
+            program: PROGRAM_ID,
+        },
+        instruction::BuyTokenWithNative { amount },
+    )
+}
+
+async fn buy_with_voucher(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    user: &Keypair,
+    signer: &Keypair,
+    voucher: AllocationVoucher,
+    amount: u64,
+) -> TxResult {
+    let instructions = [
+        voucher_instruction(signer, &voucher),
+        buy_instruction(fixture, user, amount),
+    ];
+    harness.process(&instructions, &[user]).await
+}
+
+#[tokio::test]
+async fn valid_voucher_allows_buying_up_to_its_amount() {
+    let mut harness = Harness::new().await;
+    let signer = Keypair::new();
+    let fixture = harness.voucher_pool(signer.pubkey()).await;
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ConfigureAllocationSigner(event)
+            if event.allocation_signer == Some(signer.pubkey()))
+    });
+    let user = harness.buyer().await;
+    let voucher = AllocationVoucher {
+        pool: fixture.launch_pool(),
+        buyer: user.pubkey(),
+        max_amount: 5 * TOKEN,
+        expiry: harness.now().await + 600,
+    };
+
+    buy_with_voucher(&mut harness, &fixture, &user, &signer, voucher, 3 * TOKEN)
+        .await
+        .unwrap();
+
+    // the voucher caps the wallet's total, not each buy
+    let result = buy_with_voucher(&mut harness, &fixture, &user, &signer, voucher, 3 * TOKEN).await;
+    assert_error(result, MyError::MaximumTokenAmountReached);
+
+    buy_with_voucher(&mut harness, &fixture, &user, &signer, voucher, 2 * TOKEN)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn missing_voucher_is_rejected() {
+    let mut harness = Harness::new().await;
+    let signer = Keypair::new();
+    let fixture = harness.voucher_pool(signer.pubkey()).await;
+    let user = harness.buyer().await;
+
+    let result = harness.buy(&fixture, &user, TOKEN).await;
+    assert_error(result, MyError::AllocationVoucherRequired);
+
+    // the instructions sysvar alone, without the Ed25519 instruction before the buy
+    let result = harness
+        .process(&[buy_instruction(&fixture, &user, TOKEN)], &[&user])
+        .await;
+    assert_error(result, MyError::InvalidAllocationVoucher);
+}
+
+#[tokio::test]
+async fn bad_vouchers_are_rejected() {
+    let mut harness = Harness::new().await;
+    let signer = Keypair::new();
+    let fixture = harness.voucher_pool(signer.pubkey()).await;
+    let user = harness.buyer().await;
+    let now = harness.now().await;
+    let voucher = AllocationVoucher {
+        pool: fixture.launch_pool(),
+        buyer: user.pubkey(),
+        max_amount: 5 * TOKEN,
+        expiry: now + 600,
+    };
+
+    let impostor = Keypair::new();
+    let result = buy_with_voucher(&mut harness, &fixture, &user, &impostor, voucher, TOKEN).await;
+    assert_error(result, MyError::InvalidAllocationSigner);
+
+    let other_buyer = AllocationVoucher {
+        buyer: Pubkey::new_unique(),
+        ..voucher
+    };
+    let result = buy_with_voucher(&mut harness, &fixture, &user, &signer, other_buyer, TOKEN).await;
+    assert_error(result, MyError::InvalidAllocationVoucher);
+
+    let other_pool = AllocationVoucher {
+        pool: Pubkey::new_unique(),
+        ..voucher
+    };
+    let result = buy_with_voucher(&mut harness, &fixture, &user, &signer, other_pool, TOKEN).await;
+    assert_error(result, MyError::InvalidAllocationVoucher);
+
+    let expired = AllocationVoucher {
+        expiry: now - 1,
+        ..voucher
+    };
+    let result = buy_with_voucher(&mut harness, &fixture, &user, &signer, expired, TOKEN).await;
+    assert_error(result, MyError::AllocationVoucherExpired);
+
+    // a signature that does not match the message fails in the Ed25519 program itself
+    let mut forged = voucher_instruction(&signer, &voucher);
+    let last = forged.data.len() - 1;
+    forged.data[last] ^= 1;
+    let result = harness
+        .process(&[forged, buy_instruction(&fixture, &user, TOKEN)], &[&user])
+        .await;
+    assert!(result.is_err());
+}
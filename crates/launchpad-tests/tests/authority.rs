@@ -0,0 +1,99 @@
+use anchor_lang::prelude::Pubkey;
+use encode_sol_team3::{
+    errors::MyError,
+    state::{LaunchPool, Treasurer},
+};
+use launchpad_client::{instructions, PoolEvent};
+use launchpad_tests::{assert_error, Harness, PoolFixture, PoolParams, TxResult, SOL, TOKEN};
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn propose(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    authority: &Keypair,
+    new_authority: Pubkey,
+) -> TxResult {
+    let instruction =
+        instructions::propose_authority(&fixture.pool, authority.pubkey(), new_authority);
+    harness.process(&[instruction], &[authority]).await
+}
+
+async fn accept(harness: &mut Harness, fixture: &PoolFixture, new_authority: &Keypair) -> TxResult {
+    let instruction = instructions::accept_authority(&fixture.pool, new_authority.pubkey());
+    harness.process(&[instruction], &[new_authority]).await
+}
+
+#[tokio::test]
+async fn authority_is_handed_over_in_two_steps() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let successor = harness.wallet(SOL).await;
+    let impostor = harness.wallet(SOL).await;
+
+    assert_error(
+        propose(&mut harness, &fixture, &impostor, impostor.pubkey()).await,
+        MyError::InvalidAuthority,
+    );
+    assert_error(
+        accept(&mut harness, &fixture, &successor).await,
+        MyError::InvalidPendingAuthority,
+    );
+
+    propose(&mut harness, &fixture, &fixture.creator, successor.pubkey())
+        .await
+        .unwrap();
+    assert_error(
+        accept(&mut harness, &fixture, &impostor).await,
+        MyError::InvalidPendingAuthority,
+    );
+
+    // proposing the current authority cancels the handover
+    propose(
+        &mut harness,
+        &fixture,
+        &fixture.creator,
+        fixture.creator.pubkey(),
+    )
+    .await
+    .unwrap();
+    assert_error(
+        accept(&mut harness, &fixture, &successor).await,
+        MyError::InvalidPendingAuthority,
+    );
+
+    propose(&mut harness, &fixture, &fixture.creator, successor.pubkey())
+        .await
+        .unwrap();
+    accept(&mut harness, &fixture, &successor).await.unwrap();
+
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.authority, successor.pubkey());
+    assert_eq!(launch_pool.creator, fixture.creator.pubkey());
+    assert!(launch_pool.pending_authority.is_none());
+    let treasurer: Treasurer = harness.account(&fixture.pool.treasurer).await;
+    assert_eq!(treasurer.authority, successor.pubkey());
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ProposeAuthority(event)
+            if event.authority == fixture.creator.pubkey()
+                && event.pending_authority == Some(successor.pubkey()))
+    });
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::AcceptAuthority(event) if event.authority == successor.pubkey())
+    });
+
+    // the creator lost every authority-gated instruction to the successor
+    assert_error(harness.complete(&fixture).await, MyError::InvalidAuthority);
+
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    let complete = instructions::complete_launch_pool(&fixture.pool, successor.pubkey());
+    harness.process(&[complete], &[&successor]).await.unwrap();
+
+    let successor_lamports = harness.lamports(&successor.pubkey()).await;
+    let withdraw = instructions::withdraw_proceeds(&fixture.pool, successor.pubkey());
+    harness.process(&[withdraw], &[&successor]).await.unwrap();
+    assert_eq!(
+        harness.lamports(&successor.pubkey()).await,
+        successor_lamports + SOL / 10
+    );
+}
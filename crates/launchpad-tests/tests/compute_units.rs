@@ -0,0 +1,32 @@
+use launchpad_client::instructions;
+use launchpad_tests::{Harness, PoolParams, TOKEN};
+use solana_sdk::signature::Signer;
+
+// the compute units a single instruction may use without requesting a larger budget
+const INSTRUCTION_BUDGET: u64 = 200_000;
+
+// measures buy and claim against the SBF build of the program, a natively running
+// program is not metered so the measurements are skipped without one
+#[tokio::test]
+async fn buy_and_claim_fit_the_default_budget() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    harness.start(&fixture).await.unwrap();
+    let alice = harness.buyer().await;
+
+    let buy = instructions::buy_token_with_native(&fixture.pool, alice.pubkey(), 10 * TOKEN);
+    let Some(buy_units) = harness.compute_units(&[buy], &[&alice]).await else {
+        return;
+    };
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+    harness.warp_to(fixture.unlock_date).await;
+
+    let claim = instructions::claim_token(&fixture.pool, alice.pubkey());
+    let claim_units = harness.compute_units(&[claim], &[&alice]).await.unwrap();
+
+    println!("buy_token_with_native: {buy_units} compute units");
+    println!("claim_token: {claim_units} compute units");
+    assert!(buy_units <= INSTRUCTION_BUDGET);
+    assert!(claim_units <= INSTRUCTION_BUDGET);
+}
@@ -0,0 +1,395 @@
+use anchor_lang::error::ErrorCode;
+use encode_sol_team3::{
+    errors::MyError,
+    state::{ProceedsVesting, UnsoldPolicy},
+};
+use launchpad_client::{instructions, PoolAddresses};
+use launchpad_tests::{
+    assert_anchor_error, assert_error, create_pool_instruction, Harness, PoolParams, TxResult, SOL,
+    TOKEN,
+};
+use solana_sdk::signature::Signer;
+use std::slice;
+
+// sends create_native_pool for a fresh creator and mint
+async fn create(harness: &mut Harness, unlock_date: i64, params: PoolParams) -> TxResult {
+    let creator = harness.wallet(10 * SOL).await;
+    let mint = harness.create_mint().await;
+    let pool = PoolAddresses::new(creator.pubkey(), 0, mint);
+    let instruction = create_pool_instruction(&pool, unlock_date, &params);
+    harness.process(&[instruction], &[&creator]).await
+}
+
+#[tokio::test]
+async fn create_rejects_invalid_parameters() {
+    let mut harness = Harness::new().await;
+    let now = harness.now().await;
+
+    assert_error(
+        create(&mut harness, now, PoolParams::default()).await,
+        MyError::InvalidUnlockDate,
+    );
+
+    let vesting = |completion_bps, period, periods| PoolParams {
+        proceeds_vesting: Some(ProceedsVesting {
+            completion_bps,
+            period,
+            periods,
+        }),
+        ..PoolParams::default()
+    };
+    assert_error(
+        create(&mut harness, now + 60, vesting(10_001, 100, 1)).await,
+        MyError::InvalidBasisPoints,
+    );
+    assert_error(
+        create(&mut harness, now + 60, vesting(5_000, 0, 1)).await,
+        MyError::InvalidVestingPlan,
+    );
+    assert_error(
+        create(&mut harness, now + 60, vesting(5_000, 100, 0)).await,
+        MyError::InvalidVestingPlan,
+    );
+    assert_error(
+        create(&mut harness, now + 60, vesting(10_000, 0, 4)).await,
+        MyError::InvalidVestingPlan,
+    );
+}
+
+#[tokio::test]
+async fn start_checks_status_authority_and_policy() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+
+    let impostor = harness.wallet(SOL).await;
+    let start = instructions::start_launch_pool(
+        &fixture.pool,
+        impostor.pubkey(),
+        Some(fixture.creator_tokens),
+        false,
+    );
+    assert_error(
+        harness.process(&[start], &[&impostor]).await,
+        MyError::InvalidAuthority,
+    );
+
+    harness.start(&fixture).await.unwrap();
+    assert_error(
+        harness.start(&fixture).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+
+    // unsold tokens cannot go to an AMM pool that is never seeded
+    let fixture = harness
+        .create_pool(PoolParams {
+            unsold_policy: Some(UnsoldPolicy::SendToLiquidity),
+            ..PoolParams::default()
+        })
+        .await;
+    assert_error(
+        harness.start(&fixture).await,
+        MyError::LiquidityAccountsRequired,
+    );
+}
+
+#[tokio::test]
+async fn buy_checks_status_and_limits() {
+    let mut harness = Harness::new().await;
+    let alice = harness.buyer().await;
+
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    assert_error(
+        harness.buy(&fixture, &alice, 10 * TOKEN).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+    harness.start(&fixture).await.unwrap();
+
+    assert_error(
+        harness.buy(&fixture, &alice, 0).await,
+        MyError::InvalidAmount,
+    );
+    assert_error(
+        harness.buy(&fixture, &alice, 1_001 * TOKEN).await,
+        MyError::PoolSizeRemainingNotEnough,
+    );
+    assert_error(
+        harness.buy(&fixture, &alice, TOKEN / 2).await,
+        MyError::MinimumTokenAmountNotReached,
+    );
+    assert_error(
+        harness.buy(&fixture, &alice, 101 * TOKEN).await,
+        MyError::MaximumTokenAmountReached,
+    );
+
+    // the maximum caps a wallet's purchases, not each purchase
+    harness.buy(&fixture, &alice, 60 * TOKEN).await.unwrap();
+    assert_error(
+        harness.buy(&fixture, &alice, 41 * TOKEN).await,
+        MyError::MaximumTokenAmountReached,
+    );
+    harness.buy(&fixture, &alice, 40 * TOKEN).await.unwrap();
+
+    harness.complete(&fixture).await.unwrap();
+    assert_error(
+        harness.buy(&fixture, &alice, TOKEN).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+}
+
+#[tokio::test]
+async fn buy_rejects_amounts_that_cost_nothing() {
+    let mut harness = Harness::new().await;
+    let fixture = harness
+        .active_pool(PoolParams {
+            minimum_token_amount: 1,
+            ..PoolParams::default()
+        })
+        .await;
+    let alice = harness.buyer().await;
+
+    // 99 base units at 100 tokens per SOL round down to zero lamports
+    assert_error(
+        harness.buy(&fixture, &alice, 99).await,
+        MyError::InvalidAmount,
+    );
+}
+
+#[tokio::test]
+async fn buy_stops_when_the_pool_sells_out() {
+    let mut harness = Harness::new().await;
+    let fixture = harness
+        .active_pool(PoolParams {
+            pool_size: 150 * TOKEN,
+            ..PoolParams::default()
+        })
+        .await;
+    let alice = harness.buyer().await;
+    let bob = harness.buyer().await;
+
+    harness.buy(&fixture, &alice, 100 * TOKEN).await.unwrap();
+    assert_error(
+        harness.buy(&fixture, &bob, 51 * TOKEN).await,
+        MyError::PoolSizeRemainingNotEnough,
+    );
+    harness.buy(&fixture, &bob, 50 * TOKEN).await.unwrap();
+}
+
+#[tokio::test]
+async fn complete_checks_status_and_authority() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    assert_error(
+        harness.complete(&fixture).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+
+    harness.start(&fixture).await.unwrap();
+    let impostor = harness.wallet(SOL).await;
+    let complete = instructions::complete_launch_pool(&fixture.pool, impostor.pubkey());
+    assert_error(
+        harness.process(&[complete], &[&impostor]).await,
+        MyError::InvalidAuthority,
+    );
+
+    harness.complete(&fixture).await.unwrap();
+    assert_error(
+        harness.complete(&fixture).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+}
+
+#[tokio::test]
+async fn claim_checks_status_and_position() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+
+    assert_error(
+        harness.claim(&fixture, &alice).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+
+    harness.complete(&fixture).await.unwrap();
+    harness.warp_to(fixture.unlock_date).await;
+
+    // a wallet that never bought has no position to claim from
+    let stranger = harness.buyer().await;
+    assert_anchor_error(
+        harness.claim(&fixture, &stranger).await,
+        ErrorCode::AccountNotInitialized,
+    );
+
+    harness.claim(&fixture, &alice).await.unwrap();
+    assert_error(
+        harness.claim(&fixture, &alice).await,
+        MyError::InvalidAmount,
+    );
+}
+
+#[tokio::test]
+async fn withdraw_proceeds_checks_status_and_authority() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+
+    assert_error(
+        harness.withdraw_proceeds(&fixture).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+    harness.complete(&fixture).await.unwrap();
+
+    let impostor = harness.wallet(SOL).await;
+    let withdraw = instructions::withdraw_proceeds(&fixture.pool, impostor.pubkey());
+    assert_error(
+        harness.process(&[withdraw], &[&impostor]).await,
+        MyError::InvalidAuthority,
+    );
+
+    harness.withdraw_proceeds(&fixture).await.unwrap();
+    assert_error(
+        harness.withdraw_proceeds(&fixture).await,
+        MyError::InvalidAmount,
+    );
+}
+
+#[tokio::test]
+async fn finalize_unsold_tokens_runs_once() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let finalize = instructions::finalize_unsold_tokens(
+        &fixture.pool,
+        UnsoldPolicy::RefundToCreator,
+        Some(fixture.creator_tokens),
+    );
+
+    assert_error(
+        harness.process(slice::from_ref(&finalize), &[]).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+    harness.complete(&fixture).await.unwrap();
+
+    let without_destination =
+        instructions::finalize_unsold_tokens(&fixture.pool, UnsoldPolicy::RefundToCreator, None);
+    assert_error(
+        harness.process(&[without_destination], &[]).await,
+        MyError::TokenAccountRequired,
+    );
+
+    harness
+        .process(slice::from_ref(&finalize), &[])
+        .await
+        .unwrap();
+    assert_error(
+        harness.process(&[finalize], &[]).await,
+        MyError::UnsoldTokensWithdrawn,
+    );
+}
+
+#[tokio::test]
+async fn finalize_unsold_tokens_needs_an_unsold_supply() {
+    let mut harness = Harness::new().await;
+    let fixture = harness
+        .active_pool(PoolParams {
+            pool_size: 100 * TOKEN,
+            ..PoolParams::default()
+        })
+        .await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 100 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+
+    let finalize = instructions::finalize_unsold_tokens(
+        &fixture.pool,
+        UnsoldPolicy::RefundToCreator,
+        Some(fixture.creator_tokens),
+    );
+    assert_error(
+        harness.process(&[finalize], &[]).await,
+        MyError::InvalidAmount,
+    );
+}
+
+#[tokio::test]
+async fn close_user_pool_needs_a_settled_position() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+
+    let close = instructions::close_user_pool(&fixture.pool, alice.pubkey());
+    assert_error(
+        harness.process(slice::from_ref(&close), &[&alice]).await,
+        MyError::PositionNotSettled,
+    );
+
+    harness.warp_to(fixture.unlock_date).await;
+    harness.claim(&fixture, &alice).await.unwrap();
+    harness.process(&[close], &[&alice]).await.unwrap();
+}
+
+#[tokio::test]
+async fn close_launch_pool_needs_a_settled_pool() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+
+    let close = instructions::close_launch_pool(&fixture.pool, fixture.creator.pubkey());
+    assert_error(
+        harness
+            .process(slice::from_ref(&close), &[&fixture.creator])
+            .await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+
+    harness.complete(&fixture).await.unwrap();
+    let impostor = harness.wallet(SOL).await;
+    let close_by_impostor = instructions::close_launch_pool(&fixture.pool, impostor.pubkey());
+    assert_error(
+        harness.process(&[close_by_impostor], &[&impostor]).await,
+        MyError::InvalidAuthority,
+    );
+
+    // an open position and the unsold supply both keep the pool open
+    assert_error(
+        harness
+            .process(slice::from_ref(&close), &[&fixture.creator])
+            .await,
+        MyError::LaunchPoolNotSettled,
+    );
+    harness.warp_to(fixture.unlock_date).await;
+    harness.claim(&fixture, &alice).await.unwrap();
+    let close_user_pool = instructions::close_user_pool(&fixture.pool, alice.pubkey());
+    harness
+        .process(&[close_user_pool], &[&alice])
+        .await
+        .unwrap();
+    assert_error(
+        harness
+            .process(slice::from_ref(&close), &[&fixture.creator])
+            .await,
+        MyError::LaunchPoolNotSettled,
+    );
+    let finalize = instructions::finalize_unsold_tokens(
+        &fixture.pool,
+        UnsoldPolicy::RefundToCreator,
+        Some(fixture.creator_tokens),
+    );
+    harness.process(&[finalize], &[]).await.unwrap();
+
+    // the proceeds the creator has not withdrawn keep it open as well
+    assert_error(
+        harness
+            .process(slice::from_ref(&close), &[&fixture.creator])
+            .await,
+        MyError::LaunchPoolNotSettled,
+    );
+    harness.withdraw_proceeds(&fixture).await.unwrap();
+    harness
+        .process(&[close], &[&fixture.creator])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,422 @@
+use anchor_lang::error::ErrorCode;
+use encode_sol_team3::{
+    errors::MyError,
+    state::{LaunchPool, MilestoneState, ProceedsVesting},
+};
+use launchpad_client::{instructions, PoolEvent};
+use launchpad_tests::{
+    assert_anchor_error, assert_error, replace_account, Harness, PoolFixture, PoolParams, TxResult,
+    SOL, TOKEN,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+async fn configure(harness: &mut Harness, fixture: &PoolFixture, quorum_bps: u16) -> TxResult {
+    let instruction =
+        instructions::configure_governance(&fixture.pool, fixture.creator.pubkey(), quorum_bps);
+    harness.process(&[instruction], &[&fixture.creator]).await
+}
+
+async fn propose(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    index: u8,
+    amount: u64,
+    deadline: i64,
+) -> TxResult {
+    let instruction = instructions::propose_milestone(
+        &fixture.pool,
+        fixture.creator.pubkey(),
+        index,
+        amount,
+        deadline,
+    );
+    harness.process(&[instruction], &[&fixture.creator]).await
+}
+
+async fn vote(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    index: u8,
+    voter: &Keypair,
+    approve: bool,
+) -> TxResult {
+    let instruction = instructions::vote_milestone(&fixture.pool, voter.pubkey(), index, approve);
+    harness.process(&[instruction], &[voter]).await
+}
+
+async fn execute(harness: &mut Harness, fixture: &PoolFixture, index: u8) -> TxResult {
+    let instruction =
+        instructions::execute_milestone(&fixture.pool, fixture.creator.pubkey(), index);
+    harness.process(&[instruction], &[]).await
+}
+
+async fn reclaim(harness: &mut Harness, fixture: &PoolFixture, user: &Keypair) -> TxResult {
+    let instruction = instructions::reclaim_funds(&fixture.pool, user.pubkey());
+    harness.process(&[instruction], &[user]).await
+}
+
+#[tokio::test]
+async fn configure_governance_validates_the_pool() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+
+    assert_error(
+        configure(&mut harness, &fixture, 0).await,
+        MyError::InvalidBasisPoints,
+    );
+    assert_error(
+        configure(&mut harness, &fixture, 10_001).await,
+        MyError::InvalidBasisPoints,
+    );
+
+    let impostor = harness.wallet(SOL).await;
+    let by_impostor = harness
+        .process(
+            &[instructions::configure_governance(
+                &fixture.pool,
+                impostor.pubkey(),
+                5_000,
+            )],
+            &[&impostor],
+        )
+        .await;
+    assert_error(by_impostor, MyError::InvalidAuthority);
+
+    configure(&mut harness, &fixture, 5_000).await.unwrap();
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ConfigureGovernance(event)
+            if event.authority == fixture.creator.pubkey() && event.quorum_bps == 5_000)
+    });
+
+    harness.start(&fixture).await.unwrap();
+    assert_error(
+        configure(&mut harness, &fixture, 5_000).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+
+    // milestones replace proceeds vesting
+    let fixture = harness
+        .create_pool(PoolParams {
+            proceeds_vesting: Some(ProceedsVesting {
+                completion_bps: 5_000,
+                period: 100,
+                periods: 2,
+            }),
+            ..PoolParams::default()
+        })
+        .await;
+    assert_error(
+        configure(&mut harness, &fixture, 5_000).await,
+        MyError::InvalidVestingPlan,
+    );
+}
+
+#[tokio::test]
+async fn propose_milestone_validates_the_proposal() {
+    let mut harness = Harness::new().await;
+    let now = harness.now().await;
+
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    assert_error(
+        propose(&mut harness, &fixture, 0, SOL / 10, now + 100).await,
+        MyError::LaunchPoolNotGoverned,
+    );
+
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    configure(&mut harness, &fixture, 5_000).await.unwrap();
+    harness.start(&fixture).await.unwrap();
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 60 * TOKEN).await.unwrap();
+    assert_error(
+        propose(&mut harness, &fixture, 0, SOL / 10, now + 100).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+
+    harness.complete(&fixture).await.unwrap();
+    assert_error(
+        harness.withdraw_proceeds(&fixture).await,
+        MyError::LaunchPoolIsGoverned,
+    );
+    assert_error(
+        propose(&mut harness, &fixture, 0, 0, now + 100).await,
+        MyError::InvalidAmount,
+    );
+    assert_error(
+        propose(&mut harness, &fixture, 0, SOL * 7 / 10, now + 100).await,
+        MyError::InvalidAmount,
+    );
+    assert_error(
+        propose(&mut harness, &fixture, 0, SOL / 10, now).await,
+        MyError::InvalidMilestone,
+    );
+
+    propose(&mut harness, &fixture, 0, SOL / 10, now + 100)
+        .await
+        .unwrap();
+    assert_error(
+        propose(&mut harness, &fixture, 1, SOL / 10, now + 100).await,
+        MyError::MilestoneVotingOpen,
+    );
+}
+
+#[tokio::test]
+async fn approved_milestone_releases_funds() {
+    let mut harness = Harness::new().await;
+    let (fixture, alice, bob) = harness.governed_pool().await;
+    let deadline = harness.now().await + 100;
+    propose(&mut harness, &fixture, 0, SOL / 5, deadline)
+        .await
+        .unwrap();
+
+    assert_error(
+        execute(&mut harness, &fixture, 0).await,
+        MyError::MilestoneVotingOpen,
+    );
+
+    // only buyers vote, weighted by the tokens they bought
+    let stranger = harness.buyer().await;
+    assert_anchor_error(
+        vote(&mut harness, &fixture, 0, &stranger, true).await,
+        ErrorCode::AccountNotInitialized,
+    );
+    vote(&mut harness, &fixture, 0, &bob, true).await.unwrap();
+
+    harness.warp_to(deadline).await;
+    assert_error(
+        vote(&mut harness, &fixture, 0, &alice, false).await,
+        MyError::MilestoneVotingClosed,
+    );
+
+    let creator_lamports = harness.lamports(&fixture.creator.pubkey()).await;
+    execute(&mut harness, &fixture, 0).await.unwrap();
+    assert_eq!(
+        harness.lamports(&fixture.creator.pubkey()).await,
+        creator_lamports + SOL / 5
+    );
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, SOL * 2 / 5);
+    assert_error(
+        execute(&mut harness, &fixture, 0).await,
+        MyError::InvalidMilestone,
+    );
+    assert_error(
+        reclaim(&mut harness, &fixture, &alice).await,
+        MyError::RefundNotAvailable,
+    );
+
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ProposeMilestone(event)
+            if event.index == 0 && event.amount == SOL / 5 && event.deadline == deadline)
+    });
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::VoteMilestone(event)
+            if event.voter == bob.pubkey() && event.weight == 50 * TOKEN && event.approve)
+    });
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ExecuteMilestone(event)
+            if event.status == MilestoneState::Executed && event.votes_for == 50 * TOKEN)
+    });
+}
+
+#[tokio::test]
+async fn rejected_milestone_refunds_buyers() {
+    let mut harness = Harness::new().await;
+    let (fixture, alice, bob) = harness.governed_pool().await;
+    let deadline = harness.now().await + 100;
+    propose(&mut harness, &fixture, 0, SOL / 5, deadline)
+        .await
+        .unwrap();
+
+    // alice's 10 of the 60 tokens sold do not reach the quorum
+    vote(&mut harness, &fixture, 0, &alice, true).await.unwrap();
+    harness.warp_to(deadline).await;
+    execute(&mut harness, &fixture, 0).await.unwrap();
+
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert!(launch_pool.is_refunding);
+    assert_eq!(launch_pool.refundable_amount, SOL * 6 / 10);
+    assert_error(
+        propose(&mut harness, &fixture, 1, SOL / 5, deadline + 100).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+
+    let mallory = harness.buyer().await;
+    let foreign = instructions::reclaim_funds(&fixture.pool, mallory.pubkey());
+    let foreign = replace_account(
+        foreign,
+        fixture.pool.user_pool(&mallory.pubkey()),
+        fixture.pool.user_pool(&bob.pubkey()),
+    );
+    assert_anchor_error(
+        harness.process(&[foreign], &[&mallory]).await,
+        ErrorCode::ConstraintSeeds,
+    );
+
+    let alice_lamports = harness.lamports(&alice.pubkey()).await;
+    reclaim(&mut harness, &fixture, &alice).await.unwrap();
+    assert_eq!(
+        harness.lamports(&alice.pubkey()).await,
+        alice_lamports + SOL / 10
+    );
+    assert_error(
+        reclaim(&mut harness, &fixture, &alice).await,
+        MyError::AlreadyRefunded,
+    );
+    reclaim(&mut harness, &fixture, &bob).await.unwrap();
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, 0);
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ExecuteMilestone(event)
+            if event.status == MilestoneState::Rejected
+                && event.refundable_amount == SOL * 6 / 10)
+    });
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ReclaimFunds(event)
+            if event.user == alice.pubkey() && event.amount == SOL / 10)
+    });
+
+    // a refunded position is settled
+    let close = instructions::close_user_pool(&fixture.pool, alice.pubkey());
+    harness.process(&[close], &[&alice]).await.unwrap();
+}
+
+// fails milestone index with no votes so the pool refunds what is left in the vault
+async fn reject_milestone(harness: &mut Harness, fixture: &PoolFixture, index: u8) {
+    let deadline = harness.now().await + 100;
+    propose(harness, fixture, index, SOL / 5, deadline)
+        .await
+        .unwrap();
+    harness.warp_to(deadline).await;
+    execute(harness, fixture, index).await.unwrap();
+}
+
+#[tokio::test]
+async fn claim_and_reclaim_exclude_each_other() {
+    let mut harness = Harness::new().await;
+    let (fixture, alice, bob) = harness.governed_pool().await;
+    reject_milestone(&mut harness, &fixture, 0).await;
+    harness.warp_to(fixture.unlock_date).await;
+
+    // a refunded buyer cannot take the tokens as well
+    reclaim(&mut harness, &fixture, &alice).await.unwrap();
+    assert_error(
+        harness.claim(&fixture, &alice).await,
+        MyError::AlreadyRefunded,
+    );
+
+    // nor can a buyer who took the tokens get a refund
+    harness.claim(&fixture, &bob).await.unwrap();
+    assert_error(
+        reclaim(&mut harness, &fixture, &bob).await,
+        MyError::AlreadyClaimed,
+    );
+}
+
+#[tokio::test]
+async fn last_reclaim_takes_the_rounding_remainder() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    configure(&mut harness, &fixture, 5_000).await.unwrap();
+    harness.start(&fixture).await.unwrap();
+    let mut buyers = vec![];
+    for _ in 0..3 {
+        let buyer = harness.buyer().await;
+        harness.buy(&fixture, &buyer, 10 * TOKEN).await.unwrap();
+        buyers.push(buyer);
+    }
+    harness.complete(&fixture).await.unwrap();
+
+    // releasing a single lamport leaves a refund that three equal positions cannot split evenly
+    let deadline = harness.now().await + 100;
+    propose(&mut harness, &fixture, 0, 1, deadline)
+        .await
+        .unwrap();
+    vote(&mut harness, &fixture, 0, &buyers[0], true)
+        .await
+        .unwrap();
+    vote(&mut harness, &fixture, 0, &buyers[1], true)
+        .await
+        .unwrap();
+    harness.warp_to(deadline).await;
+    execute(&mut harness, &fixture, 0).await.unwrap();
+    reject_milestone(&mut harness, &fixture, 1).await;
+
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.refundable_amount, SOL * 3 / 10 - 1);
+
+    let mut refunds = vec![];
+    for buyer in &buyers {
+        let before = harness.lamports(&buyer.pubkey()).await;
+        reclaim(&mut harness, &fixture, buyer).await.unwrap();
+        refunds.push(harness.lamports(&buyer.pubkey()).await - before);
+    }
+    assert_eq!(refunds, [SOL / 10 - 1, SOL / 10, SOL / 10]);
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, 0);
+
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.refunded_sold_amount, 30 * TOKEN);
+    assert_eq!(launch_pool.refunded_total, launch_pool.refundable_amount);
+}
+
+#[tokio::test]
+async fn claimed_positions_leave_the_refund_to_the_others() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    configure(&mut harness, &fixture, 5_000).await.unwrap();
+    harness.start(&fixture).await.unwrap();
+    let alice = harness.buyer().await;
+    let bob = harness.buyer().await;
+    let carol = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.buy(&fixture, &bob, 20 * TOKEN).await.unwrap();
+    harness.buy(&fixture, &carol, 30 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+
+    // alice takes her tokens before the vote fails, so the refund is split over bob and carol
+    harness.warp_to(fixture.unlock_date).await;
+    harness.claim(&fixture, &alice).await.unwrap();
+    reject_milestone(&mut harness, &fixture, 0).await;
+
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.claimed_sold_amount, 10 * TOKEN);
+    assert_eq!(launch_pool.refund_sold_amount, 50 * TOKEN);
+    assert_eq!(launch_pool.refundable_amount, SOL * 6 / 10);
+
+    // carol claims while refunding, leaving the whole refund to bob
+    harness.claim(&fixture, &carol).await.unwrap();
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.refund_sold_amount, 20 * TOKEN);
+
+    let bob_lamports = harness.lamports(&bob.pubkey()).await;
+    reclaim(&mut harness, &fixture, &bob).await.unwrap();
+    assert_eq!(
+        harness.lamports(&bob.pubkey()).await,
+        bob_lamports + SOL * 6 / 10
+    );
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, 0);
+}
+
+#[tokio::test]
+async fn closing_waits_for_the_milestones() {
+    let mut harness = Harness::new().await;
+    let (fixture, alice, bob) = harness.governed_pool().await;
+    for (index, amount) in [(0, SOL / 5), (1, SOL * 2 / 5)] {
+        let deadline = harness.now().await + 100;
+        propose(&mut harness, &fixture, index, amount, deadline)
+            .await
+            .unwrap();
+        vote(&mut harness, &fixture, index, &bob, true)
+            .await
+            .unwrap();
+        harness.warp_to(deadline).await;
+        if index == 0 {
+            execute(&mut harness, &fixture, index).await.unwrap();
+        }
+    }
+    harness.settle(&fixture, &[&alice, &bob]).await;
+
+    // the proceeds of the approved but unexecuted milestone are still owed to the creator
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, SOL * 2 / 5);
+    assert_error(harness.close(&fixture).await, MyError::LaunchPoolNotSettled);
+
+    execute(&mut harness, &fixture, 1).await.unwrap();
+    harness.close(&fixture).await.unwrap();
+}
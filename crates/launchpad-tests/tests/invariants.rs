@@ -0,0 +1,173 @@
+//! Property tests of the pool accounting.
+//!
+//! Every case creates a pool with random parameters and replays a random
+//! sequence of start, buy, complete, claim and clock jumps by random actors.
+//! Most calls are expected to fail somewhere along the way; whatever the
+//! outcome, the invariants are checked against the on-chain accounts after
+//! every step. Set `PROPTEST_CASES` to run more cases than the default.
+
+use encode_sol_team3::state::{LaunchPool, LaunchPoolState, Treasurer, UserPool};
+use launchpad_client::instructions;
+use launchpad_tests::{Harness, PoolFixture, PoolParams, TOKEN};
+use proptest::{prelude::*, test_runner::TestCaseError};
+use solana_sdk::signature::{Keypair, Signer};
+
+const ACTORS: usize = 3;
+
+#[derive(Clone, Debug)]
+enum Step {
+    Start,
+    // the amount is in hundredths of a token so that the zero-cost and limit paths are hit
+    Buy { actor: usize, centi_tokens: u64 },
+    Complete { by_creator: bool },
+    Claim { actor: usize },
+    Warp { seconds: i64 },
+}
+
+// whole-token pool parameters, kept valid so that every case gets a pool
+#[derive(Clone, Debug)]
+struct Pool {
+    pool_size: u64,
+    minimum: u64,
+    maximum: u64,
+    rate: u64,
+    unlock_in: i64,
+}
+
+impl Pool {
+    fn params(&self) -> PoolParams {
+        PoolParams {
+            unlock_in: self.unlock_in,
+            pool_size: self.pool_size * TOKEN,
+            minimum_token_amount: self.minimum * TOKEN,
+            maximum_token_amount: self.maximum * TOKEN,
+            rate: self.rate,
+            ..PoolParams::default()
+        }
+    }
+}
+
+fn pool() -> impl Strategy<Value = Pool> {
+    (10..=1_000u64, 1..=5u64, 1..=1_000u64, 1..=7_200i64).prop_flat_map(
+        |(pool_size, minimum, rate, unlock_in)| {
+            (minimum..=pool_size).prop_map(move |maximum| Pool {
+                pool_size,
+                minimum,
+                maximum,
+                rate,
+                unlock_in,
+            })
+        },
+    )
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    prop_oneof![
+        1 => Just(Step::Start),
+        6 => (0..ACTORS, 0..=20_000u64)
+            .prop_map(|(actor, centi_tokens)| Step::Buy { actor, centi_tokens }),
+        1 => any::<bool>().prop_map(|by_creator| Step::Complete { by_creator }),
+        3 => (0..ACTORS).prop_map(|actor| Step::Claim { actor }),
+        2 => (0..=3_600i64).prop_map(|seconds| Step::Warp { seconds }),
+    ]
+}
+
+async fn apply(harness: &mut Harness, fixture: &PoolFixture, actors: &[Keypair], step: &Step) {
+    // failures are part of the exploration, only the accounts left behind matter
+    let _ = match *step {
+        Step::Start => harness.start(fixture).await,
+        Step::Buy {
+            actor,
+            centi_tokens,
+        } => {
+            let amount = centi_tokens * (TOKEN / 100);
+            harness.buy(fixture, &actors[actor], amount).await
+        }
+        Step::Complete { by_creator: true } => harness.complete(fixture).await,
+        Step::Complete { by_creator: false } => {
+            let instruction = instructions::complete_launch_pool(&fixture.pool, actors[0].pubkey());
+            harness.process(&[instruction], &[&actors[0]]).await
+        }
+        Step::Claim { actor } => harness.claim(fixture, &actors[actor]).await,
+        Step::Warp { seconds } => {
+            harness.warp_by(seconds).await;
+            Ok(())
+        }
+    };
+}
+
+async fn check_invariants(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    actors: &[Keypair],
+) -> Result<(), TestCaseError> {
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+
+    let mut sold = 0;
+    let mut paid = 0;
+    let mut claimed = 0;
+    for actor in actors {
+        let address = fixture.pool.user_pool(&actor.pubkey());
+        if let Some(user_pool) = harness.try_account::<UserPool>(&address).await {
+            prop_assert!(user_pool.claimed <= user_pool.amount);
+            sold += user_pool.amount;
+            paid += user_pool.currency_amount;
+            claimed += user_pool.claimed;
+        }
+    }
+
+    prop_assert_eq!(launch_pool.vault_amount, paid);
+    // nothing is ever withdrawn, so the vault holds every lamport paid
+    prop_assert_eq!(harness.lamports(&fixture.pool.vault).await, paid);
+
+    // the supply only moves to the treasury, and into pool_size_remaining, when the pool starts
+    if launch_pool.status != LaunchPoolState::Pending {
+        prop_assert_eq!(
+            launch_pool.pool_size_remaining + sold,
+            launch_pool.pool_size
+        );
+        let treasurer: Treasurer = harness.account(&fixture.pool.treasurer).await;
+        prop_assert_eq!(treasurer.amount, launch_pool.pool_size - claimed);
+        prop_assert_eq!(
+            harness.token_balance(&fixture.pool.treasury).await,
+            treasurer.amount
+        );
+    } else {
+        prop_assert_eq!(sold, 0);
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        // every case boots its own bank, so keep the default run short
+        cases: std::env::var("PROPTEST_CASES")
+            .ok()
+            .and_then(|cases| cases.parse().ok())
+            .unwrap_or(24),
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn pool_accounting_holds_for_any_sequence(
+        pool in pool(),
+        steps in prop::collection::vec(step(), 1..40),
+    ) {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let mut harness = Harness::new().await;
+            let fixture = harness.create_pool(pool.params()).await;
+            let mut actors = Vec::with_capacity(ACTORS);
+            for _ in 0..ACTORS {
+                actors.push(harness.buyer().await);
+            }
+
+            check_invariants(&mut harness, &fixture, &actors).await?;
+            for step in &steps {
+                apply(&mut harness, &fixture, &actors, step).await;
+                check_invariants(&mut harness, &fixture, &actors).await?;
+            }
+            Ok::<_, TestCaseError>(())
+        })?;
+    }
+}
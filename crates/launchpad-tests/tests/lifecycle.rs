@@ -0,0 +1,344 @@
+use encode_sol_team3::{
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, Treasurer, UnsoldPolicy, UserPool},
+};
+use launchpad_client::{instructions, PoolEvent};
+use launchpad_tests::{assert_error, Harness, PoolParams, SOL, TOKEN};
+use solana_sdk::{signature::Signer, system_instruction};
+
+#[tokio::test]
+async fn pool_runs_from_creation_to_close() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert!(launch_pool.status == LaunchPoolState::Pending);
+    assert_eq!(launch_pool.creator, fixture.creator.pubkey());
+    assert_eq!(launch_pool.unlock_date, fixture.unlock_date);
+
+    harness.start(&fixture).await.unwrap();
+    assert_eq!(
+        harness.token_balance(&fixture.pool.treasury).await,
+        1_000 * TOKEN
+    );
+    assert_eq!(
+        harness.token_balance(&fixture.creator_tokens).await,
+        1_000 * TOKEN
+    );
+
+    let alice = harness.buyer().await;
+    let bob = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.buy(&fixture, &bob, 25 * TOKEN).await.unwrap();
+    harness.buy(&fixture, &bob, 25 * TOKEN).await.unwrap();
+
+    // the rate is 100 tokens per SOL
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.pool_size_remaining, 940 * TOKEN);
+    assert_eq!(launch_pool.vault_amount, SOL * 6 / 10);
+    assert_eq!(launch_pool.open_positions, 2);
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, SOL * 6 / 10);
+
+    let bob_pool: UserPool = harness
+        .account(&fixture.pool.user_pool(&bob.pubkey()))
+        .await;
+    assert_eq!(bob_pool.amount, 50 * TOKEN);
+    assert_eq!(bob_pool.currency_amount, SOL / 2);
+
+    harness.complete(&fixture).await.unwrap();
+    assert_error(
+        harness.claim(&fixture, &alice).await,
+        MyError::TimeLockNotExpired,
+    );
+
+    harness.warp_to(fixture.unlock_date).await;
+    harness.claim(&fixture, &alice).await.unwrap();
+    harness.claim(&fixture, &bob).await.unwrap();
+    assert_error(
+        harness.claim(&fixture, &alice).await,
+        MyError::InvalidAmount,
+    );
+
+    let alice_tokens = launchpad_tests::associated_token(&alice.pubkey(), &fixture.mint());
+    assert_eq!(harness.token_balance(&alice_tokens).await, 10 * TOKEN);
+    let treasurer: Treasurer = harness.account(&fixture.pool.treasurer).await;
+    assert_eq!(treasurer.amount, 940 * TOKEN);
+
+    let creator_lamports = harness.lamports(&fixture.creator.pubkey()).await;
+    harness.withdraw_proceeds(&fixture).await.unwrap();
+    assert_eq!(
+        harness.lamports(&fixture.creator.pubkey()).await,
+        creator_lamports + SOL * 6 / 10
+    );
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, 0);
+
+    let finalize = instructions::finalize_unsold_tokens(
+        &fixture.pool,
+        UnsoldPolicy::RefundToCreator,
+        Some(fixture.creator_tokens),
+    );
+    harness.process(&[finalize], &[]).await.unwrap();
+    assert_eq!(
+        harness.token_balance(&fixture.creator_tokens).await,
+        1_940 * TOKEN
+    );
+    assert_eq!(harness.token_balance(&fixture.pool.treasury).await, 0);
+
+    for user in [&alice, &bob] {
+        let close = instructions::close_user_pool(&fixture.pool, user.pubkey());
+        harness.process(&[close], &[user]).await.unwrap();
+        assert!(
+            !harness
+                .exists(&fixture.pool.user_pool(&user.pubkey()))
+                .await
+        );
+    }
+
+    let close = instructions::close_launch_pool(&fixture.pool, fixture.creator.pubkey());
+    harness
+        .process(&[close], &[&fixture.creator])
+        .await
+        .unwrap();
+    assert!(!harness.exists(&fixture.launch_pool()).await);
+    assert!(!harness.exists(&fixture.pool.treasurer).await);
+    assert!(!harness.exists(&fixture.pool.treasury).await);
+
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::CloseUserPool(event)
+            if event.user == bob.pubkey() && event.open_positions == 0)
+    });
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::CloseLaunchPool(event)
+            if event.authority == fixture.creator.pubkey()
+                && event.status == LaunchPoolState::Completed)
+    });
+}
+
+#[tokio::test]
+async fn closing_sweeps_what_is_left_in_the_vault_to_the_creator() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+    harness.settle(&fixture, &[&alice]).await;
+
+    // lamports sent to the vault outside of any purchase belong to no buyer
+    let payer = harness.payer();
+    let stray = system_instruction::transfer(&payer, &fixture.pool.vault, SOL / 100);
+    harness.process(&[stray], &[]).await.unwrap();
+    let vault = harness.lamports(&fixture.pool.vault).await;
+    assert_eq!(vault, SOL / 100);
+    let mut rent = 0;
+    for account in [
+        fixture.launch_pool(),
+        fixture.pool.treasurer,
+        fixture.pool.treasury,
+    ] {
+        rent += harness.lamports(&account).await;
+    }
+    let creator_lamports = harness.lamports(&fixture.creator.pubkey()).await;
+
+    harness.close(&fixture).await.unwrap();
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, 0);
+    assert_eq!(
+        harness.lamports(&fixture.creator.pubkey()).await,
+        creator_lamports + vault + rent
+    );
+}
+
+#[tokio::test]
+async fn buyers_pay_what_the_quote_says() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let alice = harness.buyer().await;
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+
+    let quoted = launchpad_client::quote(&launch_pool, 10 * TOKEN).unwrap();
+    let vault_before = harness.lamports(&fixture.pool.vault).await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    assert_eq!(
+        harness.lamports(&fixture.pool.vault).await - vault_before,
+        quoted
+    );
+
+    // a pool without a rate cannot be quoted instead of panicking
+    let unpriced = LaunchPool {
+        rate: 0,
+        ..launch_pool
+    };
+    assert_eq!(launchpad_client::quote(&unpriced, 10 * TOKEN), None);
+}
+
+#[tokio::test]
+async fn claim_waits_for_unlock_date() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+
+    harness.warp_to(fixture.unlock_date - 1).await;
+    assert_error(
+        harness.claim(&fixture, &alice).await,
+        MyError::TimeLockNotExpired,
+    );
+
+    harness.warp_by(1).await;
+    harness.claim(&fixture, &alice).await.unwrap();
+
+    let alice_pool: UserPool = harness
+        .account(&fixture.pool.user_pool(&alice.pubkey()))
+        .await;
+    assert_eq!(alice_pool.claimed, 10 * TOKEN);
+}
+
+#[tokio::test]
+async fn vested_proceeds_are_released_by_period() {
+    let mut harness = Harness::new().await;
+    let fixture = harness
+        .active_pool(PoolParams {
+            proceeds_vesting: Some(encode_sol_team3::state::ProceedsVesting {
+                completion_bps: 5_000,
+                period: 100,
+                periods: 2,
+            }),
+            ..PoolParams::default()
+        })
+        .await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 40 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+
+    harness.withdraw_proceeds(&fixture).await.unwrap();
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, SOL / 5);
+    assert_error(
+        harness.withdraw_proceeds(&fixture).await,
+        MyError::InvalidAmount,
+    );
+
+    harness.warp_by(100).await;
+    harness.withdraw_proceeds(&fixture).await.unwrap();
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, SOL / 10);
+
+    harness.warp_by(100).await;
+    harness.withdraw_proceeds(&fixture).await.unwrap();
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, 0);
+
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.proceeds_withdrawn, SOL * 4 / 10);
+}
+
+#[tokio::test]
+async fn closing_waits_for_the_vested_proceeds() {
+    let mut harness = Harness::new().await;
+    let fixture = harness
+        .active_pool(PoolParams {
+            proceeds_vesting: Some(encode_sol_team3::state::ProceedsVesting {
+                completion_bps: 5_000,
+                period: 10_000,
+                periods: 1,
+            }),
+            ..PoolParams::default()
+        })
+        .await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+    harness.settle(&fixture, &[&alice]).await;
+
+    // half of the proceeds are still vesting in the vault
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, SOL / 20);
+    assert_error(harness.close(&fixture).await, MyError::LaunchPoolNotSettled);
+
+    harness.warp_by(10_000).await;
+    harness.withdraw_proceeds(&fixture).await.unwrap();
+    harness.close(&fixture).await.unwrap();
+}
+
+#[tokio::test]
+async fn unsold_tokens_are_burned_by_policy() {
+    let mut harness = Harness::new().await;
+    let fixture = harness
+        .active_pool(PoolParams {
+            unsold_policy: Some(UnsoldPolicy::Burn),
+            ..PoolParams::default()
+        })
+        .await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+
+    let finalize = instructions::finalize_unsold_tokens(&fixture.pool, UnsoldPolicy::Burn, None);
+    harness.process(&[finalize], &[]).await.unwrap();
+
+    // the buyer's tokens stay claimable
+    assert_eq!(
+        harness.token_balance(&fixture.pool.treasury).await,
+        10 * TOKEN
+    );
+    let mint: anchor_spl::token::Mint = harness.account(&fixture.mint()).await;
+    assert_eq!(mint.supply, 1_010 * TOKEN);
+}
+
+#[tokio::test]
+async fn unsold_tokens_without_a_policy_are_left_to_the_authority() {
+    let mut harness = Harness::new().await;
+    let fixture = harness
+        .active_pool(PoolParams {
+            unsold_policy: None,
+            ..PoolParams::default()
+        })
+        .await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+
+    let finalize = instructions::finalize_unsold_tokens(&fixture.pool, UnsoldPolicy::Burn, None);
+    let result = harness.process(&[finalize], &[]).await;
+    assert_error(result, MyError::UnsoldPolicyNotDeclared);
+
+    let stranger = harness.wallet(SOL).await;
+    let withdraw =
+        instructions::withdraw_unsold_tokens(&fixture.pool, stranger.pubkey(), None, true);
+    let result = harness.process(&[withdraw], &[&stranger]).await;
+    assert_error(result, MyError::InvalidAuthority);
+
+    let withdraw =
+        instructions::withdraw_unsold_tokens(&fixture.pool, fixture.creator.pubkey(), None, true);
+    harness
+        .process(std::slice::from_ref(&withdraw), &[&fixture.creator])
+        .await
+        .unwrap();
+    assert_eq!(
+        harness.token_balance(&fixture.pool.treasury).await,
+        10 * TOKEN
+    );
+    let mint: anchor_spl::token::Mint = harness.account(&fixture.mint()).await;
+    assert_eq!(mint.supply, 1_010 * TOKEN);
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::WithdrawUnsoldTokens(event)
+            if event.authority == fixture.creator.pubkey()
+                && event.amount == 990 * TOKEN
+                && event.burned
+                && event.treasurer_amount == 10 * TOKEN)
+    });
+
+    harness.warp_by(1).await;
+    let result = harness.process(&[withdraw], &[&fixture.creator]).await;
+    assert_error(result, MyError::UnsoldTokensWithdrawn);
+}
+
+#[tokio::test]
+async fn a_declared_policy_cannot_be_bypassed_by_the_authority() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+
+    let withdraw =
+        instructions::withdraw_unsold_tokens(&fixture.pool, fixture.creator.pubkey(), None, true);
+    let result = harness.process(&[withdraw], &[&fixture.creator]).await;
+    assert_error(result, MyError::UnsoldPolicyDeclared);
+}
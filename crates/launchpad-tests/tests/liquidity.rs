@@ -0,0 +1,303 @@
+use encode_sol_team3::{
+    errors::MyError,
+    state::{LaunchPool, LpLock},
+};
+use launchpad_client::{
+    find_liquidity_escrow, find_lp_lock,
+    instructions::{self, ConfigureLiquidityArgs},
+    PoolEvent,
+};
+use launchpad_tests::{
+    assert_error, associated_token, replace_account, Harness, PoolFixture, PoolParams, TxResult,
+    SOL, TOKEN,
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+#[tokio::test]
+async fn completing_seeds_exactly_the_liquidity_share_through_the_escrow() {
+    let mut harness = Harness::new().await;
+    let (fixture, amm) = harness.liquidity_pool().await;
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ConfigureLiquidity(event)
+            if event.amm_pool == amm.pool
+                && event.liquidity_bps == 5_000
+                && event.listing_rate == 100
+                && event.lp_unlock_date == fixture.unlock_date + 86_400)
+    });
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 100 * TOKEN).await.unwrap();
+
+    let vault_before = harness.lamports(&fixture.pool.vault).await;
+    let treasury_before = harness.token_balance(&fixture.pool.treasury).await;
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    let complete = instructions::complete_launch_pool_with_liquidity(
+        &fixture.pool,
+        &launch_pool,
+        fixture.creator.pubkey(),
+        amm.lp_mint,
+        amm.token_reserve,
+    );
+    harness
+        .process(&[complete], &[&fixture.creator])
+        .await
+        .unwrap();
+
+    // 1 SOL raised, half of it listed with 50 tokens
+    let sol_amount = SOL / 2;
+    let token_amount = 50 * TOKEN;
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.liquidity_sol_amount, sol_amount);
+    assert_eq!(launch_pool.liquidity_token_amount, token_amount);
+    assert_eq!(
+        harness.lamports(&fixture.pool.vault).await,
+        vault_before - sol_amount
+    );
+    assert_eq!(
+        harness.token_balance(&fixture.pool.treasury).await,
+        treasury_before - token_amount
+    );
+
+    let pool: mock_amm::Pool = harness.account(&amm.pool).await;
+    assert_eq!(pool.sol_amount, sol_amount);
+    assert_eq!(pool.token_amount, token_amount);
+    assert_eq!(
+        harness.token_balance(&amm.token_reserve).await,
+        token_amount
+    );
+
+    // the escrow passes everything on and keeps nothing
+    let liquidity_escrow = find_liquidity_escrow(&fixture.launch_pool()).0;
+    assert_eq!(harness.lamports(&liquidity_escrow).await, 0);
+    assert_eq!(
+        harness
+            .token_balance(&associated_token(&liquidity_escrow, &fixture.mint()))
+            .await,
+        0
+    );
+
+    let lp_lock: LpLock = harness
+        .account(&find_lp_lock(&fixture.launch_pool()).0)
+        .await;
+    assert!(lp_lock.amount > 0);
+    assert_eq!(
+        harness.token_balance(&lp_lock.lp_token_account).await,
+        lp_lock.amount
+    );
+}
+
+#[tokio::test]
+async fn liquidity_pools_need_the_liquidity_accounts_to_complete() {
+    let mut harness = Harness::new().await;
+    let (fixture, _) = harness.liquidity_pool().await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 100 * TOKEN).await.unwrap();
+
+    let result = harness.complete(&fixture).await;
+    assert_error(result, MyError::LiquidityAccountsRequired);
+}
+
+async fn withdraw_lp(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    authority: &Keypair,
+    destination: Pubkey,
+) -> TxResult {
+    let lp_lock: LpLock = harness
+        .account(&find_lp_lock(&fixture.launch_pool()).0)
+        .await;
+    let instruction = instructions::withdraw_lp(
+        &fixture.pool,
+        authority.pubkey(),
+        lp_lock.lp_mint,
+        destination,
+    );
+    harness.process(&[instruction], &[authority]).await
+}
+
+async fn extend_lp_lock(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    authority: &Keypair,
+    unlock_date: i64,
+) -> TxResult {
+    let instruction = instructions::extend_lock(&fixture.pool, authority.pubkey(), unlock_date);
+    harness.process(&[instruction], &[authority]).await
+}
+
+#[tokio::test]
+async fn lp_tokens_stay_locked_until_the_unlock_date() {
+    let mut harness = Harness::new().await;
+    let (fixture, amm) = harness.locked_liquidity_pool().await;
+    let lp_lock: LpLock = harness
+        .account(&find_lp_lock(&fixture.launch_pool()).0)
+        .await;
+    let destination = harness
+        .mint_to(&amm.lp_mint, &fixture.creator.pubkey(), 0)
+        .await;
+
+    let result = withdraw_lp(&mut harness, &fixture, &fixture.creator, destination).await;
+    assert_error(result, MyError::TimeLockNotExpired);
+
+    let stranger = harness.wallet(SOL).await;
+    harness.warp_to(lp_lock.unlock_date).await;
+    let result = withdraw_lp(&mut harness, &fixture, &stranger, destination).await;
+    assert_error(result, MyError::InvalidAuthority);
+
+    withdraw_lp(&mut harness, &fixture, &fixture.creator, destination)
+        .await
+        .unwrap();
+    assert_eq!(harness.token_balance(&destination).await, lp_lock.amount);
+    assert_eq!(harness.token_balance(&lp_lock.lp_token_account).await, 0);
+
+    let emptied: LpLock = harness
+        .account(&find_lp_lock(&fixture.launch_pool()).0)
+        .await;
+    assert_eq!(emptied.amount, 0);
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::WithdrawLp(event)
+            if event.authority == fixture.creator.pubkey() && event.amount == lp_lock.amount)
+    });
+
+    let result = withdraw_lp(&mut harness, &fixture, &fixture.creator, destination).await;
+    assert_error(result, MyError::InvalidAmount);
+}
+
+#[tokio::test]
+async fn liquidity_is_seeded_into_a_pool_of_the_amm() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let amm = harness.create_amm_pool(fixture.mint()).await;
+
+    // the creator's wallet is not an account of the AMM program
+    let instruction = instructions::configure_liquidity(
+        &fixture.pool,
+        fixture.creator.pubkey(),
+        mock_amm::ID,
+        fixture.creator.pubkey(),
+        amm.lp_mint,
+        ConfigureLiquidityArgs {
+            liquidity_bps: 5_000,
+            listing_rate: 100,
+            liquidity_token_reserve: 100 * TOKEN,
+            lp_unlock_date: fixture.unlock_date + 86_400,
+        },
+    );
+    assert_error(
+        harness.process(&[instruction], &[&fixture.creator]).await,
+        MyError::InvalidAmmPool,
+    );
+}
+
+#[tokio::test]
+async fn lp_tokens_leave_only_from_the_lock_account() {
+    let mut harness = Harness::new().await;
+    let (fixture, amm) = harness.locked_liquidity_pool().await;
+    let lp_lock: LpLock = harness
+        .account(&find_lp_lock(&fixture.launch_pool()).0)
+        .await;
+    let destination = harness
+        .mint_to(&amm.lp_mint, &fixture.creator.pubkey(), 0)
+        .await;
+    harness.warp_to(lp_lock.unlock_date).await;
+
+    // the creator's own LP token account passes as an account of the LP mint but is not the lock's
+    let withdraw = replace_account(
+        instructions::withdraw_lp(
+            &fixture.pool,
+            fixture.creator.pubkey(),
+            amm.lp_mint,
+            destination,
+        ),
+        lp_lock.lp_token_account,
+        destination,
+    );
+    assert_error(
+        harness.process(&[withdraw], &[&fixture.creator]).await,
+        MyError::InvalidLpLock,
+    );
+}
+
+#[tokio::test]
+async fn lp_locks_can_only_be_extended() {
+    let mut harness = Harness::new().await;
+    let (fixture, amm) = harness.locked_liquidity_pool().await;
+    let lp_lock: LpLock = harness
+        .account(&find_lp_lock(&fixture.launch_pool()).0)
+        .await;
+    let destination = harness
+        .mint_to(&amm.lp_mint, &fixture.creator.pubkey(), 0)
+        .await;
+
+    let result = extend_lp_lock(
+        &mut harness,
+        &fixture,
+        &fixture.creator,
+        lp_lock.unlock_date - 1,
+    )
+    .await;
+    assert_error(result, MyError::InvalidUnlockDate);
+
+    let stranger = harness.wallet(SOL).await;
+    let extended_date = lp_lock.unlock_date + 86_400;
+    let result = extend_lp_lock(&mut harness, &fixture, &stranger, extended_date).await;
+    assert_error(result, MyError::InvalidAuthority);
+
+    extend_lp_lock(&mut harness, &fixture, &fixture.creator, extended_date)
+        .await
+        .unwrap();
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ExtendLpLock(event)
+            if event.authority == fixture.creator.pubkey() && event.unlock_date == extended_date)
+    });
+
+    // the original date no longer unlocks anything
+    harness.warp_to(lp_lock.unlock_date).await;
+    let result = withdraw_lp(&mut harness, &fixture, &fixture.creator, destination).await;
+    assert_error(result, MyError::TimeLockNotExpired);
+
+    harness.warp_to(extended_date).await;
+    withdraw_lp(&mut harness, &fixture, &fixture.creator, destination)
+        .await
+        .unwrap();
+    assert_eq!(harness.token_balance(&destination).await, lp_lock.amount);
+}
+
+#[tokio::test]
+async fn lp_lock_keeps_the_pool_open_until_withdrawn() {
+    let mut harness = Harness::new().await;
+    let (fixture, amm) = harness.liquidity_pool().await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 100 * TOKEN).await.unwrap();
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    let complete = instructions::complete_launch_pool_with_liquidity(
+        &fixture.pool,
+        &launch_pool,
+        fixture.creator.pubkey(),
+        amm.lp_mint,
+        amm.token_reserve,
+    );
+    harness
+        .process(&[complete], &[&fixture.creator])
+        .await
+        .unwrap();
+    harness.settle(&fixture, &[&alice]).await;
+
+    let result = harness.close(&fixture).await;
+    assert_error(result, MyError::LaunchPoolNotSettled);
+
+    let lp_lock: LpLock = harness
+        .account(&find_lp_lock(&fixture.launch_pool()).0)
+        .await;
+    let destination = harness
+        .mint_to(&amm.lp_mint, &fixture.creator.pubkey(), 0)
+        .await;
+    harness.warp_to(lp_lock.unlock_date).await;
+    withdraw_lp(&mut harness, &fixture, &fixture.creator, destination)
+        .await
+        .unwrap();
+    harness.close(&fixture).await.unwrap();
+    assert!(!harness.exists(&fixture.launch_pool()).await);
+}
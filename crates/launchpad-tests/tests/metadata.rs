@@ -0,0 +1,160 @@
+use encode_sol_team3::{
+    constants::{
+        MAX_POOL_NAME_LENGTH, MAX_POOL_SOCIALS, MAX_POOL_SYMBOL_LENGTH, MAX_POOL_URI_LENGTH,
+    },
+    errors::MyError,
+    state::{PoolMetadata, UnsoldPolicy},
+};
+use launchpad_client::instructions::{self, PoolMetadataArgs};
+use launchpad_tests::{assert_error, Harness, PoolFixture, PoolParams, TxResult, SOL};
+use solana_sdk::signature::{Keypair, Signer};
+
+fn args() -> PoolMetadataArgs {
+    PoolMetadataArgs {
+        name: "Launch Token".to_string(),
+        symbol: "LT".to_string(),
+        uri: "https://example.com/launch.json".to_string(),
+        website: Some("https://example.com".to_string()),
+        socials: vec!["https://x.com/launch".to_string()],
+        content_hash: [7; 32],
+    }
+}
+
+async fn update(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    authority: &Keypair,
+    args: PoolMetadataArgs,
+) -> TxResult {
+    let instruction = instructions::update_pool_metadata(&fixture.pool, authority.pubkey(), args);
+    harness.process(&[instruction], &[authority]).await
+}
+
+#[tokio::test]
+async fn creator_describes_a_pending_pool() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let creator = &fixture.creator;
+
+    update(&mut harness, &fixture, creator, args())
+        .await
+        .unwrap();
+    let metadata: PoolMetadata = harness.account(&fixture.pool.pool_metadata()).await;
+    assert_eq!(metadata.launch_pool, fixture.launch_pool());
+    assert_eq!(metadata.name, "Launch Token");
+    assert_eq!(metadata.symbol, "LT");
+    assert_eq!(metadata.website.as_deref(), Some("https://example.com"));
+    assert_eq!(metadata.socials, ["https://x.com/launch"]);
+    assert_eq!(metadata.content_hash, [7; 32]);
+
+    // a later call replaces the whole description
+    let replacement = PoolMetadataArgs {
+        uri: "https://example.com/v2.json".to_string(),
+        website: None,
+        socials: vec![],
+        content_hash: [9; 32],
+        ..args()
+    };
+    update(&mut harness, &fixture, creator, replacement)
+        .await
+        .unwrap();
+    let metadata: PoolMetadata = harness.account(&fixture.pool.pool_metadata()).await;
+    assert_eq!(metadata.uri, "https://example.com/v2.json");
+    assert_eq!(metadata.website, None);
+    assert!(metadata.socials.is_empty());
+    assert_eq!(metadata.content_hash, [9; 32]);
+
+    // the description is frozen once buyers can see the pool open
+    harness.start(&fixture).await.unwrap();
+    assert_error(
+        update(&mut harness, &fixture, creator, args()).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+}
+
+#[tokio::test]
+async fn update_pool_metadata_checks_its_arguments() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let creator = &fixture.creator;
+    let url = |len: usize| format!("https://{}", "a".repeat(len - 8));
+
+    let invalid = [
+        PoolMetadataArgs {
+            name: String::new(),
+            ..args()
+        },
+        PoolMetadataArgs {
+            name: "n".repeat(MAX_POOL_NAME_LENGTH + 1),
+            ..args()
+        },
+        PoolMetadataArgs {
+            symbol: "S".repeat(MAX_POOL_SYMBOL_LENGTH + 1),
+            ..args()
+        },
+        PoolMetadataArgs {
+            uri: url(MAX_POOL_URI_LENGTH + 1),
+            ..args()
+        },
+        PoolMetadataArgs {
+            website: Some(String::new()),
+            ..args()
+        },
+        PoolMetadataArgs {
+            socials: vec![url(20); MAX_POOL_SOCIALS + 1],
+            ..args()
+        },
+    ];
+    for args in invalid {
+        assert_error(
+            update(&mut harness, &fixture, creator, args).await,
+            MyError::InvalidPoolMetadata,
+        );
+    }
+
+    let impostor = harness.wallet(SOL).await;
+    assert_error(
+        update(&mut harness, &fixture, &impostor, args()).await,
+        MyError::InvalidAuthority,
+    );
+    assert!(!harness.exists(&fixture.pool.pool_metadata()).await);
+
+    // every field at its limit fits in the account
+    let longest = PoolMetadataArgs {
+        name: "n".repeat(MAX_POOL_NAME_LENGTH),
+        symbol: "S".repeat(MAX_POOL_SYMBOL_LENGTH),
+        uri: url(MAX_POOL_URI_LENGTH),
+        website: Some(url(MAX_POOL_URI_LENGTH)),
+        socials: vec![url(MAX_POOL_URI_LENGTH); MAX_POOL_SOCIALS],
+        content_hash: [1; 32],
+    };
+    update(&mut harness, &fixture, creator, longest)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn closing_the_pool_closes_its_metadata() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let creator = &fixture.creator;
+    update(&mut harness, &fixture, creator, args())
+        .await
+        .unwrap();
+    harness.start(&fixture).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+    harness.warp_to(fixture.unlock_date).await;
+    let finalize = instructions::finalize_unsold_tokens(
+        &fixture.pool,
+        UnsoldPolicy::RefundToCreator,
+        Some(fixture.creator_tokens),
+    );
+    harness.process(&[finalize], &[]).await.unwrap();
+
+    let creator_lamports = harness.lamports(&creator.pubkey()).await;
+    let metadata_lamports = harness.lamports(&fixture.pool.pool_metadata()).await;
+    let close = instructions::close_launch_pool(&fixture.pool, creator.pubkey());
+    harness.process(&[close], &[creator]).await.unwrap();
+    assert!(!harness.exists(&fixture.pool.pool_metadata()).await);
+    assert!(harness.lamports(&creator.pubkey()).await > creator_lamports + metadata_lamports);
+}
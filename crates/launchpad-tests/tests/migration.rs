@@ -0,0 +1,401 @@
+use anchor_lang::{
+    error::ErrorCode,
+    prelude::{borsh, Pubkey},
+    AnchorSerialize, Discriminator,
+};
+use encode_sol_team3::{
+    errors::MyError,
+    state::{CurrencyType, LaunchPool, LaunchPoolState, LaunchPoolType, Treasurer, UserPool},
+};
+use launchpad_client::{
+    find_treasurer, find_vault, instructions, PoolAddresses, PoolEvent, PROGRAM_ID,
+};
+use launchpad_tests::{
+    assert_anchor_error, assert_error, replace_account, Harness, PoolFixture, PoolParams, TxResult,
+    SOL, TOKEN,
+};
+use solana_sdk::{
+    account::Account,
+    signature::{Keypair, Signer},
+};
+
+// the accounts as the first program wrote them, before any field was appended
+#[derive(AnchorSerialize)]
+struct FirstLaunchPool {
+    unlock_date: i64,
+    pool_size: u64,
+    minimum_token_amount: u64,
+    maximum_token_amount: u64,
+    rate: u64,
+    pool_size_remaining: u64,
+    token_mint: Pubkey,
+    token_mint_decimals: u8,
+    authority: Pubkey,
+    vault_amount: u64,
+    is_vesting: bool,
+    currency: CurrencyType,
+    pool_type: LaunchPoolType,
+    status: LaunchPoolState,
+}
+
+#[derive(AnchorSerialize)]
+struct FirstTreasurer {
+    authority: Pubkey,
+    launch_pool: Pubkey,
+    token_mint: Pubkey,
+    amount: u64,
+}
+
+#[derive(AnchorSerialize)]
+struct FirstUserPool {
+    amount: u64,
+    currency_amount: u64,
+    claimed: u64,
+}
+
+const FIRST_LAUNCH_POOL_LEN: usize = 133;
+const FIRST_TREASURER_LEN: usize = 112;
+const FIRST_USER_POOL_LEN: usize = 32;
+
+// writes a program account of the first layout, funded for its length, and returns the rent
+async fn write_first_layout(
+    harness: &mut Harness,
+    address: &Pubkey,
+    discriminator: [u8; 8],
+    value: &impl AnchorSerialize,
+) -> u64 {
+    let mut data = discriminator.to_vec();
+    value.serialize(&mut data).unwrap();
+    let rent = harness.context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(data.len());
+    let account = Account {
+        lamports,
+        data,
+        owner: PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    harness.context.set_account(address, &account.into());
+    lamports
+}
+
+struct FirstPool {
+    fixture: PoolFixture,
+    buyers: Vec<Keypair>,
+    rent: u64,
+}
+
+// a pool of 1_000 tokens at 100 tokens per SOL as the first program left it, with alice and bob
+// holding 10 and 50 tokens of an active sale
+async fn first_layout_pool(harness: &mut Harness, status: LaunchPoolState) -> FirstPool {
+    let creator = harness.wallet(10 * SOL).await;
+    let mint = harness.create_mint().await;
+    let creator_tokens = harness
+        .mint_to(&mint, &creator.pubkey(), 2_000 * TOKEN)
+        .await;
+    let pool = PoolAddresses::first_layout(creator.pubkey(), mint);
+    let unlock_date = harness.now().await + 100;
+    let pending = status == LaunchPoolState::Pending;
+    let buyers = if pending {
+        Vec::new()
+    } else {
+        vec![harness.buyer().await, harness.buyer().await]
+    };
+    let sold: u64 = if pending { 0 } else { 60 * TOKEN };
+
+    let mut rent = write_first_layout(
+        harness,
+        &pool.launch_pool,
+        LaunchPool::DISCRIMINATOR,
+        &FirstLaunchPool {
+            unlock_date,
+            pool_size: 1_000 * TOKEN,
+            minimum_token_amount: TOKEN,
+            maximum_token_amount: 100 * TOKEN,
+            rate: 100,
+            pool_size_remaining: 1_000 * TOKEN - sold,
+            token_mint: mint,
+            token_mint_decimals: 9,
+            authority: creator.pubkey(),
+            vault_amount: sold / 100,
+            is_vesting: false,
+            currency: CurrencyType::SOL,
+            pool_type: LaunchPoolType::FairLaunch,
+            status,
+        },
+    )
+    .await;
+    rent += write_first_layout(
+        harness,
+        &pool.treasurer,
+        Treasurer::DISCRIMINATOR,
+        &FirstTreasurer {
+            authority: creator.pubkey(),
+            launch_pool: pool.launch_pool,
+            token_mint: mint,
+            amount: if pending { 0 } else { 1_000 * TOKEN },
+        },
+    )
+    .await;
+    if pending {
+        harness.mint_to(&mint, &pool.treasurer, 0).await;
+    } else {
+        harness.mint_to(&mint, &pool.treasurer, 1_000 * TOKEN).await;
+        let vault = Account {
+            lamports: sold / 100,
+            ..Account::default()
+        };
+        harness.context.set_account(&pool.vault, &vault.into());
+    }
+    for (buyer, amount) in buyers.iter().zip([10 * TOKEN, 50 * TOKEN]) {
+        rent += write_first_layout(
+            harness,
+            &pool.user_pool(&buyer.pubkey()),
+            UserPool::DISCRIMINATOR,
+            &FirstUserPool {
+                amount,
+                currency_amount: amount / 100,
+                claimed: 0,
+            },
+        )
+        .await;
+    }
+
+    FirstPool {
+        fixture: PoolFixture {
+            creator,
+            pool,
+            creator_tokens,
+            unlock_date,
+        },
+        buyers,
+        rent,
+    }
+}
+
+async fn migrate(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    payer: &Keypair,
+    users: &[Pubkey],
+) -> TxResult {
+    let instruction = instructions::migrate_launch_pool(&fixture.pool, payer.pubkey(), users);
+    harness.process(&[instruction], &[payer]).await
+}
+
+#[tokio::test]
+async fn first_layout_pool_is_migrated_in_place() {
+    let mut harness = Harness::new().await;
+    let FirstPool {
+        fixture,
+        buyers,
+        rent: old_rent,
+    } = first_layout_pool(&mut harness, LaunchPoolState::Active).await;
+    let [alice, bob] = [&buyers[0], &buyers[1]];
+    let users = [alice.pubkey(), bob.pubkey()];
+
+    // the current program cannot read the first layout
+    assert_anchor_error(
+        harness.buy(&fixture, alice, 10 * TOKEN).await,
+        ErrorCode::AccountDidNotDeserialize,
+    );
+
+    // anyone can pay for the migration
+    let keeper = harness.wallet(SOL).await;
+    migrate(&mut harness, &fixture, &keeper, &users)
+        .await
+        .unwrap();
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::MigrateLaunchPool(event)
+            if event.payer == keeper.pubkey()
+                && event.from_version == 0
+                && event.to_version == LaunchPool::VERSION
+                && event.user_pool_count == 2)
+    });
+
+    let rent = harness.context.banks_client.get_rent().await.unwrap();
+    assert_eq!(
+        old_rent,
+        rent.minimum_balance(FIRST_LAUNCH_POOL_LEN)
+            + rent.minimum_balance(FIRST_TREASURER_LEN)
+            + 2 * rent.minimum_balance(FIRST_USER_POOL_LEN)
+    );
+    let new_rent = rent.minimum_balance(LaunchPool::LEN)
+        + rent.minimum_balance(Treasurer::LEN)
+        + 2 * rent.minimum_balance(UserPool::LEN);
+    assert_eq!(
+        harness.lamports(&keeper.pubkey()).await,
+        SOL - (new_rent - old_rent)
+    );
+
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.version, LaunchPool::VERSION);
+    assert_eq!(launch_pool.creator, fixture.creator.pubkey());
+    assert_eq!(launch_pool.pool_size_remaining, 940 * TOKEN);
+    assert_eq!(launch_pool.open_positions, 2);
+    assert!(launch_pool.unsold_policy.is_none());
+    assert_eq!(
+        launch_pool.bumps.launchpool_bump,
+        launchpad_client::find_first_layout_launch_pool(&fixture.creator.pubkey(), &fixture.mint())
+            .1
+    );
+    assert_eq!(
+        launch_pool.bumps.treasurer_bump,
+        find_treasurer(&fixture.launch_pool(), &fixture.mint()).1
+    );
+    assert_eq!(
+        launch_pool.bumps.vault_bump,
+        find_vault(&fixture.launch_pool(), &fixture.creator.pubkey()).1
+    );
+    let treasurer: Treasurer = harness.account(&fixture.pool.treasurer).await;
+    assert_eq!(treasurer.version, Treasurer::VERSION);
+    assert_eq!(treasurer.amount, 1_000 * TOKEN);
+    let bob_pool: UserPool = harness
+        .account(&fixture.pool.user_pool(&bob.pubkey()))
+        .await;
+    assert_eq!(bob_pool.version, UserPool::VERSION);
+    assert_eq!(bob_pool.amount, 50 * TOKEN);
+
+    // migrating again changes nothing, costs the keeper nothing and counts no position twice
+    migrate(&mut harness, &fixture, &keeper, &users)
+        .await
+        .unwrap();
+    assert_eq!(
+        harness.lamports(&keeper.pubkey()).await,
+        SOL - (new_rent - old_rent)
+    );
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.open_positions, 2);
+
+    // the migrated pool runs to its close on the backfilled bumps and positions
+    let carol = harness.buyer().await;
+    harness.buy(&fixture, &carol, 30 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+    let creator_lamports = harness.lamports(&fixture.creator.pubkey()).await;
+    harness.withdraw_proceeds(&fixture).await.unwrap();
+    assert_eq!(
+        harness.lamports(&fixture.creator.pubkey()).await,
+        creator_lamports + SOL * 9 / 10
+    );
+    harness.settle(&fixture, &[alice, bob, &carol]).await;
+    let alice_tokens = launchpad_tests::associated_token(&alice.pubkey(), &fixture.mint());
+    assert_eq!(harness.token_balance(&alice_tokens).await, 10 * TOKEN);
+    assert_eq!(
+        harness.token_balance(&fixture.creator_tokens).await,
+        2_910 * TOKEN
+    );
+    harness.close(&fixture).await.unwrap();
+    assert!(!harness.exists(&fixture.launch_pool()).await);
+}
+
+#[tokio::test]
+async fn pending_first_layout_pool_starts_after_migrating() {
+    let mut harness = Harness::new().await;
+    let FirstPool { fixture, .. } = first_layout_pool(&mut harness, LaunchPoolState::Pending).await;
+    let keeper = harness.wallet(SOL).await;
+
+    migrate(&mut harness, &fixture, &keeper, &[]).await.unwrap();
+    harness.start(&fixture).await.unwrap();
+    assert_eq!(
+        harness.token_balance(&fixture.pool.treasury).await,
+        1_000 * TOKEN
+    );
+
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.open_positions, 1);
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, SOL / 10);
+}
+
+#[tokio::test]
+async fn migrate_checks_the_accounts() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let other = harness.create_pool(PoolParams::default()).await;
+    let keeper = harness.wallet(SOL).await;
+    let alice = harness.buyer().await;
+    let bob = harness.buyer().await;
+
+    let foreign_treasurer = replace_account(
+        instructions::migrate_launch_pool(&fixture.pool, keeper.pubkey(), &[]),
+        fixture.pool.treasurer,
+        other.pool.treasurer,
+    );
+    assert_error(
+        harness.process(&[foreign_treasurer], &[&keeper]).await,
+        MyError::InvalidTreasurer,
+    );
+
+    let swapped = replace_account(
+        instructions::migrate_launch_pool(&fixture.pool, keeper.pubkey(), &[]),
+        fixture.pool.launch_pool,
+        fixture.pool.treasurer,
+    );
+    assert_anchor_error(
+        harness.process(&[swapped], &[&keeper]).await,
+        ErrorCode::AccountDiscriminatorMismatch,
+    );
+
+    // a user pool must be the one of the user passed before it
+    let foreign_user_pool = replace_account(
+        instructions::migrate_launch_pool(&fixture.pool, keeper.pubkey(), &[alice.pubkey()]),
+        fixture.pool.user_pool(&alice.pubkey()),
+        fixture.pool.user_pool(&bob.pubkey()),
+    );
+    assert_error(
+        harness.process(&[foreign_user_pool], &[&keeper]).await,
+        MyError::InvalidUserPool,
+    );
+
+    let mut unpaired =
+        instructions::migrate_launch_pool(&fixture.pool, keeper.pubkey(), &[alice.pubkey()]);
+    unpaired.accounts.pop();
+    assert_anchor_error(
+        harness.process(&[unpaired], &[&keeper]).await,
+        ErrorCode::AccountNotEnoughKeys,
+    );
+
+    assert_anchor_error(
+        migrate(&mut harness, &fixture, &keeper, &[keeper.pubkey()]).await,
+        ErrorCode::AccountOwnedByWrongProgram,
+    );
+
+    migrate(&mut harness, &fixture, &keeper, &[]).await.unwrap();
+}
+
+#[tokio::test]
+async fn first_layout_pool_must_sit_at_its_first_address() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let keeper = harness.wallet(SOL).await;
+
+    // the first layout written at the address of the creator's first indexed pool
+    write_first_layout(
+        &mut harness,
+        &fixture.launch_pool(),
+        LaunchPool::DISCRIMINATOR,
+        &FirstLaunchPool {
+            unlock_date: fixture.unlock_date,
+            pool_size: 1_000 * TOKEN,
+            minimum_token_amount: TOKEN,
+            maximum_token_amount: 100 * TOKEN,
+            rate: 100,
+            pool_size_remaining: 1_000 * TOKEN,
+            token_mint: fixture.mint(),
+            token_mint_decimals: 9,
+            authority: fixture.creator.pubkey(),
+            vault_amount: 0,
+            is_vesting: false,
+            currency: CurrencyType::SOL,
+            pool_type: LaunchPoolType::FairLaunch,
+            status: LaunchPoolState::Pending,
+        },
+    )
+    .await;
+
+    assert_error(
+        migrate(&mut harness, &fixture, &keeper, &[]).await,
+        MyError::InvalidLaunchPool,
+    );
+}
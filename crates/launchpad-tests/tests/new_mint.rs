@@ -0,0 +1,344 @@
+use anchor_lang::{error::ErrorCode, solana_program::program_option::COption};
+use anchor_spl::{
+    metadata::mpl_token_metadata::{
+        accounts::Metadata, types::TokenStandard, ID as TOKEN_METADATA_ID,
+    },
+    token::Mint,
+};
+use encode_sol_team3::{
+    errors::MyError,
+    instructions::TokenMetadata,
+    state::{LaunchPool, LaunchPoolState, Treasurer},
+};
+use launchpad_client::{
+    find_mint_authority, find_token_metadata,
+    instructions::{self, CreateNativePoolArgs, MintPoolSupplyArgs},
+    team_lock_token_account, PoolAddresses, PoolEvent,
+};
+use launchpad_tests::{
+    assert_anchor_error, assert_error, associated_token, create_pool_instruction, replace_account,
+    Harness, PoolFixture, PoolParams, TxResult, DECIMALS, SOL, TOKEN,
+};
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+
+fn supply(team_amount: u64, revoke_authorities: bool) -> MintPoolSupplyArgs {
+    MintPoolSupplyArgs {
+        liquidity_amount: 0,
+        team_amount,
+        token_metadata: None,
+        revoke_authorities,
+    }
+}
+
+// a pool of a new creator whose mint is created with it, the creator holds no tokens
+async fn create_with_new_mint(harness: &mut Harness, supply: MintPoolSupplyArgs) -> PoolFixture {
+    let creator = harness.wallet(10 * SOL).await;
+    let mint = Keypair::new();
+    let unlock_date = harness.now().await + 3_600;
+    let pool = PoolAddresses::new(creator.pubkey(), 0, mint.pubkey());
+    let params = PoolParams::default();
+
+    let instructions = instructions::create_pool_with_new_mint(
+        &pool,
+        CreateNativePoolArgs {
+            unlock_date,
+            pool_size: params.pool_size,
+            minimum_token_amount: params.minimum_token_amount,
+            maximum_token_amount: params.maximum_token_amount,
+            rate: params.rate,
+            token_mint_decimals: DECIMALS,
+            proceeds_vesting: None,
+            unsold_policy: params.unsold_policy,
+        },
+        supply,
+    );
+    harness
+        .process(&instructions, &[&creator, &mint])
+        .await
+        .unwrap();
+
+    PoolFixture {
+        creator_tokens: associated_token(&creator.pubkey(), &mint.pubkey()),
+        creator,
+        pool,
+        unlock_date,
+    }
+}
+
+async fn start_minted(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    has_team_lock: bool,
+) -> TxResult {
+    let instruction = instructions::start_launch_pool(
+        &fixture.pool,
+        fixture.creator.pubkey(),
+        None,
+        has_team_lock,
+    );
+    harness.process(&[instruction], &[&fixture.creator]).await
+}
+
+#[tokio::test]
+async fn new_mint_has_a_fixed_supply_in_the_treasury() {
+    let mut harness = Harness::new().await;
+    let fixture = create_with_new_mint(&mut harness, supply(0, true)).await;
+
+    harness.assert_emitted(&fixture.mint(), |event| {
+        matches!(event, PoolEvent::CreateTokenMint(event)
+            if event.authority == fixture.creator.pubkey() && event.decimals == DECIMALS)
+    });
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::MintPoolSupply(event)
+            if event.token_mint == fixture.mint()
+                && event.amount == 1_000 * TOKEN
+                && event.authorities_revoked)
+    });
+
+    let mint: Mint = harness.account(&fixture.mint()).await;
+    assert_eq!(mint.supply, 1_000 * TOKEN);
+    assert_eq!(mint.decimals, DECIMALS);
+    assert_eq!(mint.mint_authority, COption::None);
+    assert_eq!(mint.freeze_authority, COption::None);
+    assert_eq!(
+        harness.token_balance(&fixture.pool.treasury).await,
+        1_000 * TOKEN
+    );
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.minted_supply, 1_000 * TOKEN);
+
+    // start takes nothing from the creator, who never held the token
+    start_minted(&mut harness, &fixture, false).await.unwrap();
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert!(launch_pool.status == LaunchPoolState::Active);
+    assert_eq!(launch_pool.pool_size_remaining, 1_000 * TOKEN);
+    let treasurer: Treasurer = harness.account(&fixture.pool.treasurer).await;
+    assert_eq!(treasurer.amount, 1_000 * TOKEN);
+
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+    harness.warp_to(fixture.unlock_date).await;
+    harness.claim(&fixture, &alice).await.unwrap();
+    assert_eq!(
+        harness
+            .token_balance(&associated_token(&alice.pubkey(), &fixture.mint()))
+            .await,
+        10 * TOKEN
+    );
+}
+
+#[tokio::test]
+async fn minted_team_allocation_moves_to_the_team_lock_on_start() {
+    let mut harness = Harness::new().await;
+    let fixture = create_with_new_mint(&mut harness, supply(200 * TOKEN, false)).await;
+
+    // the mint authority is kept, but only mint_pool_supply can sign with it
+    let mint: Mint = harness.account(&fixture.mint()).await;
+    let (mint_authority, _) = find_mint_authority(&fixture.creator.pubkey(), &fixture.mint());
+    assert_eq!(mint.supply, 1_200 * TOKEN);
+    assert_eq!(mint.mint_authority, COption::Some(mint_authority));
+    assert_eq!(mint.freeze_authority, COption::Some(mint_authority));
+
+    // the supply includes a team allocation that is not configured yet
+    assert_error(
+        start_minted(&mut harness, &fixture, false).await,
+        MyError::InvalidMintedSupply,
+    );
+
+    let configure_team_lock = instructions::configure_team_lock(
+        &fixture.pool,
+        fixture.creator.pubkey(),
+        200 * TOKEN,
+        fixture.unlock_date,
+        0,
+    );
+    harness
+        .process(&[configure_team_lock], &[&fixture.creator])
+        .await
+        .unwrap();
+    let team_lock_token_account = team_lock_token_account(&fixture.launch_pool(), &fixture.mint());
+
+    start_minted(&mut harness, &fixture, true).await.unwrap();
+    assert_eq!(
+        harness.token_balance(&team_lock_token_account).await,
+        200 * TOKEN
+    );
+    assert_eq!(
+        harness.token_balance(&fixture.pool.treasury).await,
+        1_000 * TOKEN
+    );
+    let treasurer: Treasurer = harness.account(&fixture.pool.treasurer).await;
+    assert_eq!(treasurer.amount, 1_000 * TOKEN);
+}
+
+#[tokio::test]
+async fn pool_supply_is_minted_once_by_its_creator() {
+    let mut harness = Harness::new().await;
+    let fixture = create_with_new_mint(&mut harness, supply(0, false)).await;
+
+    let again =
+        instructions::mint_pool_supply(&fixture.pool, fixture.creator.pubkey(), supply(0, false));
+    assert_error(
+        harness.process(&[again], &[&fixture.creator]).await,
+        MyError::InvalidMintedSupply,
+    );
+
+    let impostor = harness.wallet(SOL).await;
+    let by_impostor =
+        instructions::mint_pool_supply(&fixture.pool, impostor.pubkey(), supply(0, false));
+    assert_error(
+        harness.process(&[by_impostor], &[&impostor]).await,
+        MyError::InvalidAuthority,
+    );
+
+    // another creator's pool of a still empty mint cannot sign with its mint authority
+    let creator = harness.wallet(10 * SOL).await;
+    let mint = Keypair::new();
+    harness
+        .process(
+            &[instructions::create_token_mint(
+                creator.pubkey(),
+                mint.pubkey(),
+                DECIMALS,
+            )],
+            &[&creator, &mint],
+        )
+        .await
+        .unwrap();
+    let other = harness.wallet(10 * SOL).await;
+    let other_pool = PoolAddresses::new(other.pubkey(), 0, mint.pubkey());
+    let unlock_date = harness.now().await + 3_600;
+    harness
+        .process(
+            &[create_pool_instruction(
+                &other_pool,
+                unlock_date,
+                &PoolParams::default(),
+            )],
+            &[&other],
+        )
+        .await
+        .unwrap();
+    let foreign = replace_account(
+        instructions::mint_pool_supply(&other_pool, other.pubkey(), supply(0, false)),
+        find_mint_authority(&other.pubkey(), &mint.pubkey()).0,
+        find_mint_authority(&creator.pubkey(), &mint.pubkey()).0,
+    );
+    assert_anchor_error(
+        harness.process(&[foreign], &[&other]).await,
+        ErrorCode::ConstraintSeeds,
+    );
+}
+
+fn token_metadata() -> TokenMetadata {
+    TokenMetadata {
+        name: "Launch Token".to_string(),
+        symbol: "LT".to_string(),
+        uri: "https://example.com/launch.json".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn token_metadata_is_created_before_the_authorities_are_revoked() {
+    let mut harness = Harness::new().await;
+    let fixture = create_with_new_mint(
+        &mut harness,
+        MintPoolSupplyArgs {
+            token_metadata: Some(token_metadata()),
+            ..supply(0, true)
+        },
+    )
+    .await;
+
+    let (address, _) = find_token_metadata(&fixture.mint());
+    let account = harness
+        .context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.owner, TOKEN_METADATA_ID);
+    let metadata = Metadata::from_bytes(&account.data).unwrap();
+    assert_eq!(metadata.mint, fixture.mint());
+    assert_eq!(metadata.update_authority, fixture.creator.pubkey());
+    // the metadata program pads the strings to their maximum length
+    assert_eq!(metadata.name.trim_end_matches('\0'), "Launch Token");
+    assert_eq!(metadata.symbol.trim_end_matches('\0'), "LT");
+    assert_eq!(
+        metadata.uri.trim_end_matches('\0'),
+        "https://example.com/launch.json"
+    );
+    assert!(metadata.is_mutable);
+    assert_eq!(metadata.token_standard, Some(TokenStandard::Fungible));
+
+    let mint: Mint = harness.account(&fixture.mint()).await;
+    assert_eq!(mint.supply, 1_000 * TOKEN);
+    assert_eq!(mint.mint_authority, COption::None);
+    assert_eq!(mint.freeze_authority, COption::None);
+}
+
+#[tokio::test]
+async fn token_metadata_keeps_the_authorities_unless_revoked() {
+    let mut harness = Harness::new().await;
+    let fixture = create_with_new_mint(
+        &mut harness,
+        MintPoolSupplyArgs {
+            token_metadata: Some(token_metadata()),
+            ..supply(0, false)
+        },
+    )
+    .await;
+
+    assert!(
+        harness
+            .exists(&find_token_metadata(&fixture.mint()).0)
+            .await
+    );
+    let mint: Mint = harness.account(&fixture.mint()).await;
+    let (mint_authority, _) = find_mint_authority(&fixture.creator.pubkey(), &fixture.mint());
+    assert_eq!(mint.mint_authority, COption::Some(mint_authority));
+    assert_eq!(mint.freeze_authority, COption::Some(mint_authority));
+}
+
+#[tokio::test]
+async fn token_metadata_needs_its_accounts() {
+    let mut harness = Harness::new().await;
+    let creator = harness.wallet(10 * SOL).await;
+    let mint = Keypair::new();
+    let pool = PoolAddresses::new(creator.pubkey(), 0, mint.pubkey());
+    let unlock_date = harness.now().await + 3_600;
+    harness
+        .process(
+            &[
+                instructions::create_token_mint(creator.pubkey(), mint.pubkey(), DECIMALS),
+                create_pool_instruction(&pool, unlock_date, &PoolParams::default()),
+            ],
+            &[&creator, &mint],
+        )
+        .await
+        .unwrap();
+
+    // a supply that asks for metadata without passing the metadata account and program
+    let with_metadata = instructions::mint_pool_supply(
+        &pool,
+        creator.pubkey(),
+        MintPoolSupplyArgs {
+            token_metadata: Some(token_metadata()),
+            ..supply(0, false)
+        },
+    );
+    let instruction = Instruction {
+        data: with_metadata.data,
+        ..instructions::mint_pool_supply(&pool, creator.pubkey(), supply(0, false))
+    };
+    assert_error(
+        harness.process(&[instruction], &[&creator]).await,
+        MyError::TokenMetadataAccountsRequired,
+    );
+    assert!(!harness.exists(&find_token_metadata(&mint.pubkey()).0).await);
+}
@@ -0,0 +1,199 @@
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_spl::{
+    metadata::mpl_token_metadata::types::{Collection, Creator},
+    token::{self, spl_token},
+};
+use encode_sol_team3::{constants::MAX_NFT_GATE_CREATORS, errors::MyError, state::NftUsage};
+use launchpad_client::{
+    accounts, find_event_authority, find_nft_usage, find_token_metadata, instruction, instructions,
+    PoolEvent, PROGRAM_ID,
+};
+use launchpad_tests::{
+    assert_error, associated_token, Harness, PoolFixture, PoolParams, TxResult, TOKEN,
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+// a buy that presents the NFT held in nft_token_account and the metadata of metadata_mint
+async fn buy_with_nft(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    user: &Keypair,
+    nft_mint: Pubkey,
+    metadata_mint: Pubkey,
+) -> TxResult {
+    harness
+        .send(
+            accounts::BuyTokenWithNative {
+                launch_pool: fixture.launch_pool(),
+                token_mint: fixture.mint(),
+                user_pool: fixture.pool.user_pool(&user.pubkey()),
+                vault: fixture.pool.vault,
+                stake_account: None,
+                nft_token_account: Some(associated_token(&user.pubkey(), &nft_mint)),
+                nft_metadata: Some(find_token_metadata(&metadata_mint).0),
+                nft_usage: Some(find_nft_usage(&fixture.launch_pool(), &nft_mint).0),
+                instructions_sysvar: None,
+                referral_stats: None,
+                user: user.pubkey(),
+                system_program: system_program::ID,
+                token_program: token::ID,
+                rent: sysvar::rent::ID,
+                event_authority: find_event_authority().0,
+                program: PROGRAM_ID,
+            },
+            instruction::BuyTokenWithNative { amount: TOKEN },
+            &[user],
+        )
+        .await
+}
+
+fn collection(key: Pubkey, verified: bool) -> Option<Collection> {
+    Some(Collection { verified, key })
+}
+
+#[tokio::test]
+async fn nft_gate_needs_a_collection_or_a_few_creators() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+
+    let too_many = (0..=MAX_NFT_GATE_CREATORS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    for creators in [vec![], too_many] {
+        let instruction = instructions::configure_nft_gate(
+            &fixture.pool,
+            fixture.creator.pubkey(),
+            None,
+            creators,
+            false,
+        );
+        assert_error(
+            harness.process(&[instruction], &[&fixture.creator]).await,
+            MyError::InvalidNftGate,
+        );
+    }
+}
+
+#[tokio::test]
+async fn collection_gate_accepts_only_verified_members() {
+    let mut harness = Harness::new().await;
+    let gate_collection = Pubkey::new_unique();
+    let fixture = harness
+        .gated_pool(Some(gate_collection), vec![], false)
+        .await;
+
+    let user = harness.buyer().await;
+    let result = harness.buy(&fixture, &user, TOKEN).await;
+    assert_error(result, MyError::NftRequired);
+
+    let (unverified, _) = harness
+        .create_nft(&user.pubkey(), collection(gate_collection, false), None)
+        .await;
+    let result = buy_with_nft(&mut harness, &fixture, &user, unverified, unverified).await;
+    assert_error(result, MyError::NftNotInCollection);
+
+    let (other, _) = harness
+        .create_nft(&user.pubkey(), collection(Pubkey::new_unique(), true), None)
+        .await;
+    let result = buy_with_nft(&mut harness, &fixture, &user, other, other).await;
+    assert_error(result, MyError::NftNotInCollection);
+
+    let (member, _) = harness
+        .create_nft(&user.pubkey(), collection(gate_collection, true), None)
+        .await;
+    buy_with_nft(&mut harness, &fixture, &user, member, member)
+        .await
+        .unwrap();
+
+    // the metadata has to describe the NFT that is presented
+    let result = buy_with_nft(&mut harness, &fixture, &user, other, member).await;
+    assert_error(result, MyError::InvalidNftAccount);
+}
+
+#[tokio::test]
+async fn creator_gate_needs_a_verified_listed_creator() {
+    let mut harness = Harness::new().await;
+    let artist = Pubkey::new_unique();
+    let fixture = harness.gated_pool(None, vec![artist], false).await;
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ConfigureNftGate(event)
+            if event.collection.is_none() && event.creators == [artist] && !event.single_use)
+    });
+    let user = harness.buyer().await;
+    let creator = |verified| {
+        Some(vec![Creator {
+            address: artist,
+            verified,
+            share: 100,
+        }])
+    };
+
+    let (unverified, _) = harness
+        .create_nft(&user.pubkey(), None, creator(false))
+        .await;
+    let result = buy_with_nft(&mut harness, &fixture, &user, unverified, unverified).await;
+    assert_error(result, MyError::NftNotInCollection);
+
+    let (verified, _) = harness
+        .create_nft(&user.pubkey(), None, creator(true))
+        .await;
+    buy_with_nft(&mut harness, &fixture, &user, verified, verified)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn single_use_nft_buys_for_one_wallet_only() {
+    let mut harness = Harness::new().await;
+    let gate_collection = Pubkey::new_unique();
+    let fixture = harness
+        .gated_pool(Some(gate_collection), vec![], true)
+        .await;
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ConfigureNftGate(event)
+            if event.collection == Some(gate_collection) && event.single_use)
+    });
+    let holder = harness.buyer().await;
+    let (nft, holder_nft) = harness
+        .create_nft(&holder.pubkey(), collection(gate_collection, true), None)
+        .await;
+
+    buy_with_nft(&mut harness, &fixture, &holder, nft, nft)
+        .await
+        .unwrap();
+    // the same wallet keeps buying with it
+    buy_with_nft(&mut harness, &fixture, &holder, nft, nft)
+        .await
+        .unwrap();
+
+    let usage: NftUsage = harness
+        .account(&find_nft_usage(&fixture.launch_pool(), &nft).0)
+        .await;
+    assert_eq!(usage.user, holder.pubkey());
+    assert_eq!(usage.nft_mint, nft);
+
+    // passing the NFT on does not give a second wallet an allocation
+    let other = harness.buyer().await;
+    let other_nft = harness.mint_to(&nft, &other.pubkey(), 0).await;
+    harness
+        .process(
+            &[spl_token::instruction::transfer(
+                &spl_token::ID,
+                &holder_nft,
+                &other_nft,
+                &holder.pubkey(),
+                &[],
+                1,
+            )
+            .unwrap()],
+            &[&holder],
+        )
+        .await
+        .unwrap();
+
+    let result = buy_with_nft(&mut harness, &fixture, &other, nft, nft).await;
+    assert_error(result, MyError::NftAlreadyUsed);
+}
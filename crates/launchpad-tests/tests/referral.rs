@@ -0,0 +1,255 @@
+use anchor_lang::{
+    error::ErrorCode,
+    solana_program::{system_program, sysvar},
+};
+use anchor_spl::token;
+use encode_sol_team3::{
+    errors::MyError,
+    state::{LaunchPool, ReferralStats},
+};
+use launchpad_client::{
+    accounts, find_event_authority, find_referral_stats, find_referrer, instruction, instructions,
+    PoolEvent, PROGRAM_ID,
+};
+use launchpad_tests::{
+    assert_anchor_error, assert_error, Harness, PoolFixture, TxResult, REFERRAL_BPS, SOL, TOKEN,
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+// a registered referrer with its stats for the pool
+async fn referrer(harness: &mut Harness, fixture: &PoolFixture) -> Keypair {
+    let wallet = harness.wallet(SOL).await;
+    let instructions = [
+        instructions::register_referrer(wallet.pubkey()),
+        instructions::create_referral_stats(&fixture.pool, wallet.pubkey(), true),
+    ];
+    harness.process(&instructions, &[&wallet]).await.unwrap();
+    wallet
+}
+
+// referral stats of a wallet that is not a registered referrer, backed by its own buy
+async fn create_buyer_stats(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    wallet: &Keypair,
+) -> TxResult {
+    let instruction = instructions::create_referral_stats(&fixture.pool, wallet.pubkey(), false);
+    harness.process(&[instruction], &[wallet]).await
+}
+
+async fn buy_referred(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    user: &Keypair,
+    referrer: &Pubkey,
+    amount: u64,
+) -> TxResult {
+    harness
+        .send(
+            accounts::BuyTokenWithNative {
+                launch_pool: fixture.launch_pool(),
+                token_mint: fixture.mint(),
+                user_pool: fixture.pool.user_pool(&user.pubkey()),
+                vault: fixture.pool.vault,
+                stake_account: None,
+                nft_token_account: None,
+                nft_metadata: None,
+                nft_usage: None,
+                instructions_sysvar: None,
+                referral_stats: Some(find_referral_stats(&fixture.launch_pool(), referrer).0),
+                user: user.pubkey(),
+                system_program: system_program::ID,
+                token_program: token::ID,
+                rent: sysvar::rent::ID,
+                event_authority: find_event_authority().0,
+                program: PROGRAM_ID,
+            },
+            instruction::BuyTokenWithNative { amount },
+            &[user],
+        )
+        .await
+}
+
+async fn claim_reward(harness: &mut Harness, fixture: &PoolFixture, wallet: &Keypair) -> TxResult {
+    let instruction = instructions::claim_referral_reward(&fixture.pool, wallet.pubkey());
+    harness.process(&[instruction], &[wallet]).await
+}
+
+#[tokio::test]
+async fn referred_buys_accrue_a_reward_the_referrer_claims() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.referral_pool().await;
+    let referrer = referrer(&mut harness, &fixture).await;
+    let stats = find_referral_stats(&fixture.launch_pool(), &referrer.pubkey()).0;
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ConfigureReferral(event)
+            if event.authority == fixture.creator.pubkey() && event.referral_bps == REFERRAL_BPS)
+    });
+    let registration = find_referrer(&referrer.pubkey()).0;
+    harness.assert_emitted(&registration, |event| {
+        matches!(event, PoolEvent::RegisterReferrer(event) if event.wallet == referrer.pubkey())
+    });
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::CreateReferralStats(event)
+            if event.referrer == referrer.pubkey() && event.is_registered)
+    });
+
+    let alice = harness.buyer().await;
+    let bob = harness.buyer().await;
+    buy_referred(
+        &mut harness,
+        &fixture,
+        &alice,
+        &referrer.pubkey(),
+        10 * TOKEN,
+    )
+    .await
+    .unwrap();
+    buy_referred(&mut harness, &fixture, &bob, &referrer.pubkey(), 30 * TOKEN)
+        .await
+        .unwrap();
+
+    // 40 tokens cost 0.4 SOL, 5% of which goes to the referrer
+    let reward = SOL / 50;
+    let referral_stats: ReferralStats = harness.account(&stats).await;
+    assert_eq!(referral_stats.referred_count, 2);
+    assert_eq!(referral_stats.referred_amount, 40 * TOKEN);
+    assert_eq!(referral_stats.referred_currency_amount, 2 * SOL / 5);
+    assert_eq!(referral_stats.reward, reward);
+    let launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    assert_eq!(launch_pool.referral_reward_total, reward);
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ReferredBuy(event)
+            if event.buyer == alice.pubkey()
+                && event.referrer == referrer.pubkey()
+                && event.amount == 10 * TOKEN
+                && event.reward == reward / 4)
+    });
+
+    let result = claim_reward(&mut harness, &fixture, &referrer).await;
+    assert_error(result, MyError::InvalidLaunchPoolStatus);
+
+    harness.complete(&fixture).await.unwrap();
+    let before = harness.lamports(&referrer.pubkey()).await;
+    claim_reward(&mut harness, &fixture, &referrer)
+        .await
+        .unwrap();
+    assert_eq!(harness.lamports(&referrer.pubkey()).await, before + reward);
+
+    let referral_stats: ReferralStats = harness.account(&stats).await;
+    assert_eq!(referral_stats.claimed, reward);
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ReferralRewardClaimed(event)
+            if event.referrer == referrer.pubkey() && event.amount == reward)
+    });
+    let result = claim_reward(&mut harness, &fixture, &referrer).await;
+    assert_error(result, MyError::InvalidAmount);
+}
+
+#[tokio::test]
+async fn referrers_cannot_refer_themselves() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.referral_pool().await;
+    let referrer = referrer(&mut harness, &fixture).await;
+
+    let result = buy_referred(
+        &mut harness,
+        &fixture,
+        &referrer,
+        &referrer.pubkey(),
+        10 * TOKEN,
+    )
+    .await;
+    assert_error(result, MyError::InvalidReferrer);
+}
+
+#[tokio::test]
+async fn referral_stats_only_count_in_their_own_pool() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.referral_pool().await;
+    let other = harness.referral_pool().await;
+    let referrer = referrer(&mut harness, &other).await;
+    let alice = harness.buyer().await;
+
+    let result = harness
+        .send(
+            accounts::BuyTokenWithNative {
+                launch_pool: fixture.launch_pool(),
+                token_mint: fixture.mint(),
+                user_pool: fixture.pool.user_pool(&alice.pubkey()),
+                vault: fixture.pool.vault,
+                stake_account: None,
+                nft_token_account: None,
+                nft_metadata: None,
+                nft_usage: None,
+                instructions_sysvar: None,
+                referral_stats: Some(
+                    find_referral_stats(&other.launch_pool(), &referrer.pubkey()).0,
+                ),
+                user: alice.pubkey(),
+                system_program: system_program::ID,
+                token_program: token::ID,
+                rent: sysvar::rent::ID,
+                event_authority: find_event_authority().0,
+                program: PROGRAM_ID,
+            },
+            instruction::BuyTokenWithNative { amount: TOKEN },
+            &[&alice],
+        )
+        .await;
+    assert_error(result, MyError::InvalidReferrer);
+}
+
+#[tokio::test]
+async fn unregistered_wallets_need_a_buy_to_refer() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.referral_pool().await;
+    let alice = harness.buyer().await;
+
+    let result = create_buyer_stats(&mut harness, &fixture, &alice).await;
+    assert_anchor_error(result, ErrorCode::AccountNotInitialized);
+
+    harness.buy(&fixture, &alice, TOKEN).await.unwrap();
+    create_buyer_stats(&mut harness, &fixture, &alice)
+        .await
+        .unwrap();
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::CreateReferralStats(event)
+            if event.referrer == alice.pubkey() && !event.is_registered)
+    });
+
+    let referral_stats: ReferralStats = harness
+        .account(&find_referral_stats(&fixture.launch_pool(), &alice.pubkey()).0)
+        .await;
+    assert_eq!(referral_stats.referrer, alice.pubkey());
+}
+
+#[tokio::test]
+async fn unclaimed_rewards_keep_the_pool_open() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.referral_pool().await;
+    let referrer = referrer(&mut harness, &fixture).await;
+    let alice = harness.buyer().await;
+    buy_referred(
+        &mut harness,
+        &fixture,
+        &alice,
+        &referrer.pubkey(),
+        10 * TOKEN,
+    )
+    .await
+    .unwrap();
+    harness.complete(&fixture).await.unwrap();
+    harness.settle(&fixture, &[&alice]).await;
+
+    let result = harness.close(&fixture).await;
+    assert_error(result, MyError::LaunchPoolNotSettled);
+
+    claim_reward(&mut harness, &fixture, &referrer)
+        .await
+        .unwrap();
+    harness.close(&fixture).await.unwrap();
+}
@@ -0,0 +1,539 @@
+use anchor_lang::{error::ErrorCode, prelude::Pubkey};
+use encode_sol_team3::{
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolType, SaleRound, SaleRoundAccess, UserPool, Whitelist},
+};
+use launchpad_client::{
+    find_user_pool, find_whitelist,
+    instructions::{self, SaleRoundArgs},
+    PoolEvent,
+};
+use launchpad_tests::{
+    assert_anchor_error, assert_error, replace_account, Harness, PoolFixture, PoolParams, TxResult,
+    SOL, TOKEN,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+fn public_round(now: i64) -> SaleRoundArgs {
+    SaleRoundArgs {
+        rate: 100,
+        minimum_token_amount: TOKEN,
+        maximum_token_amount: 50 * TOKEN,
+        start_time: now,
+        end_time: now + 100,
+        allocation: 100 * TOKEN,
+        access: SaleRoundAccess::Public,
+    }
+}
+
+async fn create_round(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    index: u8,
+    round: SaleRoundArgs,
+) -> TxResult {
+    let instruction =
+        instructions::create_sale_round(&fixture.pool, fixture.creator.pubkey(), index, round);
+    harness.process(&[instruction], &[&fixture.creator]).await
+}
+
+async fn add_wallets(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    index: u8,
+    wallets: Vec<Pubkey>,
+) -> TxResult {
+    let instruction = instructions::add_wallets_to_whitelist(
+        &fixture.pool,
+        fixture.creator.pubkey(),
+        index,
+        wallets,
+    );
+    harness.process(&[instruction], &[&fixture.creator]).await
+}
+
+async fn remove_wallet(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    index: u8,
+    wallet: Pubkey,
+) -> TxResult {
+    let instruction = instructions::remove_wallet_from_whitelist(
+        &fixture.pool,
+        fixture.creator.pubkey(),
+        index,
+        wallet,
+    );
+    harness.process(&[instruction], &[&fixture.creator]).await
+}
+
+// passes the round's whitelist if it has one
+async fn buy(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    round: u8,
+    user: &Keypair,
+    amount: u64,
+) -> TxResult {
+    let sale_round = fixture.pool.sale_round(round);
+    let with_whitelist = harness.exists(&find_whitelist(&sale_round).0).await;
+    let instruction = instructions::buy_token_in_round(
+        &fixture.pool,
+        round,
+        user.pubkey(),
+        amount,
+        with_whitelist,
+    );
+    harness.process(&[instruction], &[user]).await
+}
+
+async fn advance(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    round: u8,
+    signer: &Keypair,
+) -> TxResult {
+    let instruction = instructions::advance_sale_round(&fixture.pool, signer.pubkey(), round);
+    harness.process(&[instruction], &[signer]).await
+}
+
+#[tokio::test]
+async fn create_sale_round_validates_the_round() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let now = harness.now().await;
+
+    assert_error(
+        create_round(&mut harness, &fixture, 1, public_round(now)).await,
+        MyError::InvalidSaleRound,
+    );
+    let ended = SaleRoundArgs {
+        start_time: now - 100,
+        end_time: now - 1,
+        ..public_round(now)
+    };
+    assert_error(
+        create_round(&mut harness, &fixture, 0, ended).await,
+        MyError::InvalidSaleRoundTime,
+    );
+    let inverted = SaleRoundArgs {
+        start_time: now + 200,
+        end_time: now + 100,
+        ..public_round(now)
+    };
+    assert_error(
+        create_round(&mut harness, &fixture, 0, inverted).await,
+        MyError::InvalidSaleRoundTime,
+    );
+    let free = SaleRoundArgs {
+        rate: 0,
+        ..public_round(now)
+    };
+    assert_error(
+        create_round(&mut harness, &fixture, 0, free).await,
+        MyError::InvalidAmount,
+    );
+    let inverted_limits = SaleRoundArgs {
+        minimum_token_amount: 60 * TOKEN,
+        ..public_round(now)
+    };
+    assert_error(
+        create_round(&mut harness, &fixture, 0, inverted_limits).await,
+        MyError::InvalidAmount,
+    );
+    // the access rule is an enum, a byte out of its range does not deserialize
+    let mut out_of_range = instructions::create_sale_round(
+        &fixture.pool,
+        fixture.creator.pubkey(),
+        0,
+        public_round(now),
+    );
+    *out_of_range.data.last_mut().unwrap() = 2;
+    assert_anchor_error(
+        harness.process(&[out_of_range], &[&fixture.creator]).await,
+        ErrorCode::InstructionDidNotDeserialize,
+    );
+    let oversized = SaleRoundArgs {
+        allocation: 1_001 * TOKEN,
+        ..public_round(now)
+    };
+    assert_error(
+        create_round(&mut harness, &fixture, 0, oversized).await,
+        MyError::SaleRoundAllocationExceeded,
+    );
+
+    for index in 0..3 {
+        let round = SaleRoundArgs {
+            allocation: 300 * TOKEN,
+            ..public_round(now)
+        };
+        create_round(&mut harness, &fixture, index, round)
+            .await
+            .unwrap();
+    }
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::CreateSaleRound(event)
+            if event.authority == fixture.creator.pubkey()
+                && event.index == 2
+                && event.allocation == 300 * TOKEN
+                && event.access == SaleRoundAccess::Public)
+    });
+    assert_error(
+        create_round(&mut harness, &fixture, 3, public_round(now)).await,
+        MyError::TooManySaleRounds,
+    );
+    assert_error(
+        advance(&mut harness, &fixture, 0, &fixture.creator).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+
+    harness.start(&fixture).await.unwrap();
+    let fixture = harness.active_pool(PoolParams::default()).await;
+    assert_error(
+        create_round(&mut harness, &fixture, 0, public_round(now)).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+}
+
+#[tokio::test]
+async fn whitelist_is_managed_by_the_authority() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let now = harness.now().await;
+    let whitelisted = SaleRoundArgs {
+        access: SaleRoundAccess::Whitelist,
+        ..public_round(now)
+    };
+    create_round(&mut harness, &fixture, 0, whitelisted)
+        .await
+        .unwrap();
+    create_round(&mut harness, &fixture, 1, public_round(now))
+        .await
+        .unwrap();
+
+    let alice = Pubkey::new_unique();
+    assert_error(
+        add_wallets(&mut harness, &fixture, 1, vec![alice]).await,
+        MyError::InvalidWhitelist,
+    );
+    assert_error(
+        add_wallets(&mut harness, &fixture, 0, vec![]).await,
+        MyError::WalletsMustNotBeEmpty,
+    );
+    assert_error(
+        add_wallets(&mut harness, &fixture, 0, vec![alice, alice]).await,
+        MyError::WalletAlreadyAdded,
+    );
+
+    let impostor = harness.wallet(SOL).await;
+    let by_impostor = harness
+        .process(
+            &[instructions::add_wallets_to_whitelist(
+                &fixture.pool,
+                impostor.pubkey(),
+                0,
+                vec![impostor.pubkey()],
+            )],
+            &[&impostor],
+        )
+        .await;
+    assert_error(by_impostor, MyError::InvalidAuthority);
+
+    add_wallets(&mut harness, &fixture, 0, vec![alice])
+        .await
+        .unwrap();
+    assert_error(
+        add_wallets(&mut harness, &fixture, 0, vec![alice]).await,
+        MyError::WalletAlreadyAdded,
+    );
+    remove_wallet(&mut harness, &fixture, 0, alice)
+        .await
+        .unwrap();
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::AddWalletsToWhitelist(event)
+            if event.index == 0 && event.wallets == [alice] && event.wallet_count == 1)
+    });
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::RemoveWalletFromWhitelist(event)
+            if event.index == 0 && event.wallet == alice && event.wallet_count == 0)
+    });
+    assert_error(
+        remove_wallet(&mut harness, &fixture, 0, alice).await,
+        MyError::WalletNotInList,
+    );
+
+    // the whitelist holds MAX_WHITELIST_WALLETS, added a transaction's worth at a time
+    for _ in 0..9 {
+        let wallets = (0..20).map(|_| Pubkey::new_unique()).collect();
+        add_wallets(&mut harness, &fixture, 0, wallets)
+            .await
+            .unwrap();
+    }
+    let ten = (0..10).map(|_| Pubkey::new_unique()).collect();
+    add_wallets(&mut harness, &fixture, 0, ten).await.unwrap();
+    let eleven = (0..11).map(|_| Pubkey::new_unique()).collect();
+    assert_error(
+        add_wallets(&mut harness, &fixture, 0, eleven).await,
+        MyError::WhitelistNotEnoughSpace,
+    );
+    let ten = (0..10).map(|_| Pubkey::new_unique()).collect();
+    add_wallets(&mut harness, &fixture, 0, ten).await.unwrap();
+    assert_error(
+        add_wallets(&mut harness, &fixture, 0, vec![alice]).await,
+        MyError::WhitelistFulled,
+    );
+
+    let whitelist: Whitelist = harness
+        .account(&find_whitelist(&fixture.pool.sale_round(0)).0)
+        .await;
+    assert_eq!(whitelist.wallets.len(), 200);
+}
+
+#[tokio::test]
+async fn rounds_sell_in_order_with_their_own_limits() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let now = harness.now().await;
+    let alice = harness.buyer().await;
+    let bob = harness.buyer().await;
+
+    let whitelisted = SaleRoundArgs {
+        access: SaleRoundAccess::Whitelist,
+        ..public_round(now)
+    };
+    let public = SaleRoundArgs {
+        rate: 50,
+        maximum_token_amount: 100 * TOKEN,
+        start_time: now + 200,
+        end_time: now + 1_000,
+        allocation: 200 * TOKEN,
+        ..public_round(now)
+    };
+    create_round(&mut harness, &fixture, 0, whitelisted)
+        .await
+        .unwrap();
+    create_round(&mut harness, &fixture, 1, public)
+        .await
+        .unwrap();
+    add_wallets(&mut harness, &fixture, 0, vec![alice.pubkey()])
+        .await
+        .unwrap();
+
+    assert_error(
+        buy(&mut harness, &fixture, 0, &alice, 10 * TOKEN).await,
+        MyError::InvalidLaunchPoolStatus,
+    );
+    harness.start(&fixture).await.unwrap();
+
+    // pools with rounds only sell through them
+    assert_error(
+        harness.buy(&fixture, &alice, 10 * TOKEN).await,
+        MyError::LaunchPoolHasSaleRounds,
+    );
+
+    assert_error(
+        buy(&mut harness, &fixture, 0, &alice, 0).await,
+        MyError::InvalidAmount,
+    );
+    assert_error(
+        buy(&mut harness, &fixture, 0, &bob, 10 * TOKEN).await,
+        MyError::UserNotInWhiteList,
+    );
+    let without_whitelist =
+        instructions::buy_token_in_round(&fixture.pool, 0, alice.pubkey(), 10 * TOKEN, false);
+    assert_error(
+        harness.process(&[without_whitelist], &[&alice]).await,
+        MyError::InvalidWhitelist,
+    );
+    assert_error(
+        buy(&mut harness, &fixture, 0, &alice, 101 * TOKEN).await,
+        MyError::SaleRoundAllocationNotEnough,
+    );
+    assert_error(
+        buy(&mut harness, &fixture, 0, &alice, TOKEN / 2).await,
+        MyError::MinimumTokenAmountNotReached,
+    );
+    assert_error(
+        buy(&mut harness, &fixture, 0, &alice, 51 * TOKEN).await,
+        MyError::MaximumTokenAmountReached,
+    );
+
+    let other_mint = harness.create_mint().await;
+    let wrong_mint =
+        instructions::buy_token_in_round(&fixture.pool, 0, alice.pubkey(), 10 * TOKEN, true);
+    let wrong_mint = replace_account(wrong_mint, fixture.mint(), other_mint);
+    let wrong_mint = replace_account(
+        wrong_mint,
+        fixture.pool.user_pool(&alice.pubkey()),
+        find_user_pool(&alice.pubkey(), &fixture.launch_pool(), &other_mint).0,
+    );
+    assert_error(
+        harness.process(&[wrong_mint], &[&alice]).await,
+        MyError::InvalidTokenMint,
+    );
+
+    buy(&mut harness, &fixture, 0, &alice, 40 * TOKEN)
+        .await
+        .unwrap();
+    assert_eq!(harness.lamports(&fixture.pool.vault).await, SOL * 4 / 10);
+
+    // only the authority can close a round before it ends
+    assert_error(
+        advance(&mut harness, &fixture, 0, &bob).await,
+        MyError::InvalidAuthority,
+    );
+    harness.warp_to(now + 100).await;
+    assert_error(
+        buy(&mut harness, &fixture, 0, &alice, 10 * TOKEN).await,
+        MyError::SaleRoundNotOpen,
+    );
+    advance(&mut harness, &fixture, 0, &bob).await.unwrap();
+    assert_error(
+        add_wallets(&mut harness, &fixture, 0, vec![bob.pubkey()]).await,
+        MyError::SaleRoundNotOpen,
+    );
+
+    let round: SaleRound = harness.account(&fixture.pool.sale_round(1)).await;
+    assert_eq!(round.allocation, 260 * TOKEN);
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::BuyInRound(event)
+            if event.buyer == alice.pubkey() && event.round == 0 && event.amount == 40 * TOKEN)
+    });
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::AdvanceSaleRound(event)
+            if event.signer == bob.pubkey()
+                && event.closed_round == 0
+                && event.current_round == 1
+                && event.rolled_over == 60 * TOKEN)
+    });
+
+    assert_error(
+        buy(&mut harness, &fixture, 1, &bob, 100 * TOKEN).await,
+        MyError::SaleRoundNotOpen,
+    );
+    harness.warp_to(now + 200).await;
+    buy(&mut harness, &fixture, 1, &bob, 100 * TOKEN)
+        .await
+        .unwrap();
+    buy(&mut harness, &fixture, 1, &alice, 10 * TOKEN)
+        .await
+        .unwrap();
+
+    // the rate of the second round is 50 tokens per SOL
+    let bob_pool: UserPool = harness
+        .account(&fixture.pool.user_pool(&bob.pubkey()))
+        .await;
+    assert_eq!(bob_pool.currency_amount, 2 * SOL);
+    let alice_pool: UserPool = harness
+        .account(&fixture.pool.user_pool(&alice.pubkey()))
+        .await;
+    assert_eq!(alice_pool.round_amounts, [40 * TOKEN, 10 * TOKEN, 0]);
+    assert_eq!(alice_pool.amount, 50 * TOKEN);
+}
+
+#[tokio::test]
+async fn rounds_gate_purchases_by_window_and_access() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let now = harness.now().await;
+    let alice = harness.buyer().await;
+    let bob = harness.buyer().await;
+
+    let public = SaleRoundArgs {
+        start_time: now + 50,
+        ..public_round(now)
+    };
+    let whitelisted = SaleRoundArgs {
+        start_time: now + 100,
+        end_time: now + 300,
+        access: SaleRoundAccess::Whitelist,
+        ..public_round(now)
+    };
+    create_round(&mut harness, &fixture, 0, public)
+        .await
+        .unwrap();
+    create_round(&mut harness, &fixture, 1, whitelisted)
+        .await
+        .unwrap();
+    add_wallets(
+        &mut harness,
+        &fixture,
+        1,
+        vec![alice.pubkey(), bob.pubkey()],
+    )
+    .await
+    .unwrap();
+    harness.start(&fixture).await.unwrap();
+
+    assert_error(
+        buy(&mut harness, &fixture, 0, &alice, 10 * TOKEN).await,
+        MyError::SaleRoundNotOpen,
+    );
+    // only the pool's current round sells
+    let ahead =
+        instructions::buy_token_in_round(&fixture.pool, 1, alice.pubkey(), 10 * TOKEN, true);
+    assert_anchor_error(
+        harness.process(&[ahead], &[&alice]).await,
+        ErrorCode::ConstraintSeeds,
+    );
+
+    // a public round sells to any wallet, without a whitelist
+    harness.warp_to(now + 50).await;
+    buy(&mut harness, &fixture, 0, &bob, 10 * TOKEN)
+        .await
+        .unwrap();
+    harness.warp_to(now + 100).await;
+    assert_error(
+        buy(&mut harness, &fixture, 0, &bob, 10 * TOKEN).await,
+        MyError::SaleRoundNotOpen,
+    );
+    advance(&mut harness, &fixture, 0, &bob).await.unwrap();
+
+    // a wallet taken off the whitelist can no longer buy in its round
+    remove_wallet(&mut harness, &fixture, 1, bob.pubkey())
+        .await
+        .unwrap();
+    assert_error(
+        buy(&mut harness, &fixture, 1, &bob, 10 * TOKEN).await,
+        MyError::UserNotInWhiteList,
+    );
+    buy(&mut harness, &fixture, 1, &alice, 10 * TOKEN)
+        .await
+        .unwrap();
+    harness.warp_to(now + 300).await;
+    assert_error(
+        buy(&mut harness, &fixture, 1, &alice, 10 * TOKEN).await,
+        MyError::SaleRoundNotOpen,
+    );
+
+    let alice_pool: UserPool = harness
+        .account(&fixture.pool.user_pool(&alice.pubkey()))
+        .await;
+    assert_eq!(alice_pool.round_amounts, [0, 10 * TOKEN, 0]);
+    let bob_pool: UserPool = harness
+        .account(&fixture.pool.user_pool(&bob.pubkey()))
+        .await;
+    assert_eq!(bob_pool.round_amounts, [10 * TOKEN, 0, 0]);
+}
+
+#[tokio::test]
+async fn rounds_only_sell_in_fair_launch_pools() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    let now = harness.now().await;
+    let alice = harness.buyer().await;
+    create_round(&mut harness, &fixture, 0, public_round(now))
+        .await
+        .unwrap();
+    harness.start(&fixture).await.unwrap();
+
+    let mut launch_pool: LaunchPool = harness.account(&fixture.launch_pool()).await;
+    launch_pool.pool_type = LaunchPoolType::WhiteList;
+    harness
+        .write_account(&fixture.launch_pool(), &launch_pool)
+        .await;
+    assert_error(
+        buy(&mut harness, &fixture, 0, &alice, 10 * TOKEN).await,
+        MyError::InvalidLaunchPoolType,
+    );
+}
@@ -0,0 +1,366 @@
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_spl::token;
+use encode_sol_team3::{
+    errors::MyError,
+    state::{StakeAccount, StakeTier, StakingConfig, TierThreshold},
+};
+use launchpad_client::{
+    accounts, find_event_authority, find_stake_account, find_staking_config, instruction,
+    instructions, PoolEvent, PROGRAM_ID,
+};
+use launchpad_tests::{
+    assert_error, associated_token, replace_account, Harness, PoolFixture, PoolParams, TxResult,
+    SOL, TOKEN,
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const LOCK: i64 = 3_600;
+
+// bronze, silver and gold all need the stake locked for LOCK
+fn tier_thresholds() -> [TierThreshold; 3] {
+    [10, 100, 1_000].map(|amount| TierThreshold {
+        minimum_amount: amount * TOKEN,
+        minimum_lock_duration: LOCK,
+    })
+}
+
+async fn initialize(harness: &mut Harness, authority: &Keypair, stake_mint: Pubkey) -> TxResult {
+    let instruction =
+        instructions::initialize_staking(authority.pubkey(), stake_mint, tier_thresholds());
+    harness.process(&[instruction], &[authority]).await
+}
+
+// staking initialized by the upgrade authority, and its stake mint
+async fn staking(harness: &mut Harness) -> Pubkey {
+    let authority = harness.wallet(SOL).await;
+    let stake_mint = harness.create_mint().await;
+
+    harness.set_upgrade_authority(&authority.pubkey()).await;
+    initialize(harness, &authority, stake_mint).await.unwrap();
+    stake_mint
+}
+
+async fn stake(
+    harness: &mut Harness,
+    stake_mint: Pubkey,
+    user: &Keypair,
+    amount: u64,
+    lock_duration: i64,
+) -> TxResult {
+    let instruction = instructions::stake(user.pubkey(), stake_mint, amount, lock_duration);
+    harness.process(&[instruction], &[user]).await
+}
+
+async fn unstake(
+    harness: &mut Harness,
+    stake_mint: Pubkey,
+    user: &Keypair,
+    amount: u64,
+) -> TxResult {
+    let instruction = instructions::unstake(user.pubkey(), stake_mint, amount);
+    harness.process(&[instruction], &[user]).await
+}
+
+// a funded buyer holding amount of the stake mint
+async fn staker(harness: &mut Harness, stake_mint: Pubkey, amount: u64) -> Keypair {
+    let user = harness.buyer().await;
+    harness.mint_to(&stake_mint, &user.pubkey(), amount).await;
+    user
+}
+
+// a buy that presents the buyer's stake account for the tier cap
+async fn buy_staked(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    user: &Keypair,
+    amount: u64,
+) -> TxResult {
+    harness
+        .send(
+            accounts::BuyTokenWithNative {
+                launch_pool: fixture.launch_pool(),
+                token_mint: fixture.mint(),
+                user_pool: fixture.pool.user_pool(&user.pubkey()),
+                vault: fixture.pool.vault,
+                stake_account: Some(find_stake_account(&user.pubkey()).0),
+                nft_token_account: None,
+                nft_metadata: None,
+                nft_usage: None,
+                instructions_sysvar: None,
+                referral_stats: None,
+                user: user.pubkey(),
+                system_program: system_program::ID,
+                token_program: token::ID,
+                rent: sysvar::rent::ID,
+                event_authority: find_event_authority().0,
+                program: PROGRAM_ID,
+            },
+            instruction::BuyTokenWithNative { amount },
+            &[user],
+        )
+        .await
+}
+
+// bronze stakers may buy 10 tokens, silver 20 and gold 50
+async fn tiered_pool(harness: &mut Harness) -> PoolFixture {
+    harness
+        .configured_pool(PoolParams::default(), |pool, authority| {
+            instructions::configure_tier_allocations(
+                pool,
+                authority,
+                [10 * TOKEN, 20 * TOKEN, 50 * TOKEN],
+            )
+        })
+        .await
+}
+
+#[tokio::test]
+async fn only_the_upgrade_authority_initializes_staking() {
+    let mut harness = Harness::new().await;
+    let authority = harness.wallet(SOL).await;
+    let stranger = harness.wallet(SOL).await;
+    let stake_mint = harness.create_mint().await;
+    harness.set_upgrade_authority(&authority.pubkey()).await;
+
+    let result = initialize(&mut harness, &stranger, stake_mint).await;
+    assert_error(result, MyError::InvalidAuthority);
+
+    initialize(&mut harness, &authority, stake_mint)
+        .await
+        .unwrap();
+
+    let staking_config: StakingConfig = harness.account(&find_staking_config().0).await;
+    assert_eq!(staking_config.authority, authority.pubkey());
+    assert_eq!(staking_config.stake_mint, stake_mint);
+    harness.assert_emitted(&find_staking_config().0, |event| {
+        matches!(event, PoolEvent::InitializeStaking(event)
+            if event.authority == authority.pubkey() && event.stake_mint == stake_mint)
+    });
+}
+
+async fn update_tiers(
+    harness: &mut Harness,
+    authority: &Keypair,
+    tier_thresholds: [TierThreshold; 3],
+) -> TxResult {
+    let instruction = instructions::update_staking_tiers(authority.pubkey(), tier_thresholds);
+    harness.process(&[instruction], &[authority]).await
+}
+
+#[tokio::test]
+async fn only_the_staking_authority_updates_the_tiers() {
+    let mut harness = Harness::new().await;
+    let authority = harness.wallet(SOL).await;
+    let stake_mint = harness.create_mint().await;
+    harness.set_upgrade_authority(&authority.pubkey()).await;
+    initialize(&mut harness, &authority, stake_mint)
+        .await
+        .unwrap();
+
+    let mut thresholds = tier_thresholds();
+    thresholds[2].minimum_amount = 2_000 * TOKEN;
+    let stranger = harness.wallet(SOL).await;
+    assert_error(
+        update_tiers(&mut harness, &stranger, thresholds).await,
+        MyError::InvalidAuthority,
+    );
+
+    update_tiers(&mut harness, &authority, thresholds)
+        .await
+        .unwrap();
+    let staking_config: StakingConfig = harness.account(&find_staking_config().0).await;
+    assert_eq!(
+        staking_config.tier_thresholds[2].minimum_amount,
+        2_000 * TOKEN
+    );
+    harness.assert_emitted(&find_staking_config().0, |event| {
+        matches!(event, PoolEvent::UpdateStakingTiers(event)
+            if event.authority == authority.pubkey()
+                && event.tier_thresholds[2].minimum_amount == 2_000 * TOKEN)
+    });
+}
+
+#[tokio::test]
+async fn stake_tiers_must_rise_from_a_nonzero_bronze() {
+    let mut harness = Harness::new().await;
+    let authority = harness.wallet(SOL).await;
+    let stake_mint = harness.create_mint().await;
+    harness.set_upgrade_authority(&authority.pubkey()).await;
+    initialize(&mut harness, &authority, stake_mint)
+        .await
+        .unwrap();
+
+    let mut cheaper_gold = tier_thresholds();
+    cheaper_gold[2].minimum_amount = 50 * TOKEN;
+    assert_error(
+        update_tiers(&mut harness, &authority, cheaper_gold).await,
+        MyError::InvalidStakeTiers,
+    );
+    let mut shorter_gold = tier_thresholds();
+    shorter_gold[2].minimum_lock_duration = LOCK - 1;
+    assert_error(
+        update_tiers(&mut harness, &authority, shorter_gold).await,
+        MyError::InvalidStakeTiers,
+    );
+    let mut free_bronze = tier_thresholds();
+    free_bronze[0].minimum_amount = 0;
+    assert_error(
+        update_tiers(&mut harness, &authority, free_bronze).await,
+        MyError::InvalidStakeTiers,
+    );
+
+    // a pool's tier caps rise the same way, from at least its minimum buy
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    for tier_caps in [
+        [10 * TOKEN, 50 * TOKEN, 20 * TOKEN],
+        [TOKEN / 2, 20 * TOKEN, 50 * TOKEN],
+    ] {
+        let instruction = instructions::configure_tier_allocations(
+            &fixture.pool,
+            fixture.creator.pubkey(),
+            tier_caps,
+        );
+        assert_error(
+            harness.process(&[instruction], &[&fixture.creator]).await,
+            MyError::InvalidStakeTiers,
+        );
+    }
+}
+
+#[tokio::test]
+async fn stakes_move_only_the_stake_mint_of_their_owner() {
+    let mut harness = Harness::new().await;
+    let stake_mint = staking(&mut harness).await;
+    let user = staker(&mut harness, stake_mint, 100 * TOKEN).await;
+
+    // a mint of the same decimals is still not the staked one
+    let other_mint = harness.create_mint().await;
+    let foreign_stake = replace_account(
+        instructions::stake(user.pubkey(), stake_mint, 10 * TOKEN, LOCK),
+        stake_mint,
+        other_mint,
+    );
+    assert_error(
+        harness.process(&[foreign_stake], &[&user]).await,
+        MyError::InvalidStakeMint,
+    );
+
+    stake(&mut harness, stake_mint, &user, 10 * TOKEN, LOCK)
+        .await
+        .unwrap();
+    harness.warp_by(LOCK).await;
+
+    // a stake account that names another owner cannot be unstaked by the wallet it derives from
+    let stake_account = find_stake_account(&user.pubkey()).0;
+    let mut position: StakeAccount = harness.account(&stake_account).await;
+    position.owner = Pubkey::new_unique();
+    harness.write_account(&stake_account, &position).await;
+    assert_error(
+        unstake(&mut harness, stake_mint, &user, 10 * TOKEN).await,
+        MyError::InvalidStakeAccount,
+    );
+}
+
+#[tokio::test]
+async fn stake_reaches_a_tier_and_unstakes_after_the_lock() {
+    let mut harness = Harness::new().await;
+    let stake_mint = staking(&mut harness).await;
+    let user = staker(&mut harness, stake_mint, 200 * TOKEN).await;
+    let user_tokens = associated_token(&user.pubkey(), &stake_mint);
+    let stake_account = find_stake_account(&user.pubkey()).0;
+
+    stake(&mut harness, stake_mint, &user, 100 * TOKEN, LOCK)
+        .await
+        .unwrap();
+
+    let position: StakeAccount = harness.account(&stake_account).await;
+    assert_eq!(position.amount, 100 * TOKEN);
+    assert!(position.tier == StakeTier::Silver);
+    assert_eq!(harness.token_balance(&user_tokens).await, 100 * TOKEN);
+    harness.assert_emitted(&find_staking_config().0, |event| {
+        matches!(event, PoolEvent::Stake(event)
+            if event.user == user.pubkey()
+                && event.amount == 100 * TOKEN
+                && event.lock_end == position.lock_end
+                && event.tier == StakeTier::Silver)
+    });
+
+    let result = unstake(&mut harness, stake_mint, &user, 40 * TOKEN).await;
+    assert_error(result, MyError::TimeLockNotExpired);
+
+    harness.warp_by(LOCK).await;
+    unstake(&mut harness, stake_mint, &user, 40 * TOKEN)
+        .await
+        .unwrap();
+
+    let position: StakeAccount = harness.account(&stake_account).await;
+    assert_eq!(position.amount, 60 * TOKEN);
+    assert!(position.tier == StakeTier::Bronze);
+    assert_eq!(harness.token_balance(&user_tokens).await, 140 * TOKEN);
+    harness.assert_emitted(&find_staking_config().0, |event| {
+        matches!(event, PoolEvent::Unstake(event)
+            if event.user == user.pubkey()
+                && event.amount == 40 * TOKEN
+                && event.staked == 60 * TOKEN
+                && event.tier == StakeTier::Bronze)
+    });
+
+    let result = unstake(&mut harness, stake_mint, &user, 61 * TOKEN).await;
+    assert_error(result, MyError::InvalidAmount);
+
+    unstake(&mut harness, stake_mint, &user, 60 * TOKEN)
+        .await
+        .unwrap();
+
+    let position: StakeAccount = harness.account(&stake_account).await;
+    assert!(position.tier == StakeTier::Unranked);
+    let staking_config: StakingConfig = harness.account(&find_staking_config().0).await;
+    assert_eq!(staking_config.total_staked, 0);
+}
+
+#[tokio::test]
+async fn tiered_pools_cap_buys_by_the_active_stake_tier() {
+    let mut harness = Harness::new().await;
+    let stake_mint = staking(&mut harness).await;
+    let fixture = tiered_pool(&mut harness).await;
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ConfigureTierAllocations(event)
+            if event.tier_caps == [10 * TOKEN, 20 * TOKEN, 50 * TOKEN])
+    });
+
+    let unstaked = harness.buyer().await;
+    let result = harness.buy(&fixture, &unstaked, TOKEN).await;
+    assert_error(result, MyError::StakeTierRequired);
+
+    let bronze = staker(&mut harness, stake_mint, 10 * TOKEN).await;
+    stake(&mut harness, stake_mint, &bronze, 10 * TOKEN, LOCK)
+        .await
+        .unwrap();
+    let result = buy_staked(&mut harness, &fixture, &bronze, 11 * TOKEN).await;
+    assert_error(result, MyError::MaximumTokenAmountReached);
+    buy_staked(&mut harness, &fixture, &bronze, 10 * TOKEN)
+        .await
+        .unwrap();
+
+    let gold = staker(&mut harness, stake_mint, 1_000 * TOKEN).await;
+    stake(&mut harness, stake_mint, &gold, 1_000 * TOKEN, LOCK)
+        .await
+        .unwrap();
+    buy_staked(&mut harness, &fixture, &gold, 50 * TOKEN)
+        .await
+        .unwrap();
+    let result = buy_staked(&mut harness, &fixture, &gold, TOKEN).await;
+    assert_error(result, MyError::MaximumTokenAmountReached);
+
+    // an expired lock no longer counts towards any tier
+    let silver = staker(&mut harness, stake_mint, 100 * TOKEN).await;
+    stake(&mut harness, stake_mint, &silver, 100 * TOKEN, LOCK)
+        .await
+        .unwrap();
+    harness.warp_by(LOCK).await;
+    let result = buy_staked(&mut harness, &fixture, &silver, TOKEN).await;
+    assert_error(result, MyError::StakeTierRequired);
+}
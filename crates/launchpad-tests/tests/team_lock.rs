@@ -0,0 +1,190 @@
+use encode_sol_team3::{errors::MyError, state::TeamLock};
+use launchpad_client::{find_team_lock, instructions, team_lock_token_account, PoolEvent};
+use launchpad_tests::{
+    assert_error, associated_token, replace_account, Harness, PoolFixture, PoolParams, TxResult,
+    SOL, TEAM_VESTING_DURATION, TOKEN,
+};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+async fn configure_team_lock(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    amount: u64,
+    cliff_date: i64,
+) -> TxResult {
+    let instruction = instructions::configure_team_lock(
+        &fixture.pool,
+        fixture.creator.pubkey(),
+        amount,
+        cliff_date,
+        TEAM_VESTING_DURATION,
+    );
+    harness.process(&[instruction], &[&fixture.creator]).await
+}
+
+async fn withdraw_team_tokens(
+    harness: &mut Harness,
+    fixture: &PoolFixture,
+    authority: &Keypair,
+    destination: Pubkey,
+) -> TxResult {
+    let instruction =
+        instructions::withdraw_team_tokens(&fixture.pool, authority.pubkey(), destination);
+    harness.process(&[instruction], &[authority]).await
+}
+
+#[tokio::test]
+async fn team_cliff_cannot_come_before_the_buyers_unlock() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+
+    let result =
+        configure_team_lock(&mut harness, &fixture, 100 * TOKEN, fixture.unlock_date - 1).await;
+    assert_error(result, MyError::InvalidUnlockDate);
+}
+
+#[tokio::test]
+async fn team_tokens_move_only_through_the_lock_account() {
+    let mut harness = Harness::new().await;
+    let fixture = harness.create_pool(PoolParams::default()).await;
+    configure_team_lock(&mut harness, &fixture, 100 * TOKEN, fixture.unlock_date)
+        .await
+        .unwrap();
+
+    // a pool with a team allocation does not start without funding its lock
+    assert_error(harness.start(&fixture).await, MyError::InvalidTeamLock);
+    let start = instructions::start_launch_pool(
+        &fixture.pool,
+        fixture.creator.pubkey(),
+        Some(fixture.creator_tokens),
+        true,
+    );
+    harness
+        .process(&[start], &[&fixture.creator])
+        .await
+        .unwrap();
+
+    // the creator's own token account passes as an account of the mint but is not the lock's
+    harness
+        .warp_to(fixture.unlock_date + TEAM_VESTING_DURATION)
+        .await;
+    let withdraw = replace_account(
+        instructions::withdraw_team_tokens(
+            &fixture.pool,
+            fixture.creator.pubkey(),
+            fixture.creator_tokens,
+        ),
+        team_lock_token_account(&fixture.launch_pool(), &fixture.mint()),
+        fixture.creator_tokens,
+    );
+    assert_error(
+        harness.process(&[withdraw], &[&fixture.creator]).await,
+        MyError::InvalidTeamLock,
+    );
+}
+
+#[tokio::test]
+async fn team_tokens_vest_linearly_after_the_cliff() {
+    let mut harness = Harness::new().await;
+    let (fixture, cliff_date) = harness.team_locked_pool().await;
+    let (team_lock, _) = find_team_lock(&fixture.launch_pool());
+    let destination = fixture.creator_tokens;
+    let team_lock_token_account = associated_token(&team_lock, &fixture.mint());
+    assert_eq!(
+        harness.token_balance(&team_lock_token_account).await,
+        100 * TOKEN
+    );
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::ConfigureTeamLock(event)
+            if event.amount == 100 * TOKEN
+                && event.cliff_date == cliff_date
+                && event.end_date == cliff_date + TEAM_VESTING_DURATION)
+    });
+
+    let result = withdraw_team_tokens(&mut harness, &fixture, &fixture.creator, destination).await;
+    assert_error(result, MyError::InvalidLaunchPoolStatus);
+
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+
+    harness.warp_to(cliff_date - 1).await;
+    let result = withdraw_team_tokens(&mut harness, &fixture, &fixture.creator, destination).await;
+    assert_error(result, MyError::TimeLockNotExpired);
+
+    let before = harness.token_balance(&destination).await;
+    harness
+        .warp_to(cliff_date + TEAM_VESTING_DURATION / 2)
+        .await;
+    let stranger = harness.wallet(SOL).await;
+    let result = withdraw_team_tokens(&mut harness, &fixture, &stranger, destination).await;
+    assert_error(result, MyError::InvalidAuthority);
+
+    withdraw_team_tokens(&mut harness, &fixture, &fixture.creator, destination)
+        .await
+        .unwrap();
+    assert_eq!(
+        harness.token_balance(&destination).await,
+        before + 50 * TOKEN
+    );
+    harness.assert_emitted(&fixture.launch_pool(), |event| {
+        matches!(event, PoolEvent::WithdrawTeamTokens(event)
+            if event.amount == 50 * TOKEN && event.withdrawn == 50 * TOKEN)
+    });
+
+    // nothing more vested yet
+    let result = withdraw_team_tokens(&mut harness, &fixture, &fixture.creator, destination).await;
+    assert_error(result, MyError::InvalidAmount);
+
+    harness.warp_to(cliff_date + TEAM_VESTING_DURATION).await;
+    withdraw_team_tokens(&mut harness, &fixture, &fixture.creator, destination)
+        .await
+        .unwrap();
+    assert_eq!(
+        harness.token_balance(&destination).await,
+        before + 100 * TOKEN
+    );
+
+    let team_lock: TeamLock = harness.account(&team_lock).await;
+    assert_eq!(team_lock.withdrawn, 100 * TOKEN);
+    assert_eq!(harness.token_balance(&team_lock_token_account).await, 0);
+}
+
+#[tokio::test]
+async fn team_lock_keeps_the_pool_open_until_emptied() {
+    let mut harness = Harness::new().await;
+    let (fixture, cliff_date) = harness.team_locked_pool().await;
+    let alice = harness.buyer().await;
+    harness.buy(&fixture, &alice, 10 * TOKEN).await.unwrap();
+    harness.complete(&fixture).await.unwrap();
+    harness.settle(&fixture, &[&alice]).await;
+
+    harness
+        .warp_to(cliff_date + TEAM_VESTING_DURATION / 2)
+        .await;
+    withdraw_team_tokens(
+        &mut harness,
+        &fixture,
+        &fixture.creator,
+        fixture.creator_tokens,
+    )
+    .await
+    .unwrap();
+    let result = harness.close(&fixture).await;
+    assert_error(result, MyError::LaunchPoolNotSettled);
+
+    harness.warp_to(cliff_date + TEAM_VESTING_DURATION).await;
+    withdraw_team_tokens(
+        &mut harness,
+        &fixture,
+        &fixture.creator,
+        fixture.creator_tokens,
+    )
+    .await
+    .unwrap();
+    harness.close(&fixture).await.unwrap();
+    assert!(!harness.exists(&fixture.launch_pool()).await);
+}
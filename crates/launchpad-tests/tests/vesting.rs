@@ -0,0 +1,68 @@
+use encode_sol_team3::{
+    errors::MyError,
+    state::{ProceedsVesting, VestingSchedule},
+};
+
+fn proceeds_vesting(completion_bps: u16, period: i64, periods: u16) -> ProceedsVesting {
+    ProceedsVesting {
+        completion_bps,
+        period,
+        periods,
+    }
+}
+
+#[test]
+fn proceeds_vesting_needs_a_period_whenever_it_has_periods() {
+    assert!(proceeds_vesting(10_000, 0, 0).validate().is_ok());
+    assert!(proceeds_vesting(5_000, 100, 4).validate().is_ok());
+    assert!(proceeds_vesting(10_000, 100, 4).validate().is_ok());
+
+    for plan in [
+        proceeds_vesting(10_000, 0, 4),
+        proceeds_vesting(10_000, -100, 4),
+        proceeds_vesting(5_000, 0, 4),
+        proceeds_vesting(5_000, 100, 0),
+    ] {
+        assert_eq!(
+            plan.validate().unwrap_err(),
+            MyError::InvalidVestingPlan.into()
+        );
+    }
+    assert_eq!(
+        proceeds_vesting(10_001, 100, 1).validate().unwrap_err(),
+        MyError::InvalidBasisPoints.into()
+    );
+}
+
+#[test]
+fn proceeds_are_released_by_whole_periods() {
+    let plan = proceeds_vesting(2_000, 100, 4);
+
+    assert_eq!(plan.released_amount(1_000, 0, 0), 200);
+    assert_eq!(plan.released_amount(1_000, 0, 99), 200);
+    assert_eq!(plan.released_amount(1_000, 0, 100), 400);
+    assert_eq!(plan.released_amount(1_000, 0, 399), 800);
+    assert_eq!(plan.released_amount(1_000, 0, 10_000), 1_000);
+
+    // a plan that releases everything at completion ignores its periods
+    let plan = proceeds_vesting(10_000, 100, 4);
+    assert_eq!(plan.released_amount(1_000, 0, 50), 1_000);
+}
+
+#[test]
+fn team_tokens_vest_linearly_from_the_cliff() {
+    let schedule = VestingSchedule::new(100, 1_000).unwrap();
+
+    assert_eq!(
+        schedule.vested_amount(1_000, 99).unwrap_err(),
+        MyError::TimeLockNotExpired.into()
+    );
+    assert_eq!(schedule.vested_amount(1_000, 100).unwrap(), 0);
+    assert_eq!(schedule.vested_amount(1_000, 600).unwrap(), 500);
+    assert_eq!(schedule.vested_amount(1_000, 5_000).unwrap(), 1_000);
+
+    assert!(matches!(
+        VestingSchedule::new(100, -1),
+        Err(error) if error == MyError::InvalidVestingPlan.into()
+    ));
+}
@@ -0,0 +1,82 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        hash::hash,
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+};
+
+// accounts handed to an AMM when the launch pool seeds liquidity
+pub struct AddLiquidityAccounts<'info> {
+    pub amm_program: AccountInfo<'info>,
+    pub pool: AccountInfo<'info>,
+    pub lp_mint: AccountInfo<'info>,
+    pub token_reserve: AccountInfo<'info>,
+    pub sol_source: AccountInfo<'info>,
+    pub token_source: AccountInfo<'info>,
+    pub token_authority: AccountInfo<'info>,
+    pub lp_destination: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+pub trait LiquidityAdapter {
+    fn add_liquidity(
+        accounts: AddLiquidityAccounts<'_>,
+        sol_amount: u64,
+        token_amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()>;
+}
+
+// speaks the `add_liquidity(sol_amount, token_amount)` interface of the bundled mock AMM
+pub struct ConstantProductAmm;
+
+impl LiquidityAdapter for ConstantProductAmm {
+    fn add_liquidity(
+        accounts: AddLiquidityAccounts<'_>,
+        sol_amount: u64,
+        token_amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let mut data = hash(b"global:add_liquidity").to_bytes()[..8].to_vec();
+        data.extend_from_slice(&sol_amount.to_le_bytes());
+        data.extend_from_slice(&token_amount.to_le_bytes());
+
+        let instruction = Instruction {
+            program_id: accounts.amm_program.key(),
+            accounts: vec![
+                AccountMeta::new(accounts.pool.key(), false),
+                AccountMeta::new(accounts.lp_mint.key(), false),
+                AccountMeta::new(accounts.token_reserve.key(), false),
+                AccountMeta::new(accounts.sol_source.key(), true),
+                AccountMeta::new(accounts.token_source.key(), false),
+                AccountMeta::new_readonly(accounts.token_authority.key(), true),
+                AccountMeta::new(accounts.lp_destination.key(), false),
+                AccountMeta::new_readonly(accounts.token_program.key(), false),
+                AccountMeta::new_readonly(accounts.system_program.key(), false),
+            ],
+            data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                accounts.pool,
+                accounts.lp_mint,
+                accounts.token_reserve,
+                accounts.sol_source,
+                accounts.token_source,
+                accounts.token_authority,
+                accounts.lp_destination,
+                accounts.token_program,
+                accounts.system_program,
+                accounts.amm_program,
+            ],
+            signer_seeds,
+        )?;
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use anchor_lang::prelude::Pubkey;
 pub const DISCRIMINATOR_SIZE: usize = std::mem::size_of::<u64>();
 pub const PUBKEY_SIZE: usize = std::mem::size_of::<Pubkey>();
 pub const U8_SIZE: usize = std::mem::size_of::<u8>();
+pub const U16_SIZE: usize = std::mem::size_of::<u16>();
 pub const U32_SIZE: usize = std::mem::size_of::<u32>();
 pub const U64_SIZE: usize = std::mem::size_of::<u64>();
 pub const U128_SIZE: usize = std::mem::size_of::<u128>();
@@ -16,4 +17,32 @@ pub const VAULT_SEED: &[u8] = b"vault";
 pub const USER_POOL_SEED: &[u8] = b"userpool";
 pub const WHITELIST_SEED: &[u8] = b"whitelist";
 pub const VESTING_PLAN_SEED: &[u8] = b"vestingplan";
+pub const SALE_ROUND_SEED: &[u8] = b"saleround";
+pub const STAKING_CONFIG_SEED: &[u8] = b"stakingconfig";
+pub const STAKE_ACCOUNT_SEED: &[u8] = b"stake";
+pub const NFT_USAGE_SEED: &[u8] = b"nftusage";
+pub const REFERRER_SEED: &[u8] = b"referrer";
+pub const REFERRAL_STATS_SEED: &[u8] = b"referralstats";
+pub const LP_LOCK_SEED: &[u8] = b"lplock";
+pub const LIQUIDITY_ESCROW_SEED: &[u8] = b"liquidityescrow";
+pub const TEAM_LOCK_SEED: &[u8] = b"teamlock";
+pub const MILESTONE_SEED: &[u8] = b"milestone";
+pub const VOTE_SEED: &[u8] = b"vote";
+pub const CREATOR_REGISTRY_SEED: &[u8] = b"creatorregistry";
+pub const POOL_METADATA_SEED: &[u8] = b"poolmetadata";
+pub const MINT_AUTHORITY_SEED: &[u8] = b"mintauthority";
 pub const CURRENCY_DECIMALS: u32 = 9;
+pub const MAX_SALE_ROUNDS: usize = 3;
+pub const MAX_WHITELIST_WALLETS: usize = 200;
+pub const STAKE_TIERS: usize = 3;
+pub const MAX_NFT_GATE_CREATORS: usize = 5;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_POOL_NAME_LENGTH: usize = 32;
+pub const MAX_POOL_SYMBOL_LENGTH: usize = 10;
+pub const MAX_POOL_URI_LENGTH: usize = 200;
+pub const MAX_POOL_SOCIALS: usize = 4;
+pub const CONTENT_HASH_SIZE: usize = 32;
+// bytes kept free at the end of an account so new fields do not need a realloc
+pub const LAUNCH_POOL_RESERVED_SIZE: usize = 79;
+pub const TREASURER_RESERVED_SIZE: usize = 32;
+pub const USER_POOL_RESERVED_SIZE: usize = 32;
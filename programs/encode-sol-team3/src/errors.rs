@@ -64,4 +64,94 @@ pub enum MyError {
     InvalidVestingPlan,
     #[msg("Invalid schedule size")]
     InvalidScheduleSize,
+    #[msg("Invalid sale round")]
+    InvalidSaleRound,
+    #[msg("Too many sale rounds")]
+    TooManySaleRounds,
+    #[msg("Sale round allocation exceeds pool size")]
+    SaleRoundAllocationExceeded,
+    #[msg("Invalid sale round time window")]
+    InvalidSaleRoundTime,
+    #[msg("Sale round is not open")]
+    SaleRoundNotOpen,
+    #[msg("Sale round allocation not enough")]
+    SaleRoundAllocationNotEnough,
+    #[msg("Launch pool is sold in rounds")]
+    LaunchPoolHasSaleRounds,
+    #[msg("Invalid stake tiers")]
+    InvalidStakeTiers,
+    #[msg("Invalid stake mint")]
+    InvalidStakeMint,
+    #[msg("Invalid stake account")]
+    InvalidStakeAccount,
+    #[msg("Stake tier required")]
+    StakeTierRequired,
+    #[msg("Invalid NFT gate")]
+    InvalidNftGate,
+    #[msg("NFT required to buy")]
+    NftRequired,
+    #[msg("Invalid NFT account")]
+    InvalidNftAccount,
+    #[msg("NFT not in allowed collection")]
+    NftNotInCollection,
+    #[msg("NFT already used")]
+    NftAlreadyUsed,
+    #[msg("Allocation voucher required")]
+    AllocationVoucherRequired,
+    #[msg("Invalid allocation voucher")]
+    InvalidAllocationVoucher,
+    #[msg("Invalid allocation signer")]
+    InvalidAllocationSigner,
+    #[msg("Allocation voucher expired")]
+    AllocationVoucherExpired,
+    #[msg("Invalid referrer")]
+    InvalidReferrer,
+    #[msg("Invalid basis points")]
+    InvalidBasisPoints,
+    #[msg("Invalid AMM pool")]
+    InvalidAmmPool,
+    #[msg("Liquidity accounts required")]
+    LiquidityAccountsRequired,
+    #[msg("Invalid LP lock")]
+    InvalidLpLock,
+    #[msg("Invalid team lock")]
+    InvalidTeamLock,
+    #[msg("Launch pool releases funds by milestones")]
+    LaunchPoolIsGoverned,
+    #[msg("Launch pool is not governed")]
+    LaunchPoolNotGoverned,
+    #[msg("Invalid milestone")]
+    InvalidMilestone,
+    #[msg("Milestone voting is closed")]
+    MilestoneVotingClosed,
+    #[msg("Milestone voting is still open")]
+    MilestoneVotingOpen,
+    #[msg("Refund not available")]
+    RefundNotAvailable,
+    #[msg("Already refunded")]
+    AlreadyRefunded,
+    #[msg("Invalid pending authority")]
+    InvalidPendingAuthority,
+    #[msg("Position not settled")]
+    PositionNotSettled,
+    #[msg("Launch pool not settled")]
+    LaunchPoolNotSettled,
+    #[msg("Unsold tokens already withdrawn")]
+    UnsoldTokensWithdrawn,
+    #[msg("Token account required")]
+    TokenAccountRequired,
+    #[msg("Invalid pool metadata")]
+    InvalidPoolMetadata,
+    #[msg("Minted supply does not match the launch pool")]
+    InvalidMintedSupply,
+    #[msg("Token metadata accounts required")]
+    TokenMetadataAccountsRequired,
+    #[msg("Already claimed")]
+    AlreadyClaimed,
+    #[msg("Unsold policy not declared")]
+    UnsoldPolicyNotDeclared,
+    #[msg("Unsold policy declared at creation")]
+    UnsoldPolicyDeclared,
+    #[msg("Invalid user pool")]
+    InvalidUserPool,
 }
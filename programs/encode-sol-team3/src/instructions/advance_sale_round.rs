@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::SALE_ROUND_SEED,
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, SaleRound},
+};
+
+#[event]
+pub struct AdvanceSaleRoundEvent {
+    pub launch_pool: Pubkey,
+    pub signer: Pubkey,
+    pub closed_round: u8,
+    pub current_round: u8,
+    pub rolled_over: u64,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AdvanceSaleRound<'info> {
+    #[account(mut)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [SALE_ROUND_SEED, launch_pool.key().as_ref(), &[launch_pool.current_round]],
+        bump,
+    )]
+    pub current_round: Box<Account<'info, SaleRound>>,
+    #[account(
+        mut,
+        seeds = [SALE_ROUND_SEED, launch_pool.key().as_ref(), &[launch_pool.current_round + 1]],
+        bump,
+    )]
+    pub next_round: Box<Account<'info, SaleRound>>,
+    pub signer: Signer<'info>,
+}
+
+pub fn advance_sale_round_handler(ctx: Context<AdvanceSaleRound>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let current_round = &mut ctx.accounts.current_round;
+    let next_round = &mut ctx.accounts.next_round;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Active,
+        MyError::InvalidLaunchPoolStatus
+    );
+    // the authority can close a round early, anyone else has to wait for it to end
+    require!(
+        launch_pool.authority == *ctx.accounts.signer.key
+            || current_round.end_time <= Clock::get()?.unix_timestamp,
+        MyError::InvalidAuthority
+    );
+
+    let unsold = current_round.allocation_remaining();
+
+    current_round.allocation = current_round.sold;
+    current_round.is_closed = true;
+    next_round.allocation = next_round.allocation.checked_add(unsold).unwrap();
+    launch_pool.current_round += 1;
+
+    msg!(
+        "Sale round {} closed, {} unsold tokens rolled to round {}",
+        current_round.index,
+        unsold,
+        next_round.index
+    );
+
+    emit_cpi!(AdvanceSaleRoundEvent {
+        launch_pool: launch_pool.key(),
+        signer: *ctx.accounts.signer.key,
+        closed_round: current_round.index,
+        current_round: next_round.index,
+        rolled_over: unsold,
+        status: launch_pool.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use anchor_lang::{
+    prelude::*, solana_program::sysvar::instructions as sysvar_instructions, system_program,
+};
+use anchor_spl::{metadata::MetadataAccount, token};
+
+use crate::{
+    constants::{
+        NFT_USAGE_SEED, SALE_ROUND_SEED, STAKE_ACCOUNT_SEED, USER_POOL_SEED, VAULT_SEED,
+        WHITELIST_SEED,
+    },
+    errors::MyError,
+    instructions::purchase::{apply_purchase_checks, record_purchase, PurchaseGates},
+    state::{
+        CurrencyType, LaunchPool, LaunchPoolState, LaunchPoolType, NftUsage, ReferralStats,
+        SaleRound, SaleRoundAccess, StakeAccount, UserPool, Whitelist,
+    },
+};
+
+#[event]
+pub struct BuyTokenInRoundEvent {
+    pub launch_pool: Pubkey,
+    pub buyer: Pubkey,
+    pub round: u8,
+    pub amount: u64,
+    pub currency_amount: u64,
+    pub token_amount: u64,
+    pub round_token_amount: u64,
+    pub vault_amount: u64,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BuyTokenInRound<'info> {
+    #[account(mut)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [SALE_ROUND_SEED, launch_pool.key().as_ref(), &[launch_pool.current_round]],
+        bump,
+    )]
+    pub sale_round: Box<Account<'info, SaleRound>>,
+    #[account(
+        seeds = [WHITELIST_SEED, sale_round.key().as_ref()],
+        bump,
+    )]
+    pub whitelist: Option<Box<Account<'info, Whitelist>>>,
+    pub token_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        init_if_needed,
+        seeds = [USER_POOL_SEED, user.key().as_ref(), launch_pool.key().as_ref(),token_mint.key().as_ref()],
+        bump,
+        payer = user,
+        space = UserPool::LEN
+    )]
+    pub user_pool: Box<Account<'info, UserPool>>,
+    /// CHECK: Create a new vault for the launch pool
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            launch_pool.key().as_ref(),
+            launch_pool.creator.as_ref()
+        ],
+        bump = launch_pool.bumps.vault_bump,
+    )]
+    pub vault: AccountInfo<'info>,
+    #[account(
+        seeds = [STAKE_ACCOUNT_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub stake_account: Option<Box<Account<'info, StakeAccount>>>,
+    #[account(
+        token::authority = user,
+        constraint = nft_token_account.amount == 1 @ MyError::InvalidNftAccount,
+    )]
+    pub nft_token_account: Option<Box<Account<'info, token::TokenAccount>>>,
+    pub nft_metadata: Option<Box<Account<'info, MetadataAccount>>>,
+    #[account(
+        init_if_needed,
+        seeds = [NFT_USAGE_SEED, launch_pool.key().as_ref(), nft_token_account.as_ref().ok_or(MyError::NftRequired)?.mint.as_ref()],
+        bump,
+        payer = user,
+        space = NftUsage::LEN
+    )]
+    pub nft_usage: Option<Box<Account<'info, NftUsage>>>,
+    /// CHECK: the instructions sysvar, read for the Ed25519 allocation voucher
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        constraint = referral_stats.launch_pool == launch_pool.key() @ MyError::InvalidReferrer,
+        constraint = referral_stats.referrer != user.key() @ MyError::InvalidReferrer,
+    )]
+    pub referral_stats: Option<Box<Account<'info, ReferralStats>>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, token::Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn buy_token_in_round_handler(ctx: Context<BuyTokenInRound>, amount: u64) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let sale_round = &mut ctx.accounts.sale_round;
+    let user_pool = &mut ctx.accounts.user_pool;
+    let user = &ctx.accounts.user;
+
+    require!(amount.gt(&0), MyError::InvalidAmount);
+
+    require!(
+        launch_pool.status == LaunchPoolState::Active,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(
+        launch_pool.pool_type == LaunchPoolType::FairLaunch,
+        MyError::InvalidLaunchPoolType
+    );
+    require!(
+        launch_pool.currency == CurrencyType::SOL,
+        MyError::InvalidCurrencyType
+    );
+    require!(
+        launch_pool.token_mint == ctx.accounts.token_mint.key(),
+        MyError::InvalidTokenMint
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(sale_round.is_open(now), MyError::SaleRoundNotOpen);
+
+    if sale_round.access == SaleRoundAccess::Whitelist {
+        let whitelist = ctx
+            .accounts
+            .whitelist
+            .as_ref()
+            .ok_or(MyError::InvalidWhitelist)?;
+        require!(whitelist.contains(user.key), MyError::UserNotInWhiteList);
+    }
+
+    require!(
+        sale_round.allocation_remaining().ge(&amount),
+        MyError::SaleRoundAllocationNotEnough
+    );
+    require!(
+        launch_pool.pool_size_remaining.ge(&amount),
+        MyError::PoolSizeRemainingNotEnough
+    );
+
+    let round = sale_round.index as usize;
+    let round_amount = user_pool.round_amounts[round]
+        .checked_add(amount)
+        .ok_or(MyError::Overflow)?;
+
+    require!(
+        round_amount.ge(&sale_round.minimum_token_amount),
+        MyError::MinimumTokenAmountNotReached
+    );
+    require!(
+        round_amount.le(&sale_round.maximum_token_amount),
+        MyError::MaximumTokenAmountReached
+    );
+
+    apply_purchase_checks(
+        launch_pool,
+        user_pool,
+        PurchaseGates {
+            stake_account: ctx.accounts.stake_account.as_deref(),
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+            nft_usage: ctx.accounts.nft_usage.as_deref_mut(),
+            instructions_sysvar: ctx.accounts.instructions_sysvar.as_ref(),
+        },
+        user.key,
+        amount,
+        now,
+    )?;
+
+    let user_must_pay = launch_pool.calculate_user_must_pay_at_rate(amount, sale_round.rate);
+
+    require!(user_must_pay.gt(&0), MyError::InvalidAmount);
+
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        system_program::Transfer {
+            from: user.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_context, user_must_pay)?;
+
+    msg!(
+        "User buy {} token {} in round {} with {} RENEC",
+        amount,
+        launch_pool.token_mint,
+        sale_round.index,
+        user_must_pay
+    );
+
+    user_pool.round_amounts[round] = round_amount;
+    sale_round.sold = sale_round.sold.checked_add(amount).unwrap();
+    let referred = record_purchase(
+        launch_pool,
+        user_pool,
+        ctx.accounts.referral_stats.as_deref_mut(),
+        user.key,
+        amount,
+        user_must_pay,
+    )?;
+    if let Some(referred) = referred {
+        emit_cpi!(referred);
+    }
+
+    emit_cpi!(BuyTokenInRoundEvent {
+        launch_pool: launch_pool.key(),
+        buyer: *user.key,
+        round: sale_round.index,
+        amount,
+        currency_amount: user_must_pay,
+        token_amount: user_pool.amount,
+        round_token_amount: round_amount,
+        vault_amount: launch_pool.vault_amount,
+        status: launch_pool.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
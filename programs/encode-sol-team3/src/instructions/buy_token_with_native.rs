@@ -1,28 +1,40 @@
-use anchor_lang::{prelude::*, system_program};
-use anchor_spl::token;
+use anchor_lang::{
+    prelude::*, solana_program::sysvar::instructions as sysvar_instructions, system_program,
+};
+use anchor_spl::{metadata::MetadataAccount, token};
 
 use crate::{
-    constants::{USER_POOL_SEED, VAULT_SEED},
+    constants::{NFT_USAGE_SEED, STAKE_ACCOUNT_SEED, USER_POOL_SEED, VAULT_SEED},
     errors::MyError,
-    state::{CurrencyType, LaunchPool, LaunchPoolState, LaunchPoolType, UserPool},
+    instructions::purchase::{apply_purchase_checks, record_purchase, PurchaseGates},
+    state::{
+        CurrencyType, LaunchPool, LaunchPoolState, LaunchPoolType, NftUsage, ReferralStats,
+        StakeAccount, UserPool,
+    },
 };
 
 #[event]
 pub struct BuyTokenWithNativeEvent {
+    pub launch_pool: Pubkey,
     pub buyer: Pubkey,
     pub amount: u64,
+    pub currency_amount: u64,
     pub token_amount: u64,
     pub vault_amount: u64,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
 }
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct BuyTokenWithNative<'info> {
     #[account(mut)]
     pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(address = launch_pool.token_mint @ MyError::InvalidTokenMint)]
     pub token_mint: Box<Account<'info, token::Mint>>,
     #[account(
         init_if_needed,
-        seeds = [USER_POOL_SEED.as_ref(), user.key().as_ref(), launch_pool.key().as_ref(),token_mint.key().as_ref()],
+        seeds = [USER_POOL_SEED, user.key().as_ref(), launch_pool.key().as_ref(),token_mint.key().as_ref()],
         bump,
         payer = user,
         space = UserPool::LEN
@@ -32,13 +44,41 @@ pub struct BuyTokenWithNative<'info> {
     #[account(
         mut,
         seeds = [
-            VAULT_SEED.as_ref(),
+            VAULT_SEED,
             launch_pool.key().as_ref(),
-            launch_pool.authority.as_ref()
+            launch_pool.creator.as_ref()
         ],
-        bump ,
+        bump = launch_pool.bumps.vault_bump,
     )]
     pub vault: AccountInfo<'info>,
+    #[account(
+        seeds = [STAKE_ACCOUNT_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub stake_account: Option<Box<Account<'info, StakeAccount>>>,
+    #[account(
+        token::authority = user,
+        constraint = nft_token_account.amount == 1 @ MyError::InvalidNftAccount,
+    )]
+    pub nft_token_account: Option<Box<Account<'info, token::TokenAccount>>>,
+    pub nft_metadata: Option<Box<Account<'info, MetadataAccount>>>,
+    #[account(
+        init_if_needed,
+        seeds = [NFT_USAGE_SEED, launch_pool.key().as_ref(), nft_token_account.as_ref().ok_or(MyError::NftRequired)?.mint.as_ref()],
+        bump,
+        payer = user,
+        space = NftUsage::LEN
+    )]
+    pub nft_usage: Option<Box<Account<'info, NftUsage>>>,
+    /// CHECK: the instructions sysvar, read for the Ed25519 allocation voucher
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        constraint = referral_stats.launch_pool == launch_pool.key() @ MyError::InvalidReferrer,
+        constraint = referral_stats.referrer != user.key() @ MyError::InvalidReferrer,
+    )]
+    pub referral_stats: Option<Box<Account<'info, ReferralStats>>>,
     #[account(mut)]
     pub user: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -46,7 +86,7 @@ pub struct BuyTokenWithNative<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<BuyTokenWithNative>, amount: u64) -> Result<()> {
+pub fn buy_token_with_native_handler(ctx: Context<BuyTokenWithNative>, amount: u64) -> Result<()> {
     let launch_pool = &mut ctx.accounts.launch_pool;
     let user_pool = &mut ctx.accounts.user_pool;
 
@@ -65,6 +105,11 @@ pub fn handler(ctx: Context<BuyTokenWithNative>, amount: u64) -> Result<()> {
         launch_pool.currency == CurrencyType::SOL,
         MyError::InvalidCurrencyType
     );
+    require!(
+        !launch_pool.has_sale_rounds(),
+        MyError::LaunchPoolHasSaleRounds
+    );
+
     require!(
         launch_pool.pool_size_remaining.ge(&amount),
         MyError::PoolSizeRemainingNotEnough
@@ -78,15 +123,32 @@ pub fn handler(ctx: Context<BuyTokenWithNative>, amount: u64) -> Result<()> {
             .ge(&launch_pool.minimum_token_amount),
         MyError::MinimumTokenAmountNotReached
     );
-
-    require!(
-        user_pool
-            .amount
-            .checked_add(amount)
-            .unwrap()
-            .le(&launch_pool.maximum_token_amount),
-        MyError::MaximumTokenAmountReached
-    );
+    // tiered pools are capped by the buyer's tier instead
+    if !launch_pool.is_tiered {
+        require!(
+            user_pool
+                .amount
+                .checked_add(amount)
+                .unwrap()
+                .le(&launch_pool.maximum_token_amount),
+            MyError::MaximumTokenAmountReached
+        );
+    }
+
+    apply_purchase_checks(
+        launch_pool,
+        user_pool,
+        PurchaseGates {
+            stake_account: ctx.accounts.stake_account.as_deref(),
+            nft_token_account: ctx.accounts.nft_token_account.as_deref(),
+            nft_metadata: ctx.accounts.nft_metadata.as_deref(),
+            nft_usage: ctx.accounts.nft_usage.as_deref_mut(),
+            instructions_sysvar: ctx.accounts.instructions_sysvar.as_ref(),
+        },
+        ctx.accounts.user.key,
+        amount,
+        Clock::get()?.unix_timestamp,
+    )?;
 
     let user_must_pay = launch_pool.calculate_user_must_pay(amount);
 
@@ -108,19 +170,27 @@ pub fn handler(ctx: Context<BuyTokenWithNative>, amount: u64) -> Result<()> {
         user_must_pay
     );
 
-    user_pool.amount = user_pool.amount.checked_add(amount).unwrap();
-    user_pool.currency_amount = user_pool
-        .currency_amount
-        .checked_add(user_must_pay)
-        .unwrap();
-    launch_pool.pool_size_remaining = launch_pool.pool_size_remaining.checked_sub(amount).unwrap();
-    launch_pool.vault_amount = launch_pool.vault_amount.checked_add(user_must_pay).unwrap();
-
-    emit!(BuyTokenWithNativeEvent {
+    let referred = record_purchase(
+        launch_pool,
+        user_pool,
+        ctx.accounts.referral_stats.as_deref_mut(),
+        ctx.accounts.user.key,
+        amount,
+        user_must_pay,
+    )?;
+    if let Some(referred) = referred {
+        emit_cpi!(referred);
+    }
+
+    emit_cpi!(BuyTokenWithNativeEvent {
+        launch_pool: launch_pool.key(),
         buyer: *ctx.accounts.user.key,
         amount,
+        currency_amount: user_must_pay,
         token_amount: user_pool.amount,
         vault_amount: launch_pool.vault_amount,
+        status: launch_pool.status,
+        timestamp: Clock::get()?.unix_timestamp,
     });
 
     Ok(())
@@ -2,15 +2,27 @@ use anchor_lang::prelude::*;
 use anchor_spl::{associated_token, token};
 
 use crate::{
-    constants::TREASURER_SEED,
+    constants::{TREASURER_SEED, USER_POOL_SEED},
     errors::MyError,
     state::{LaunchPool, LaunchPoolState, Treasurer, UserPool},
 };
 
+#[event]
+pub struct ClaimTokenEvent {
+    pub launch_pool: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub claimed: u64,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimToken<'info> {
     #[account(mut)]
     pub launch_pool: Account<'info, LaunchPool>,
+    #[account(address = launch_pool.token_mint @ MyError::InvalidTokenMint)]
     pub token_mint: Box<Account<'info, token::Mint>>,
     #[account(mut)]
     pub treasurer: Box<Account<'info, Treasurer>>,
@@ -20,7 +32,11 @@ pub struct ClaimToken<'info> {
          associated_token::authority = treasurer
     )]
     pub treasury: Box<Account<'info, token::TokenAccount>>,
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [USER_POOL_SEED, user.key().as_ref(), launch_pool.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+    )]
     pub user_pool: Box<Account<'info, UserPool>>,
     #[account(init_if_needed,
         payer = user,
@@ -36,18 +52,21 @@ pub struct ClaimToken<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<ClaimToken>) -> Result<()> {
-    let launch_pool = &ctx.accounts.launch_pool;
+pub fn claim_token_handler(ctx: Context<ClaimToken>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
     let user_pool = &mut ctx.accounts.user_pool;
 
-    let (treasurer_pda, tbump) = Pubkey::find_program_address(
+    let tbump = launch_pool.bumps.treasurer_bump;
+    let treasurer_pda = Pubkey::create_program_address(
         &[
-            TREASURER_SEED.as_ref(),
+            TREASURER_SEED,
             launch_pool.key().as_ref(),
             ctx.accounts.token_mint.key().as_ref(),
+            &[tbump],
         ],
         ctx.program_id,
-    );
+    )
+    .map_err(|_| MyError::InvalidTreasurer)?;
 
     require!(
         treasurer_pda == *ctx.accounts.treasurer.to_account_info().key,
@@ -59,14 +78,15 @@ pub fn handler(ctx: Context<ClaimToken>) -> Result<()> {
         MyError::InvalidLaunchPoolStatus
     );
 
-    require!(
-        launch_pool.unlock_date <= Clock::get()?.unix_timestamp,
-        MyError::TimeLockNotExpired
-    );
+    let vested_amount = launch_pool
+        .buyer_vesting()
+        .vested_amount(user_pool.amount, Clock::get()?.unix_timestamp)?;
 
     require!(user_pool.amount > 0, MyError::InvalidAmount);
+    // a refunded buyer already got their funds back
+    require!(user_pool.refunded == 0, MyError::AlreadyRefunded);
 
-    let user_token_amount = user_pool.amount - user_pool.claimed;
+    let user_token_amount = vested_amount - user_pool.claimed;
 
     require!(user_token_amount > 0, MyError::InvalidAmount);
 
@@ -75,7 +95,7 @@ pub fn handler(ctx: Context<ClaimToken>) -> Result<()> {
     let token_mint = ctx.accounts.token_mint.key();
 
     let signer_seeds = [
-        &TREASURER_SEED.as_ref()[..],
+        TREASURER_SEED,
         lp_key.as_ref(),
         token_mint.as_ref(),
         &[tbump],
@@ -94,9 +114,33 @@ pub fn handler(ctx: Context<ClaimToken>) -> Result<()> {
         user_token_amount,
     )?;
 
+    // a position that claims can no longer be refunded, so it leaves the refund split
+    if user_pool.claimed == 0 {
+        launch_pool.claimed_sold_amount = launch_pool
+            .claimed_sold_amount
+            .checked_add(user_pool.amount)
+            .unwrap();
+        if launch_pool.is_refunding {
+            launch_pool.refund_sold_amount = launch_pool
+                .refund_sold_amount
+                .checked_sub(user_pool.amount)
+                .unwrap();
+        }
+    }
     user_pool.claimed += user_token_amount;
+    let treasurer = &mut ctx.accounts.treasurer;
+    treasurer.amount = treasurer.amount.checked_sub(user_token_amount).unwrap();
 
     msg!("User token claimed: {}", user_pool.claimed);
 
+    emit_cpi!(ClaimTokenEvent {
+        launch_pool: lp_key,
+        user: *ctx.accounts.user.key,
+        amount: user_token_amount,
+        claimed: user_pool.claimed,
+        status: launch_pool.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -0,0 +1,231 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token;
+
+use crate::{
+    constants::{
+        LP_LOCK_SEED, POOL_METADATA_SEED, TEAM_LOCK_SEED, TREASURER_SEED, USER_POOL_SEED,
+        VAULT_SEED,
+    },
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, LpLock, PoolMetadata, TeamLock, Treasurer, UserPool},
+};
+
+#[event]
+pub struct CloseUserPoolEvent {
+    pub launch_pool: Pubkey,
+    pub user: Pubkey,
+    pub open_positions: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CloseLaunchPoolEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseUserPool<'info> {
+    #[account(mut)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [USER_POOL_SEED, user.key().as_ref(), launch_pool.key().as_ref(), launch_pool.token_mint.as_ref()],
+        bump,
+        close = user
+    )]
+    pub user_pool: Box<Account<'info, UserPool>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseLaunchPool<'info> {
+    #[account(
+        mut,
+        has_one = authority @ MyError::InvalidAuthority,
+        has_one = creator @ MyError::InvalidAuthority,
+        close = creator
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [TREASURER_SEED, launch_pool.key().as_ref(), launch_pool.token_mint.as_ref()],
+        bump = launch_pool.bumps.treasurer_bump,
+        close = creator
+    )]
+    pub treasurer: Box<Account<'info, Treasurer>>,
+    #[account(
+        mut,
+        token::mint = launch_pool.token_mint,
+        token::authority = treasurer,
+    )]
+    pub treasury: Box<Account<'info, token::TokenAccount>>,
+    /// CHECK: The vault of the launch pool
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            launch_pool.key().as_ref(),
+            launch_pool.creator.as_ref()
+        ],
+        bump = launch_pool.bumps.vault_bump,
+    )]
+    pub vault: AccountInfo<'info>,
+    /// CHECK: The pool metadata, closed along with the pool when it was created
+    #[account(
+        mut,
+        seeds = [POOL_METADATA_SEED, launch_pool.key().as_ref()],
+        bump,
+    )]
+    pub pool_metadata: AccountInfo<'info>,
+    /// CHECK: The team lock of the pool, must be emptied if it was configured
+    #[account(
+        seeds = [TEAM_LOCK_SEED, launch_pool.key().as_ref()],
+        bump,
+    )]
+    pub team_lock: AccountInfo<'info>,
+    /// CHECK: The LP lock of the pool, must be emptied if it was configured
+    #[account(
+        seeds = [LP_LOCK_SEED, launch_pool.key().as_ref()],
+        bump,
+    )]
+    pub lp_lock: AccountInfo<'info>,
+    /// CHECK: Receives the rent, must be the creator who paid for the pool
+    #[account(mut)]
+    pub creator: AccountInfo<'info>,
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, token::Token>,
+}
+
+pub fn close_user_pool_handler(ctx: Context<CloseUserPool>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let user_pool = &ctx.accounts.user_pool;
+
+    require!(user_pool.is_settled(), MyError::PositionNotSettled);
+
+    launch_pool.open_positions = launch_pool.open_positions.checked_sub(1).unwrap();
+
+    msg!(
+        "User pool closed, {} positions left open",
+        launch_pool.open_positions
+    );
+
+    emit_cpi!(CloseUserPoolEvent {
+        launch_pool: launch_pool.key(),
+        user: *ctx.accounts.user.key,
+        open_positions: launch_pool.open_positions,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn close_launch_pool_handler(ctx: Context<CloseLaunchPool>) -> Result<()> {
+    let launch_pool = &ctx.accounts.launch_pool;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Completed
+            || launch_pool.status == LaunchPoolState::Cancelled,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(
+        launch_pool.open_positions == 0
+            && launch_pool.referral_reward_claimed == launch_pool.referral_reward_total
+            && ctx.accounts.treasury.amount == 0,
+        MyError::LaunchPoolNotSettled
+    );
+    // proceeds still owed to the creator or the buyers must not be swept with the rent
+    if launch_pool.status == LaunchPoolState::Completed {
+        require!(
+            launch_pool.proceeds_settled(),
+            MyError::LaunchPoolNotSettled
+        );
+    }
+    // tokens still locked could never be withdrawn once the pool is gone
+    if !ctx.accounts.team_lock.data_is_empty() {
+        let team_lock =
+            TeamLock::try_deserialize(&mut &ctx.accounts.team_lock.try_borrow_data()?[..])?;
+        require!(
+            team_lock.withdrawn == team_lock.amount,
+            MyError::LaunchPoolNotSettled
+        );
+    }
+    if !ctx.accounts.lp_lock.data_is_empty() {
+        let lp_lock = LpLock::try_deserialize(&mut &ctx.accounts.lp_lock.try_borrow_data()?[..])?;
+        require!(lp_lock.amount == 0, MyError::LaunchPoolNotSettled);
+    }
+
+    let lp_key = launch_pool.key();
+
+    // whatever is left in the vault goes back to the creator along with the rent
+    let vault_lamports = ctx.accounts.vault.lamports();
+    if vault_lamports > 0 {
+        let vault_seeds = [
+            VAULT_SEED,
+            lp_key.as_ref(),
+            launch_pool.creator.as_ref(),
+            &[launch_pool.bumps.vault_bump],
+        ];
+
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+                &[&vault_seeds],
+            ),
+            vault_lamports,
+        )?;
+    }
+
+    let signer_seeds = [
+        TREASURER_SEED,
+        lp_key.as_ref(),
+        launch_pool.token_mint.as_ref(),
+        &[launch_pool.bumps.treasurer_bump],
+    ];
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.treasury.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.treasurer.to_account_info(),
+        },
+        &[&signer_seeds],
+    ))?;
+
+    // moved by hand after the CPIs, which only see the accounts passed to them
+    if !ctx.accounts.pool_metadata.data_is_empty() {
+        let pool_metadata = &ctx.accounts.pool_metadata;
+        let creator = &ctx.accounts.creator;
+        PoolMetadata::try_deserialize(&mut &pool_metadata.try_borrow_data()?[..])?;
+
+        **creator.try_borrow_mut_lamports()? = creator
+            .lamports()
+            .checked_add(pool_metadata.lamports())
+            .unwrap();
+        **pool_metadata.try_borrow_mut_lamports()? = 0;
+        pool_metadata.assign(&system_program::ID);
+        pool_metadata.realloc(0, false)?;
+    }
+
+    msg!("Launch pool closed");
+
+    emit_cpi!(CloseLaunchPoolEvent {
+        launch_pool: lp_key,
+        authority: *ctx.accounts.authority.key,
+        status: launch_pool.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,21 +1,95 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, system_program};
 use anchor_spl::token;
 
 use crate::{
+    amm::{AddLiquidityAccounts, ConstantProductAmm, LiquidityAdapter},
+    constants::{LIQUIDITY_ESCROW_SEED, LP_LOCK_SEED, TREASURER_SEED, VAULT_SEED},
     errors::MyError,
-    state::{LaunchPool, LaunchPoolState},
+    state::{LaunchPool, LaunchPoolState, LpLock, Treasurer},
 };
 
+#[event]
+pub struct CompleteLaunchPoolEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub vault_amount: u64,
+    pub sold_amount: u64,
+    pub liquidity_sol_amount: u64,
+    pub liquidity_token_amount: u64,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CompleteLaunchPool<'info> {
     #[account(mut)]
     pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(address = launch_pool.token_mint @ MyError::InvalidTokenMint)]
     pub token_mint: Box<Account<'info, token::Mint>>,
     #[account(mut)]
     pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [TREASURER_SEED, launch_pool.key().as_ref(), token_mint.key().as_ref()],
+        bump = launch_pool.bumps.treasurer_bump
+    )]
+    pub treasurer: Option<Box<Account<'info, Treasurer>>>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = treasurer
+    )]
+    pub treasury: Option<Box<Account<'info, token::TokenAccount>>>,
+    /// CHECK: The vault of the launch pool
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            launch_pool.key().as_ref(),
+            launch_pool.creator.as_ref()
+        ],
+        bump = launch_pool.bumps.vault_bump,
+    )]
+    pub vault: Option<UncheckedAccount<'info>>,
+    /// CHECK: The AMM program set when configuring liquidity
+    #[account(address = launch_pool.amm_program @ MyError::InvalidAmmPool)]
+    pub amm_program: Option<UncheckedAccount<'info>>,
+    /// CHECK: The AMM pool set when configuring liquidity
+    #[account(mut, address = launch_pool.amm_pool @ MyError::InvalidAmmPool)]
+    pub amm_pool: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated by the AMM program
+    #[account(mut)]
+    pub amm_lp_mint: Option<UncheckedAccount<'info>>,
+    /// CHECK: Validated by the AMM program
+    #[account(mut)]
+    pub amm_token_reserve: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, launch_pool.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Option<Box<Account<'info, LpLock>>>,
+    #[account(mut)]
+    pub lp_token_account: Option<Box<Account<'info, token::TokenAccount>>>,
+    /// CHECK: PDA that hands the AMM exactly the liquidity amounts, it holds no data
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_ESCROW_SEED, launch_pool.key().as_ref()],
+        bump
+    )]
+    pub liquidity_escrow: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = liquidity_escrow
+    )]
+    pub escrow_token_account: Option<Box<Account<'info, token::TokenAccount>>>,
+    pub system_program: Option<Program<'info, System>>,
+    pub token_program: Option<Program<'info, token::Token>>,
 }
 
-pub fn handler(ctx: Context<CompleteLaunchPool>) -> Result<()> {
+pub fn complete_launch_pool_handler(mut ctx: Context<CompleteLaunchPool>) -> Result<()> {
     let launch_pool = &mut ctx.accounts.launch_pool;
     require!(
         launch_pool.status == LaunchPoolState::Active,
@@ -27,8 +101,189 @@ pub fn handler(ctx: Context<CompleteLaunchPool>) -> Result<()> {
     );
 
     launch_pool.status = LaunchPoolState::Completed;
+    launch_pool.completed_at = Clock::get()?.unix_timestamp;
 
     msg!("Launch pool completed");
 
+    if launch_pool.seeds_liquidity() {
+        seed_liquidity(&mut ctx)?;
+    }
+
+    let launch_pool = &ctx.accounts.launch_pool;
+    emit_cpi!(CompleteLaunchPoolEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        vault_amount: launch_pool.vault_amount,
+        sold_amount: launch_pool.sold_amount(),
+        liquidity_sol_amount: launch_pool.liquidity_sol_amount,
+        liquidity_token_amount: launch_pool.liquidity_token_amount,
+        status: launch_pool.status,
+        timestamp: launch_pool.completed_at,
+    });
+
+    Ok(())
+}
+
+// the AMM is picked by the creator, so it only ever gets signed access to an escrow
+// holding the liquidity amounts, never to the vault or the treasury
+fn seed_liquidity(ctx: &mut Context<CompleteLaunchPool>) -> Result<()> {
+    let accounts = &mut ctx.accounts;
+    let launch_pool = &mut accounts.launch_pool;
+    let (sol_amount, token_amount) = launch_pool.calculate_liquidity_amounts()?;
+
+    let missing = || error!(MyError::LiquidityAccountsRequired);
+    let treasurer = accounts.treasurer.as_mut().ok_or_else(missing)?;
+    let treasury = accounts.treasury.as_ref().ok_or_else(missing)?;
+    let vault = accounts.vault.as_ref().ok_or_else(missing)?;
+    let lp_lock = accounts.lp_lock.as_mut().ok_or_else(missing)?;
+    let lp_token_account = accounts.lp_token_account.as_mut().ok_or_else(missing)?;
+    let liquidity_escrow = accounts.liquidity_escrow.as_ref().ok_or_else(missing)?;
+    let escrow_token_account = accounts.escrow_token_account.as_mut().ok_or_else(missing)?;
+    let system_program = accounts.system_program.as_ref().ok_or_else(missing)?;
+    let token_program = accounts.token_program.as_ref().ok_or_else(missing)?;
+    require_keys_eq!(
+        lp_token_account.key(),
+        lp_lock.lp_token_account,
+        MyError::InvalidLpLock
+    );
+
+    let lp_key = launch_pool.key();
+    let token_mint = launch_pool.token_mint;
+    let vault_seeds = [
+        VAULT_SEED,
+        lp_key.as_ref(),
+        launch_pool.creator.as_ref(),
+        &[launch_pool.bumps.vault_bump],
+    ];
+    let treasurer_seeds = [
+        TREASURER_SEED,
+        lp_key.as_ref(),
+        token_mint.as_ref(),
+        &[launch_pool.bumps.treasurer_bump],
+    ];
+    let escrow_seeds = [
+        LIQUIDITY_ESCROW_SEED,
+        lp_key.as_ref(),
+        &[ctx.bumps.liquidity_escrow],
+    ];
+
+    // anything sent to the escrow beforehand is swept along with what the AMM leaves
+    let escrow_lamports = liquidity_escrow.lamports();
+    let escrow_tokens = escrow_token_account.amount;
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: vault.to_account_info(),
+                to: liquidity_escrow.to_account_info(),
+            },
+            &[&vault_seeds],
+        ),
+        sol_amount,
+    )?;
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: treasury.to_account_info(),
+                to: escrow_token_account.to_account_info(),
+                authority: treasurer.to_account_info(),
+            },
+            &[&treasurer_seeds],
+        ),
+        token_amount,
+    )?;
+
+    ConstantProductAmm::add_liquidity(
+        AddLiquidityAccounts {
+            amm_program: accounts
+                .amm_program
+                .as_ref()
+                .ok_or_else(missing)?
+                .to_account_info(),
+            pool: accounts
+                .amm_pool
+                .as_ref()
+                .ok_or_else(missing)?
+                .to_account_info(),
+            lp_mint: accounts
+                .amm_lp_mint
+                .as_ref()
+                .ok_or_else(missing)?
+                .to_account_info(),
+            token_reserve: accounts
+                .amm_token_reserve
+                .as_ref()
+                .ok_or_else(missing)?
+                .to_account_info(),
+            sol_source: liquidity_escrow.to_account_info(),
+            token_source: escrow_token_account.to_account_info(),
+            token_authority: liquidity_escrow.to_account_info(),
+            lp_destination: lp_token_account.to_account_info(),
+            token_program: token_program.to_account_info(),
+            system_program: system_program.to_account_info(),
+        },
+        sol_amount,
+        token_amount,
+        &[&escrow_seeds],
+    )?;
+
+    // whatever the AMM did not take goes back, so only what it used counts as liquidity
+    escrow_token_account.reload()?;
+    let unused_tokens = escrow_token_account.amount;
+    if unused_tokens > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: escrow_token_account.to_account_info(),
+                    to: treasury.to_account_info(),
+                    authority: liquidity_escrow.to_account_info(),
+                },
+                &[&escrow_seeds],
+            ),
+            unused_tokens,
+        )?;
+    }
+    let unused_lamports = liquidity_escrow.lamports();
+    if unused_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: liquidity_escrow.to_account_info(),
+                    to: vault.to_account_info(),
+                },
+                &[&escrow_seeds],
+            ),
+            unused_lamports,
+        )?;
+    }
+    let sol_amount = sol_amount
+        .checked_add(escrow_lamports)
+        .unwrap()
+        .saturating_sub(unused_lamports);
+    let token_amount = token_amount
+        .checked_add(escrow_tokens)
+        .unwrap()
+        .saturating_sub(unused_tokens);
+
+    // the LP tokens minted by the AMM stay in the lock until its unlock date
+    lp_token_account.reload()?;
+    lp_lock.amount = lp_token_account.amount;
+
+    treasurer.amount = treasurer.amount.checked_sub(token_amount).unwrap();
+    launch_pool.liquidity_sol_amount = sol_amount;
+    launch_pool.liquidity_token_amount = token_amount;
+
+    msg!(
+        "Seeded liquidity with {} lamports and {} tokens, {} LP locked until {}",
+        sol_amount,
+        token_amount,
+        lp_lock.amount,
+        lp_lock.unlock_date
+    );
+
     Ok(())
 }
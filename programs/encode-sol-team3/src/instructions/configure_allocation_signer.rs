@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState},
+};
+
+#[event]
+pub struct ConfigureAllocationSignerEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub allocation_signer: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureAllocationSigner<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn configure_allocation_signer_handler(
+    ctx: Context<ConfigureAllocationSigner>,
+    allocation_signer: Option<Pubkey>,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Pending,
+        MyError::InvalidLaunchPoolStatus
+    );
+
+    launch_pool.allocation_signer = allocation_signer;
+
+    msg!("Launch pool allocation signer: {:?}", allocation_signer);
+
+    emit_cpi!(ConfigureAllocationSignerEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        allocation_signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token};
+
+use crate::{
+    constants::{BPS_DENOMINATOR, LIQUIDITY_ESCROW_SEED, LP_LOCK_SEED},
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, LpLock},
+};
+
+#[event]
+pub struct ConfigureLiquidityEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub amm_pool: Pubkey,
+    pub liquidity_bps: u16,
+    pub listing_rate: u64,
+    pub liquidity_token_reserve: u64,
+    pub lp_unlock_date: i64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureLiquidity<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(address = launch_pool.token_mint @ MyError::InvalidTokenMint)]
+    pub token_mint: Box<Account<'info, token::Mint>>,
+    /// CHECK: The AMM program the liquidity is seeded into
+    #[account(executable)]
+    pub amm_program: UncheckedAccount<'info>,
+    /// CHECK: The AMM pool, owned by the AMM program
+    #[account(owner = amm_program.key() @ MyError::InvalidAmmPool)]
+    pub amm_pool: UncheckedAccount<'info>,
+    pub lp_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        init,
+        seeds = [LP_LOCK_SEED, launch_pool.key().as_ref()],
+        bump,
+        payer = authority,
+        space = LpLock::LEN
+    )]
+    pub lp_lock: Box<Account<'info, LpLock>>,
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = lp_mint,
+        associated_token::authority = lp_lock
+    )]
+    pub lp_lock_token_account: Box<Account<'info, token::TokenAccount>>,
+    /// CHECK: PDA that hands the AMM exactly the liquidity amounts, it holds no data
+    #[account(
+        seeds = [LIQUIDITY_ESCROW_SEED, launch_pool.key().as_ref()],
+        bump
+    )]
+    pub liquidity_escrow: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = token_mint,
+        associated_token::authority = liquidity_escrow
+    )]
+    pub escrow_token_account: Box<Account<'info, token::TokenAccount>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, token::Token>,
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn configure_liquidity_handler(
+    ctx: Context<ConfigureLiquidity>,
+    liquidity_bps: u16,
+    listing_rate: u64,
+    liquidity_token_reserve: u64,
+    lp_unlock_date: i64,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let lp_lock = &mut ctx.accounts.lp_lock;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Pending,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(
+        liquidity_bps > 0 && (liquidity_bps as u64).le(&BPS_DENOMINATOR),
+        MyError::InvalidBasisPoints
+    );
+    require!(
+        listing_rate > 0 && liquidity_token_reserve > 0,
+        MyError::InvalidAmount
+    );
+    require!(
+        lp_unlock_date > launch_pool.unlock_date,
+        MyError::InvalidUnlockDate
+    );
+
+    launch_pool.liquidity_bps = liquidity_bps;
+    launch_pool.listing_rate = listing_rate;
+    launch_pool.liquidity_token_reserve = liquidity_token_reserve;
+    launch_pool.amm_program = ctx.accounts.amm_program.key();
+    launch_pool.amm_pool = ctx.accounts.amm_pool.key();
+
+    lp_lock.launch_pool = launch_pool.key();
+    lp_lock.lp_mint = ctx.accounts.lp_mint.key();
+    lp_lock.lp_token_account = ctx.accounts.lp_lock_token_account.key();
+    lp_lock.amount = 0;
+    lp_lock.unlock_date = lp_unlock_date;
+    lp_lock.bump = ctx.bumps.lp_lock;
+
+    msg!(
+        "Launch pool seeds {} bps of proceeds into AMM pool {} at rate {}, LP locked until {}",
+        liquidity_bps,
+        launch_pool.amm_pool,
+        listing_rate,
+        lp_unlock_date
+    );
+
+    emit_cpi!(ConfigureLiquidityEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        amm_pool: launch_pool.amm_pool,
+        liquidity_bps,
+        listing_rate,
+        liquidity_token_reserve,
+        lp_unlock_date,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, NftGate},
+};
+
+#[event]
+pub struct ConfigureNftGateEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub creators: Vec<Pubkey>,
+    pub single_use: bool,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureNftGate<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn configure_nft_gate_handler(
+    ctx: Context<ConfigureNftGate>,
+    collection: Option<Pubkey>,
+    creators: Vec<Pubkey>,
+    single_use: bool,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Pending,
+        MyError::InvalidLaunchPoolStatus
+    );
+
+    launch_pool.nft_gate = NftGate::new(collection, creators, single_use)?;
+
+    msg!(
+        "Launch pool gated by collection {:?} and {} creators",
+        launch_pool.nft_gate.collection,
+        launch_pool.nft_gate.creators.len()
+    );
+
+    emit_cpi!(ConfigureNftGateEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        collection: launch_pool.nft_gate.collection,
+        creators: launch_pool.nft_gate.creators.clone(),
+        single_use: launch_pool.nft_gate.single_use,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::STAKE_TIERS,
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState},
+};
+
+#[event]
+pub struct ConfigureTierAllocationsEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub tier_caps: [u64; STAKE_TIERS],
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureTierAllocations<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn configure_tier_allocations_handler(
+    ctx: Context<ConfigureTierAllocations>,
+    tier_caps: [u64; STAKE_TIERS],
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Pending,
+        MyError::InvalidLaunchPoolStatus
+    );
+    for i in 1..STAKE_TIERS {
+        require!(tier_caps[i] >= tier_caps[i - 1], MyError::InvalidStakeTiers);
+    }
+    require!(
+        tier_caps[0] >= launch_pool.minimum_token_amount,
+        MyError::InvalidStakeTiers
+    );
+
+    launch_pool.is_tiered = true;
+    launch_pool.tier_caps = tier_caps;
+
+    msg!("Launch pool tier caps: {:?}", tier_caps);
+
+    emit_cpi!(ConfigureTierAllocationsEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        tier_caps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,15 +1,41 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{associated_token, token};
 
-use crate::constants::{LAUNCH_POOL_SEED, TREASURER_SEED};
+use crate::constants::{CREATOR_REGISTRY_SEED, LAUNCH_POOL_SEED, TREASURER_SEED, VAULT_SEED};
 use crate::errors::MyError;
-use crate::state::{CurrencyType, LaunchPool, LaunchPoolType, Treasurer};
+use crate::state::{
+    CreatorRegistry, CurrencyType, LaunchPool, LaunchPoolBumps, LaunchPoolState, LaunchPoolType,
+    ProceedsVesting, Treasurer, UnsoldPolicy,
+};
+#[event]
+pub struct CreateLaunchPoolEvent {
+    pub launch_pool: Pubkey,
+    pub creator: Pubkey,
+    pub token_mint: Pubkey,
+    pub index: u64,
+    pub pool_size: u64,
+    pub rate: u64,
+    pub unlock_date: i64,
+    pub unsold_policy: Option<UnsoldPolicy>,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateFairlaunchPool<'info> {
+    #[account(
+        init_if_needed,
+        seeds = [CREATOR_REGISTRY_SEED, authority.key().as_ref()],
+        bump,
+        payer = authority,
+        space = CreatorRegistry::LEN
+    )]
+    pub creator_registry: Box<Account<'info, CreatorRegistry>>,
     #[
         account(
             init,
-            seeds = [LAUNCH_POOL_SEED.as_ref(), authority.key().as_ref(), token_mint.key().as_ref()],
+            seeds = [LAUNCH_POOL_SEED, authority.key().as_ref(), &creator_registry.pool_count.to_le_bytes()],
             bump,
             payer = authority,
             space = LaunchPool::LEN
@@ -20,7 +46,7 @@ pub struct CreateFairlaunchPool<'info> {
     #[
         account(
             init,
-            seeds = [TREASURER_SEED.as_ref(), launch_pool.key().as_ref(), token_mint.key().as_ref()],
+            seeds = [TREASURER_SEED, launch_pool.key().as_ref(), token_mint.key().as_ref()],
             bump ,
             payer = authority,
             space = Treasurer::LEN
@@ -42,7 +68,8 @@ pub struct CreateFairlaunchPool<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(
+#[allow(clippy::too_many_arguments)]
+pub fn create_fairlaunch_pool_handler(
     ctx: Context<CreateFairlaunchPool>,
     unlock_date: i64,
     pool_size: u64,
@@ -50,6 +77,8 @@ pub fn handler(
     maximum_token_amount: u64,
     rate: u64,
     token_mint_decimals: u8,
+    proceeds_vesting: Option<ProceedsVesting>,
+    unsold_policy: Option<UnsoldPolicy>,
 ) -> Result<()> {
     let launch_pool = &mut ctx.accounts.launch_pool;
     let treasurer = &mut ctx.accounts.treasurer;
@@ -67,7 +96,7 @@ pub fn handler(
         *token_mint.to_account_info().key,
     );
 
-    Ok(launch_pool.initialize(
+    launch_pool.initialize(
         unlock_date,
         pool_size,
         minimum_token_amount,
@@ -78,5 +107,54 @@ pub fn handler(
         *authority.key,
         CurrencyType::SOL,
         LaunchPoolType::FairLaunch,
-    )?)
+    )?;
+
+    // proceeds vesting is disclosed up front and can never be changed afterwards
+    if let Some(vesting) = &proceeds_vesting {
+        vesting.validate()?;
+    }
+    launch_pool.proceeds_vesting = proceeds_vesting;
+    launch_pool.unsold_policy = unsold_policy;
+
+    // the vault is only ever signed for, so its bump is found once here and stored
+    let (_, vault_bump) = Pubkey::find_program_address(
+        &[
+            VAULT_SEED,
+            launch_pool.key().as_ref(),
+            authority.key.as_ref(),
+        ],
+        ctx.program_id,
+    );
+    launch_pool.bumps = LaunchPoolBumps {
+        launchpool_bump: ctx.bumps.launch_pool,
+        treasurer_bump: ctx.bumps.treasurer,
+        vault_bump,
+    };
+
+    // every pool gets the next index, so a creator can launch again with the same mint
+    let creator_registry = &mut ctx.accounts.creator_registry;
+    creator_registry.creator = *authority.key;
+    launch_pool.index = creator_registry.pool_count;
+    creator_registry.pool_count = creator_registry.pool_count.checked_add(1).unwrap();
+
+    msg!(
+        "Launch pool {} created by {}",
+        launch_pool.index,
+        authority.key
+    );
+
+    emit_cpi!(CreateLaunchPoolEvent {
+        launch_pool: launch_pool.key(),
+        creator: *authority.key,
+        token_mint: launch_pool.token_mint,
+        index: launch_pool.index,
+        pool_size,
+        rate,
+        unlock_date,
+        unsold_policy: launch_pool.unsold_policy,
+        status: launch_pool.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
 }
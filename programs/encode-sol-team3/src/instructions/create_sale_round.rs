@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{MAX_SALE_ROUNDS, SALE_ROUND_SEED},
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, SaleRound, SaleRoundAccess},
+};
+
+#[event]
+pub struct CreateSaleRoundEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub index: u8,
+    pub rate: u64,
+    pub allocation: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub access: SaleRoundAccess,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct CreateSaleRound<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        init,
+        seeds = [SALE_ROUND_SEED, launch_pool.key().as_ref(), &[index]],
+        bump,
+        payer = authority,
+        space = SaleRound::LEN
+    )]
+    pub sale_round: Box<Account<'info, SaleRound>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_sale_round_handler(
+    ctx: Context<CreateSaleRound>,
+    index: u8,
+    rate: u64,
+    minimum_token_amount: u64,
+    maximum_token_amount: u64,
+    start_time: i64,
+    end_time: i64,
+    allocation: u64,
+    access: SaleRoundAccess,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let sale_round = &mut ctx.accounts.sale_round;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Pending,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(
+        (launch_pool.round_count as usize) < MAX_SALE_ROUNDS,
+        MyError::TooManySaleRounds
+    );
+    require!(index == launch_pool.round_count, MyError::InvalidSaleRound);
+    require!(
+        end_time > Clock::get()?.unix_timestamp,
+        MyError::InvalidSaleRoundTime
+    );
+
+    let rounds_allocation = launch_pool
+        .rounds_allocation
+        .checked_add(allocation)
+        .ok_or(MyError::Overflow)?;
+    require!(
+        rounds_allocation.le(&launch_pool.pool_size),
+        MyError::SaleRoundAllocationExceeded
+    );
+
+    sale_round.initialize(
+        launch_pool.key(),
+        index,
+        rate,
+        minimum_token_amount,
+        maximum_token_amount,
+        start_time,
+        end_time,
+        allocation,
+        access,
+    )?;
+
+    launch_pool.round_count += 1;
+    launch_pool.rounds_allocation = rounds_allocation;
+
+    msg!(
+        "Sale round {} created with {} tokens at rate {}",
+        index,
+        allocation,
+        rate
+    );
+
+    emit_cpi!(CreateSaleRoundEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        index,
+        rate,
+        allocation,
+        start_time,
+        end_time,
+        access,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+use crate::{
+    constants::TREASURER_SEED,
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, Treasurer, UnsoldPolicy},
+};
+
+#[event]
+pub struct FinalizeUnsoldTokensEvent {
+    pub launch_pool: Pubkey,
+    pub unsold_policy: UnsoldPolicy,
+    pub amount: u64,
+    pub treasurer_amount: u64,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FinalizeUnsoldTokens<'info> {
+    #[account(mut)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(mut, address = launch_pool.token_mint @ MyError::InvalidTokenMint)]
+    pub token_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        mut,
+        seeds = [TREASURER_SEED, launch_pool.key().as_ref(), token_mint.key().as_ref()],
+        bump = launch_pool.bumps.treasurer_bump
+    )]
+    pub treasurer: Box<Account<'info, Treasurer>>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = treasurer,
+    )]
+    pub treasury: Box<Account<'info, token::TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = launch_pool.authority,
+    )]
+    pub authority_token_account: Option<Box<Account<'info, token::TokenAccount>>>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = launch_pool.amm_pool,
+    )]
+    pub amm_token_reserve: Option<Box<Account<'info, token::TokenAccount>>>,
+    pub token_program: Program<'info, token::Token>,
+}
+
+// anyone can finalize, the destination is fixed by the policy chosen at creation
+pub fn finalize_unsold_tokens_handler(ctx: Context<FinalizeUnsoldTokens>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let treasurer = &mut ctx.accounts.treasurer;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Completed,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(
+        !launch_pool.unsold_withdrawn,
+        MyError::UnsoldTokensWithdrawn
+    );
+
+    let unsold_policy = launch_pool
+        .unsold_policy
+        .ok_or(MyError::UnsoldPolicyNotDeclared)?;
+
+    let destination = match unsold_policy {
+        UnsoldPolicy::Burn => None,
+        UnsoldPolicy::RefundToCreator => Some(
+            ctx.accounts
+                .authority_token_account
+                .as_deref()
+                .ok_or(MyError::TokenAccountRequired)?,
+        ),
+        UnsoldPolicy::SendToLiquidity => Some(
+            ctx.accounts
+                .amm_token_reserve
+                .as_deref()
+                .ok_or(MyError::TokenAccountRequired)?,
+        ),
+    };
+    let amount = release_unsold_tokens(
+        launch_pool,
+        treasurer,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.treasury,
+        destination,
+        &ctx.accounts.token_program,
+    )?;
+
+    msg!(
+        "Unsold tokens finalized with {:?}: {}, {} left for buyers",
+        unsold_policy,
+        amount,
+        treasurer.amount
+    );
+
+    emit_cpi!(FinalizeUnsoldTokensEvent {
+        launch_pool: launch_pool.key(),
+        unsold_policy,
+        amount,
+        treasurer_amount: treasurer.amount,
+        status: launch_pool.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// moves the tokens no buyer can claim out of the treasury to destination, burning them
+// when there is none, and returns the amount released
+pub fn release_unsold_tokens<'info>(
+    launch_pool: &mut Account<'info, LaunchPool>,
+    treasurer: &mut Account<'info, Treasurer>,
+    token_mint: &Account<'info, token::Mint>,
+    treasury: &Account<'info, token::TokenAccount>,
+    destination: Option<&Account<'info, token::TokenAccount>>,
+    token_program: &Program<'info, token::Token>,
+) -> Result<u64> {
+    // only what no buyer can claim leaves the treasury, outstanding claims stay covered
+    let amount = launch_pool.unsold_amount();
+    require!(amount > 0, MyError::InvalidAmount);
+
+    let lp_key = launch_pool.key();
+    let token_mint_key = launch_pool.token_mint;
+    let signer_seeds = [
+        TREASURER_SEED,
+        lp_key.as_ref(),
+        token_mint_key.as_ref(),
+        &[launch_pool.bumps.treasurer_bump],
+    ];
+
+    match destination {
+        Some(destination) => token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: treasury.to_account_info(),
+                    to: destination.to_account_info(),
+                    authority: treasurer.to_account_info(),
+                },
+                &[&signer_seeds],
+            ),
+            amount,
+        )?,
+        None => token::burn(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                token::Burn {
+                    mint: token_mint.to_account_info(),
+                    from: treasury.to_account_info(),
+                    authority: treasurer.to_account_info(),
+                },
+                &[&signer_seeds],
+            ),
+            amount,
+        )?,
+    }
+
+    treasurer.amount = treasurer.amount.checked_sub(amount).unwrap();
+    launch_pool.unsold_withdrawn = true;
+
+    Ok(amount)
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+use crate::{
+    constants::LP_LOCK_SEED,
+    errors::MyError,
+    state::{LaunchPool, LpLock},
+};
+
+#[event]
+pub struct WithdrawLpEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExtendLpLockEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub unlock_date: i64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawLp<'info> {
+    #[account(has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, launch_pool.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Box<Account<'info, LpLock>>,
+    #[account(mut, address = lp_lock.lp_token_account @ MyError::InvalidLpLock)]
+    pub lp_lock_token_account: Box<Account<'info, token::TokenAccount>>,
+    #[account(mut, token::mint = lp_lock.lp_mint)]
+    pub destination: Box<Account<'info, token::TokenAccount>>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, token::Token>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExtendLpLock<'info> {
+    #[account(has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, launch_pool.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Box<Account<'info, LpLock>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn withdraw_lp_handler(ctx: Context<WithdrawLp>) -> Result<()> {
+    let lp_lock = &mut ctx.accounts.lp_lock;
+
+    require!(
+        lp_lock.unlock_date <= Clock::get()?.unix_timestamp,
+        MyError::TimeLockNotExpired
+    );
+
+    let amount = ctx.accounts.lp_lock_token_account.amount;
+    require!(amount > 0, MyError::InvalidAmount);
+
+    let lp_key = ctx.accounts.launch_pool.key();
+    let signer_seeds = [LP_LOCK_SEED, lp_key.as_ref(), &[lp_lock.bump]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.lp_lock_token_account.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: lp_lock.to_account_info(),
+            },
+            &[&signer_seeds],
+        ),
+        amount,
+    )?;
+
+    lp_lock.amount = 0;
+
+    msg!("LP tokens withdrawn: {}", amount);
+
+    emit_cpi!(WithdrawLpEvent {
+        launch_pool: lp_key,
+        authority: *ctx.accounts.authority.key,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn extend_handler(ctx: Context<ExtendLpLock>, unlock_date: i64) -> Result<()> {
+    let lp_lock = &mut ctx.accounts.lp_lock;
+
+    // a lock can only ever be pushed later
+    require!(
+        unlock_date > lp_lock.unlock_date,
+        MyError::InvalidUnlockDate
+    );
+
+    lp_lock.unlock_date = unlock_date;
+
+    msg!("LP lock extended until {}", unlock_date);
+
+    emit_cpi!(ExtendLpLockEvent {
+        launch_pool: ctx.accounts.launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        unlock_date,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,199 @@
+use anchor_lang::{error::ErrorCode, prelude::*, system_program, Discriminator};
+
+use crate::{
+    constants::{LAUNCH_POOL_SEED, TREASURER_SEED, USER_POOL_SEED, VAULT_SEED},
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolBumps, Treasurer, UserPool},
+};
+
+#[event]
+pub struct LaunchPoolMigratedEvent {
+    pub launch_pool: Pubkey,
+    pub payer: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub user_pool_count: u32,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateLaunchPool<'info> {
+    /// CHECK: An older layout does not deserialize, the owner and discriminator are checked in the handler
+    #[account(mut)]
+    pub launch_pool: UncheckedAccount<'info>,
+    /// CHECK: Checked in the handler to be the treasurer of the launch pool
+    #[account(mut)]
+    pub treasurer: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+// each buyer and their user pool are passed as a pair of remaining accounts, the user pools
+// writable, migrating only appends fields so anyone can pay for it
+pub fn migrate_launch_pool_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateLaunchPool<'info>>,
+) -> Result<()> {
+    let payer = &ctx.accounts.payer;
+    let system_program = &ctx.accounts.system_program;
+    let launch_pool_info = ctx.accounts.launch_pool.to_account_info();
+    let treasurer_info = ctx.accounts.treasurer.to_account_info();
+
+    let mut launch_pool: LaunchPool =
+        realloc_account(&launch_pool_info, payer, system_program, LaunchPool::LEN)?;
+    let from_version = launch_pool.version;
+    let bumps = if from_version == 0 {
+        first_layout_bumps(&launch_pool_info.key(), &launch_pool)?
+    } else {
+        launch_pool.bumps
+    };
+    launch_pool.migrate(bumps);
+
+    let mut treasurer: Treasurer =
+        realloc_account(&treasurer_info, payer, system_program, Treasurer::LEN)?;
+    require_keys_eq!(
+        treasurer.launch_pool,
+        launch_pool_info.key(),
+        MyError::InvalidTreasurer
+    );
+    treasurer.migrate();
+    write_account(&treasurer_info, &treasurer)?;
+
+    let user_accounts = ctx.remaining_accounts.chunks_exact(2);
+    require!(
+        user_accounts.remainder().is_empty(),
+        ErrorCode::AccountNotEnoughKeys
+    );
+    for accounts in user_accounts {
+        let (user_info, user_pool_info) = (&accounts[0], &accounts[1]);
+        let (user_pool_address, _) = Pubkey::find_program_address(
+            &[
+                USER_POOL_SEED,
+                user_info.key.as_ref(),
+                launch_pool_info.key.as_ref(),
+                launch_pool.token_mint.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            user_pool_address,
+            user_pool_info.key(),
+            MyError::InvalidUserPool
+        );
+
+        let mut user_pool: UserPool =
+            realloc_account(user_pool_info, payer, system_program, UserPool::LEN)?;
+        // the first layout did not count positions or claims, each one is counted as its user pool migrates
+        if user_pool.version == 0 {
+            launch_pool.open_positions = launch_pool.open_positions.checked_add(1).unwrap();
+            if user_pool.claimed > 0 {
+                launch_pool.claimed_sold_amount = launch_pool
+                    .claimed_sold_amount
+                    .checked_add(user_pool.amount)
+                    .unwrap();
+            }
+        }
+        user_pool.migrate();
+        write_account(user_pool_info, &user_pool)?;
+    }
+
+    write_account(&launch_pool_info, &launch_pool)?;
+
+    let user_pool_count = (ctx.remaining_accounts.len() / 2) as u32;
+    msg!(
+        "Launch pool {} migrated from version {} to {} with {} user pools",
+        launch_pool_info.key(),
+        from_version,
+        LaunchPool::VERSION,
+        user_pool_count
+    );
+
+    emit_cpi!(LaunchPoolMigratedEvent {
+        launch_pool: launch_pool_info.key(),
+        payer: ctx.accounts.payer.key(),
+        from_version,
+        to_version: LaunchPool::VERSION,
+        user_pool_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// pools of the first layout were derived from their authority and mint rather than creator and index
+fn first_layout_bumps(address: &Pubkey, launch_pool: &LaunchPool) -> Result<LaunchPoolBumps> {
+    let (launch_pool_address, launchpool_bump) = Pubkey::find_program_address(
+        &[
+            LAUNCH_POOL_SEED,
+            launch_pool.authority.as_ref(),
+            launch_pool.token_mint.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(launch_pool_address, *address, MyError::InvalidLaunchPool);
+
+    let (_, treasurer_bump) = Pubkey::find_program_address(
+        &[
+            TREASURER_SEED,
+            address.as_ref(),
+            launch_pool.token_mint.as_ref(),
+        ],
+        &crate::ID,
+    );
+    let (_, vault_bump) = Pubkey::find_program_address(
+        &[VAULT_SEED, address.as_ref(), launch_pool.authority.as_ref()],
+        &crate::ID,
+    );
+
+    Ok(LaunchPoolBumps {
+        launchpool_bump,
+        treasurer_bump,
+        vault_bump,
+    })
+}
+
+// grows an account written by an older program to len, topping its rent up from the payer,
+// and reads it back with the appended fields zeroed
+fn realloc_account<'info, T: AccountDeserialize + Discriminator>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    len: usize,
+) -> Result<T> {
+    require_keys_eq!(
+        *account.owner,
+        crate::ID,
+        ErrorCode::AccountOwnedByWrongProgram
+    );
+    require!(account.is_writable, ErrorCode::AccountNotMutable);
+    require!(
+        account.try_borrow_data()?.starts_with(&T::DISCRIMINATOR),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    let rent = Rent::get()?.minimum_balance(len);
+    if rent > account.lamports() {
+        let cpi_context = CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            },
+        );
+        system_program::transfer(cpi_context, rent - account.lamports())?;
+    }
+    if account.data_len() < len {
+        account.realloc(len, true)?;
+    }
+
+    let data = account.try_borrow_data()?;
+    let value = T::try_deserialize(&mut &data[..])?;
+    Ok(value)
+}
+
+fn write_account<T: AccountSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let mut data = account.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    value.try_serialize(&mut writer)
+}
@@ -0,0 +1,446 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    constants::{BPS_DENOMINATOR, MILESTONE_SEED, USER_POOL_SEED, VAULT_SEED, VOTE_SEED},
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, Milestone, MilestoneState, UserPool, Vote},
+};
+
+#[event]
+pub struct ConfigureGovernanceEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub quorum_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposeMilestoneEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub deadline: i64,
+    pub status: MilestoneState,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteMilestoneEvent {
+    pub launch_pool: Pubkey,
+    pub voter: Pubkey,
+    pub index: u8,
+    pub weight: u64,
+    pub approve: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExecuteMilestoneEvent {
+    pub launch_pool: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub status: MilestoneState,
+    pub refundable_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReclaimFundsEvent {
+    pub launch_pool: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureGovernance<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeMilestone<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        init,
+        seeds = [MILESTONE_SEED, launch_pool.key().as_ref(), &[launch_pool.milestone_count]],
+        bump,
+        payer = authority,
+        space = Milestone::LEN
+    )]
+    pub milestone: Box<Account<'info, Milestone>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VoteMilestone<'info> {
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, launch_pool.key().as_ref(), &[milestone.index]],
+        bump,
+    )]
+    pub milestone: Box<Account<'info, Milestone>>,
+    #[account(
+        seeds = [USER_POOL_SEED, voter.key().as_ref(), launch_pool.key().as_ref(), launch_pool.token_mint.as_ref()],
+        bump,
+    )]
+    pub user_pool: Box<Account<'info, UserPool>>,
+    #[account(
+        init,
+        seeds = [VOTE_SEED, milestone.key().as_ref(), voter.key().as_ref()],
+        bump,
+        payer = voter,
+        space = Vote::LEN
+    )]
+    pub vote: Box<Account<'info, Vote>>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteMilestone<'info> {
+    #[account(mut)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, launch_pool.key().as_ref(), &[milestone.index]],
+        bump,
+    )]
+    pub milestone: Box<Account<'info, Milestone>>,
+    /// CHECK: The vault of the launch pool
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            launch_pool.key().as_ref(),
+            launch_pool.creator.as_ref()
+        ],
+        bump = launch_pool.bumps.vault_bump,
+    )]
+    pub vault: AccountInfo<'info>,
+    /// CHECK: Receives the released funds, must be the pool authority
+    #[account(mut, address = launch_pool.authority @ MyError::InvalidAuthority)]
+    pub authority: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReclaimFunds<'info> {
+    #[account(mut)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [USER_POOL_SEED, user.key().as_ref(), launch_pool.key().as_ref(), launch_pool.token_mint.as_ref()],
+        bump,
+    )]
+    pub user_pool: Box<Account<'info, UserPool>>,
+    /// CHECK: The vault of the launch pool
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            launch_pool.key().as_ref(),
+            launch_pool.creator.as_ref()
+        ],
+        bump = launch_pool.bumps.vault_bump,
+    )]
+    pub vault: AccountInfo<'info>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn configure_governance_handler(
+    ctx: Context<ConfigureGovernance>,
+    quorum_bps: u16,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Pending,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(
+        quorum_bps > 0 && (quorum_bps as u64).le(&BPS_DENOMINATOR),
+        MyError::InvalidBasisPoints
+    );
+    // milestones replace the disclosed vesting schedule, a pool cannot use both
+    require!(
+        launch_pool.proceeds_vesting.is_none(),
+        MyError::InvalidVestingPlan
+    );
+
+    launch_pool.is_governed = true;
+    launch_pool.quorum_bps = quorum_bps;
+
+    msg!(
+        "Launch pool releases funds by milestones, quorum {} bps",
+        quorum_bps
+    );
+
+    emit_cpi!(ConfigureGovernanceEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        quorum_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn propose_milestone_handler(
+    ctx: Context<ProposeMilestone>,
+    amount: u64,
+    deadline: i64,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let milestone = &mut ctx.accounts.milestone;
+
+    require!(launch_pool.is_governed, MyError::LaunchPoolNotGoverned);
+    require!(
+        launch_pool.status == LaunchPoolState::Completed,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(!launch_pool.is_refunding, MyError::InvalidLaunchPoolStatus);
+    // one milestone is voted on at a time
+    require!(
+        !launch_pool.has_open_milestone,
+        MyError::MilestoneVotingOpen
+    );
+    require!(
+        amount > 0 && amount <= launch_pool.unreleased_proceeds(),
+        MyError::InvalidAmount
+    );
+    require!(
+        deadline > Clock::get()?.unix_timestamp,
+        MyError::InvalidMilestone
+    );
+
+    milestone.launch_pool = launch_pool.key();
+    milestone.index = launch_pool.milestone_count;
+    milestone.amount = amount;
+    milestone.deadline = deadline;
+    milestone.votes_for = 0;
+    milestone.votes_against = 0;
+    milestone.status = MilestoneState::Voting;
+
+    launch_pool.milestone_count = launch_pool.milestone_count.checked_add(1).unwrap();
+    launch_pool.has_open_milestone = true;
+
+    msg!(
+        "Milestone {} proposed: {} lamports, voting until {}",
+        milestone.index,
+        amount,
+        deadline
+    );
+
+    emit_cpi!(ProposeMilestoneEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        index: milestone.index,
+        amount,
+        deadline,
+        status: milestone.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn vote_handler(ctx: Context<VoteMilestone>, approve: bool) -> Result<()> {
+    let milestone = &mut ctx.accounts.milestone;
+    let vote = &mut ctx.accounts.vote;
+    let weight = ctx.accounts.user_pool.amount;
+
+    require!(
+        milestone.is_voting(Clock::get()?.unix_timestamp),
+        MyError::MilestoneVotingClosed
+    );
+    require!(weight > 0, MyError::InvalidAmount);
+
+    if approve {
+        milestone.votes_for = milestone.votes_for.checked_add(weight).unwrap();
+    } else {
+        milestone.votes_against = milestone.votes_against.checked_add(weight).unwrap();
+    }
+
+    vote.milestone = milestone.key();
+    vote.voter = ctx.accounts.voter.key();
+    vote.weight = weight;
+    vote.approve = approve;
+
+    msg!(
+        "Milestone {} votes: {} for, {} against",
+        milestone.index,
+        milestone.votes_for,
+        milestone.votes_against
+    );
+
+    emit_cpi!(VoteMilestoneEvent {
+        launch_pool: ctx.accounts.launch_pool.key(),
+        voter: vote.voter,
+        index: milestone.index,
+        weight,
+        approve,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn execute_handler(ctx: Context<ExecuteMilestone>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let milestone = &mut ctx.accounts.milestone;
+
+    require!(
+        milestone.status == MilestoneState::Voting,
+        MyError::InvalidMilestone
+    );
+    require!(
+        milestone.deadline <= Clock::get()?.unix_timestamp,
+        MyError::MilestoneVotingOpen
+    );
+
+    launch_pool.has_open_milestone = false;
+
+    if !milestone.is_approved(launch_pool.sold_amount(), launch_pool.quorum_bps) {
+        // a failed vote hands what is left in the vault back to the buyers
+        milestone.status = MilestoneState::Rejected;
+        launch_pool.is_refunding = true;
+        launch_pool.refundable_amount = launch_pool.unreleased_proceeds();
+        launch_pool.refund_sold_amount = launch_pool
+            .sold_amount()
+            .checked_sub(launch_pool.claimed_sold_amount)
+            .unwrap();
+
+        msg!(
+            "Milestone {} rejected, {} lamports refundable",
+            milestone.index,
+            launch_pool.refundable_amount
+        );
+
+        emit_cpi!(ExecuteMilestoneEvent {
+            launch_pool: launch_pool.key(),
+            index: milestone.index,
+            amount: 0,
+            votes_for: milestone.votes_for,
+            votes_against: milestone.votes_against,
+            status: milestone.status,
+            refundable_amount: launch_pool.refundable_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        return Ok(());
+    }
+
+    let lp_key = launch_pool.key();
+    let signer_seeds = [
+        VAULT_SEED,
+        lp_key.as_ref(),
+        launch_pool.creator.as_ref(),
+        &[launch_pool.bumps.vault_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.authority.to_account_info(),
+            },
+            &[&signer_seeds],
+        ),
+        milestone.amount,
+    )?;
+
+    milestone.status = MilestoneState::Executed;
+    launch_pool.milestone_released = launch_pool
+        .milestone_released
+        .checked_add(milestone.amount)
+        .unwrap();
+
+    msg!(
+        "Milestone {} executed, {} lamports released",
+        milestone.index,
+        launch_pool.milestone_released
+    );
+
+    emit_cpi!(ExecuteMilestoneEvent {
+        launch_pool: lp_key,
+        index: milestone.index,
+        amount: milestone.amount,
+        votes_for: milestone.votes_for,
+        votes_against: milestone.votes_against,
+        status: milestone.status,
+        refundable_amount: 0,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn reclaim_handler(ctx: Context<ReclaimFunds>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let user_pool = &mut ctx.accounts.user_pool;
+
+    require!(launch_pool.is_refunding, MyError::RefundNotAvailable);
+    require!(user_pool.refunded == 0, MyError::AlreadyRefunded);
+    // buyers who took their tokens keep them instead of a refund
+    require!(user_pool.claimed == 0, MyError::AlreadyClaimed);
+
+    let amount = launch_pool.refund_for(user_pool.amount);
+
+    require!(amount > 0, MyError::InvalidAmount);
+
+    let lp_key = launch_pool.key();
+    let signer_seeds = [
+        VAULT_SEED,
+        lp_key.as_ref(),
+        launch_pool.creator.as_ref(),
+        &[launch_pool.bumps.vault_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.user.to_account_info(),
+            },
+            &[&signer_seeds],
+        ),
+        amount,
+    )?;
+
+    user_pool.refunded = amount;
+    launch_pool.refunded_sold_amount = launch_pool
+        .refunded_sold_amount
+        .checked_add(user_pool.amount)
+        .unwrap();
+    launch_pool.refunded_total = launch_pool.refunded_total.checked_add(amount).unwrap();
+
+    msg!("User refunded: {}", amount);
+
+    emit_cpi!(ReclaimFundsEvent {
+        launch_pool: lp_key,
+        user: *ctx.accounts.user.key,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
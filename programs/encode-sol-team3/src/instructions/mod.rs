@@ -12,3 +12,69 @@ pub use complete_launch_pool::*;
 
 pub mod claim_token;
 pub use claim_token::*;
+
+pub mod create_sale_round;
+pub use create_sale_round::*;
+
+pub mod update_whitelist;
+pub use update_whitelist::*;
+
+pub mod buy_token_in_round;
+pub use buy_token_in_round::*;
+
+pub mod advance_sale_round;
+pub use advance_sale_round::*;
+
+pub mod staking;
+pub use staking::*;
+
+pub mod configure_tier_allocations;
+pub use configure_tier_allocations::*;
+
+pub mod configure_nft_gate;
+pub use configure_nft_gate::*;
+
+pub mod configure_allocation_signer;
+pub use configure_allocation_signer::*;
+
+pub mod referral;
+pub use referral::*;
+
+pub mod configure_liquidity;
+pub use configure_liquidity::*;
+
+pub mod lp_lock;
+pub use lp_lock::*;
+
+pub mod team_lock;
+pub use team_lock::*;
+
+pub mod withdraw_proceeds;
+pub use withdraw_proceeds::*;
+
+pub mod milestone;
+pub use milestone::*;
+
+pub mod transfer_authority;
+pub use transfer_authority::*;
+
+pub mod close_accounts;
+pub use close_accounts::*;
+
+pub mod finalize_unsold_tokens;
+pub use finalize_unsold_tokens::*;
+
+pub mod withdraw_unsold_tokens;
+pub use withdraw_unsold_tokens::*;
+
+pub mod migrate_launch_pool;
+pub use migrate_launch_pool::*;
+
+pub mod update_pool_metadata;
+pub use update_pool_metadata::*;
+
+pub mod token_mint;
+pub use token_mint::*;
+
+pub mod purchase;
+pub use purchase::*;
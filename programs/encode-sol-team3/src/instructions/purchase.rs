@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{metadata::MetadataAccount, token};
+
+use crate::{
+    errors::MyError,
+    instructions::referral::ReferredBuyEvent,
+    state::{AllocationVoucher, LaunchPool, NftUsage, ReferralStats, StakeAccount, UserPool},
+};
+
+// the optional accounts both buy instructions take for the NFT gate, tiers and vouchers
+pub struct PurchaseGates<'a, 'info> {
+    pub stake_account: Option<&'a Account<'info, StakeAccount>>,
+    pub nft_token_account: Option<&'a Account<'info, token::TokenAccount>>,
+    pub nft_metadata: Option<&'a Account<'info, MetadataAccount>>,
+    pub nft_usage: Option<&'a mut Account<'info, NftUsage>>,
+    pub instructions_sysvar: Option<&'a UncheckedAccount<'info>>,
+}
+
+// checks the pool's NFT gate, stake tier cap and allocation voucher for a purchase of amount,
+// the per-purchase limits of the pool or round are left to the caller
+pub fn apply_purchase_checks(
+    launch_pool: &Account<LaunchPool>,
+    user_pool: &UserPool,
+    gates: PurchaseGates,
+    user: &Pubkey,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    if launch_pool.nft_gate.is_enabled {
+        let nft_token_account = gates.nft_token_account.ok_or(MyError::NftRequired)?;
+        let nft_metadata = gates.nft_metadata.ok_or(MyError::NftRequired)?;
+        require_keys_eq!(
+            nft_metadata.mint,
+            nft_token_account.mint,
+            MyError::InvalidNftAccount
+        );
+        launch_pool.nft_gate.verify(nft_metadata)?;
+
+        if launch_pool.nft_gate.single_use {
+            let nft_usage = gates.nft_usage.ok_or(MyError::NftRequired)?;
+            nft_usage.use_by(launch_pool.key(), nft_token_account.mint, *user)?;
+        }
+    }
+
+    let total_amount = user_pool.amount.checked_add(amount).unwrap();
+
+    // tiered pools cap the wallet's purchases across the pool by its stake tier
+    if launch_pool.is_tiered {
+        let tier = gates
+            .stake_account
+            .map(|stake_account| stake_account.active_tier(now));
+        require!(
+            total_amount.le(&launch_pool.maximum_token_amount_for(tier)?),
+            MyError::MaximumTokenAmountReached
+        );
+    }
+
+    if let Some(allocation_signer) = launch_pool.allocation_signer {
+        let instructions_sysvar = gates
+            .instructions_sysvar
+            .ok_or(MyError::AllocationVoucherRequired)?;
+        let voucher = AllocationVoucher::load_verified(instructions_sysvar, &allocation_signer)?;
+        voucher.verify(&launch_pool.key(), user, total_amount, now)?;
+    }
+
+    Ok(())
+}
+
+// books a paid purchase on the pool and the buyer's position and accrues the referrer's reward,
+// returning the event to emit for a referred purchase
+pub fn record_purchase(
+    launch_pool: &mut Account<LaunchPool>,
+    user_pool: &mut UserPool,
+    referral_stats: Option<&mut Account<ReferralStats>>,
+    user: &Pubkey,
+    amount: u64,
+    user_must_pay: u64,
+) -> Result<Option<ReferredBuyEvent>> {
+    // a fresh user pool opens a position the launch pool can only close once it is settled
+    if user_pool.amount == 0 {
+        launch_pool.open_positions = launch_pool.open_positions.checked_add(1).unwrap();
+        user_pool.version = UserPool::VERSION;
+    }
+    user_pool.amount = user_pool.amount.checked_add(amount).unwrap();
+    user_pool.currency_amount = user_pool
+        .currency_amount
+        .checked_add(user_must_pay)
+        .unwrap();
+    launch_pool.pool_size_remaining = launch_pool.pool_size_remaining.checked_sub(amount).unwrap();
+    launch_pool.vault_amount = launch_pool.vault_amount.checked_add(user_must_pay).unwrap();
+
+    let Some(referral_stats) = referral_stats else {
+        return Ok(None);
+    };
+    let reward = referral_stats.accrue(amount, user_must_pay, launch_pool.referral_bps)?;
+    launch_pool.referral_reward_total = launch_pool
+        .referral_reward_total
+        .checked_add(reward)
+        .unwrap();
+
+    Ok(Some(ReferredBuyEvent {
+        launch_pool: launch_pool.key(),
+        buyer: *user,
+        referrer: referral_stats.referrer,
+        amount,
+        currency_amount: user_must_pay,
+        reward,
+        timestamp: Clock::get()?.unix_timestamp,
+    }))
+}
@@ -0,0 +1,254 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    constants::{BPS_DENOMINATOR, REFERRAL_STATS_SEED, REFERRER_SEED, USER_POOL_SEED, VAULT_SEED},
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, ReferralStats, Referrer, UserPool},
+};
+
+#[event]
+pub struct ReferredBuyEvent {
+    pub launch_pool: Pubkey,
+    pub buyer: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub currency_amount: u64,
+    pub reward: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralRewardClaimedEvent {
+    pub launch_pool: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RegisterReferrerEvent {
+    pub referrer: Pubkey,
+    pub wallet: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreateReferralStatsEvent {
+    pub launch_pool: Pubkey,
+    pub referrer: Pubkey,
+    pub is_registered: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigureReferralEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub referral_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        init,
+        seeds = [REFERRER_SEED, wallet.key().as_ref()],
+        bump,
+        payer = wallet,
+        space = Referrer::LEN
+    )]
+    pub referrer: Box<Account<'info, Referrer>>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateReferralStats<'info> {
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        init,
+        seeds = [REFERRAL_STATS_SEED, launch_pool.key().as_ref(), wallet.key().as_ref()],
+        bump,
+        payer = wallet,
+        space = ReferralStats::LEN
+    )]
+    pub referral_stats: Box<Account<'info, ReferralStats>>,
+    #[account(
+        seeds = [REFERRER_SEED, wallet.key().as_ref()],
+        bump,
+    )]
+    pub referrer: Option<Box<Account<'info, Referrer>>>,
+    #[account(
+        seeds = [USER_POOL_SEED, wallet.key().as_ref(), launch_pool.key().as_ref(), launch_pool.token_mint.as_ref()],
+        bump,
+    )]
+    pub user_pool: Option<Box<Account<'info, UserPool>>>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureReferral<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferralReward<'info> {
+    #[account(mut)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [REFERRAL_STATS_SEED, launch_pool.key().as_ref(), wallet.key().as_ref()],
+        bump,
+    )]
+    pub referral_stats: Box<Account<'info, ReferralStats>>,
+    /// CHECK: The vault of the launch pool
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            launch_pool.key().as_ref(),
+            launch_pool.creator.as_ref()
+        ],
+        bump = launch_pool.bumps.vault_bump,
+    )]
+    pub vault: AccountInfo<'info>,
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_referrer_handler(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let referrer = &mut ctx.accounts.referrer;
+
+    referrer.wallet = ctx.accounts.wallet.key();
+    referrer.registered_at = Clock::get()?.unix_timestamp;
+
+    msg!("Referrer {} registered", referrer.wallet);
+
+    emit_cpi!(RegisterReferrerEvent {
+        referrer: referrer.key(),
+        wallet: referrer.wallet,
+        timestamp: referrer.registered_at,
+    });
+
+    Ok(())
+}
+
+pub fn create_referral_stats_handler(ctx: Context<CreateReferralStats>) -> Result<()> {
+    let referral_stats = &mut ctx.accounts.referral_stats;
+
+    // only registered referrers or wallets that already bought can refer
+    let is_buyer = ctx
+        .accounts
+        .user_pool
+        .as_ref()
+        .is_some_and(|user_pool| user_pool.amount > 0);
+    require!(
+        ctx.accounts.referrer.is_some() || is_buyer,
+        MyError::InvalidReferrer
+    );
+
+    referral_stats.launch_pool = ctx.accounts.launch_pool.key();
+    referral_stats.referrer = ctx.accounts.wallet.key();
+
+    msg!(
+        "Referrer {} joined launch pool {}",
+        referral_stats.referrer,
+        referral_stats.launch_pool
+    );
+
+    emit_cpi!(CreateReferralStatsEvent {
+        launch_pool: referral_stats.launch_pool,
+        referrer: referral_stats.referrer,
+        is_registered: ctx.accounts.referrer.is_some(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn configure_referral_handler(
+    ctx: Context<ConfigureReferral>,
+    referral_bps: u16,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Pending,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(
+        (referral_bps as u64).le(&BPS_DENOMINATOR),
+        MyError::InvalidBasisPoints
+    );
+
+    launch_pool.referral_bps = referral_bps;
+
+    msg!("Launch pool referral share: {} bps", referral_bps);
+
+    emit_cpi!(ConfigureReferralEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        referral_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn claim_reward_handler(ctx: Context<ClaimReferralReward>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let referral_stats = &mut ctx.accounts.referral_stats;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Completed,
+        MyError::InvalidLaunchPoolStatus
+    );
+
+    let reward = referral_stats.unclaimed();
+    require!(reward.gt(&0), MyError::InvalidAmount);
+
+    let lp_key = launch_pool.key();
+    let signer_seeds = [
+        VAULT_SEED,
+        lp_key.as_ref(),
+        launch_pool.creator.as_ref(),
+        &[launch_pool.bumps.vault_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.wallet.to_account_info(),
+            },
+            &[&signer_seeds],
+        ),
+        reward,
+    )?;
+
+    referral_stats.claimed = referral_stats.reward;
+    launch_pool.referral_reward_claimed = launch_pool
+        .referral_reward_claimed
+        .checked_add(reward)
+        .unwrap();
+
+    emit_cpi!(ReferralRewardClaimedEvent {
+        launch_pool: lp_key,
+        referrer: referral_stats.referrer,
+        amount: reward,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
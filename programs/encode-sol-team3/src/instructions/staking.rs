@@ -0,0 +1,322 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+use anchor_spl::{associated_token, token};
+
+use crate::{
+    constants::{STAKE_ACCOUNT_SEED, STAKE_TIERS, STAKING_CONFIG_SEED},
+    errors::MyError,
+    state::{StakeAccount, StakeTier, StakingConfig, TierThreshold},
+};
+
+#[event]
+pub struct InitializeStakingEvent {
+    pub staking_config: Pubkey,
+    pub authority: Pubkey,
+    pub stake_mint: Pubkey,
+    pub tier_thresholds: [TierThreshold; STAKE_TIERS],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdateStakingTiersEvent {
+    pub staking_config: Pubkey,
+    pub authority: Pubkey,
+    pub tier_thresholds: [TierThreshold; STAKE_TIERS],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeEvent {
+    pub staking_config: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub staked: u64,
+    pub lock_end: i64,
+    pub tier: StakeTier,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnstakeEvent {
+    pub staking_config: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub staked: u64,
+    pub tier: StakeTier,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitializeStaking<'info> {
+    #[account(
+        init,
+        seeds = [STAKING_CONFIG_SEED],
+        bump,
+        payer = authority,
+        space = StakingConfig::LEN
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+    pub stake_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = stake_mint,
+        associated_token::authority = staking_config
+    )]
+    pub stake_vault: Box<Account<'info, token::TokenAccount>>,
+    // the staking config is global, so only the program's upgrade authority may create it
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ MyError::InvalidAuthority
+    )]
+    pub program_data: Box<Account<'info, ProgramData>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, token::Token>,
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateStakingTiers<'info> {
+    #[account(
+        mut,
+        seeds = [STAKING_CONFIG_SEED],
+        bump = staking_config.bump,
+        has_one = authority @ MyError::InvalidAuthority
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(
+        mut,
+        seeds = [STAKING_CONFIG_SEED],
+        bump = staking_config.bump,
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+    #[account(address = staking_config.stake_mint @ MyError::InvalidStakeMint)]
+    pub stake_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = staking_config
+    )]
+    pub stake_vault: Box<Account<'info, token::TokenAccount>>,
+    #[account(
+        init_if_needed,
+        seeds = [STAKE_ACCOUNT_SEED, user.key().as_ref()],
+        bump,
+        payer = user,
+        space = StakeAccount::LEN
+    )]
+    pub stake_account: Box<Account<'info, StakeAccount>>,
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = user
+    )]
+    pub user_token_account: Box<Account<'info, token::TokenAccount>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, token::Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(
+        mut,
+        seeds = [STAKING_CONFIG_SEED],
+        bump = staking_config.bump,
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+    #[account(address = staking_config.stake_mint @ MyError::InvalidStakeMint)]
+    pub stake_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = staking_config
+    )]
+    pub stake_vault: Box<Account<'info, token::TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [STAKE_ACCOUNT_SEED, user.key().as_ref()],
+        bump,
+        constraint = stake_account.owner == user.key() @ MyError::InvalidStakeAccount,
+    )]
+    pub stake_account: Box<Account<'info, StakeAccount>>,
+    #[account(
+        mut,
+        token::mint = stake_mint,
+        token::authority = user
+    )]
+    pub user_token_account: Box<Account<'info, token::TokenAccount>>,
+    #[account(mut)]
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, token::Token>,
+}
+
+pub fn initialize_handler(
+    ctx: Context<InitializeStaking>,
+    tier_thresholds: [TierThreshold; STAKE_TIERS],
+) -> Result<()> {
+    let staking_config = &mut ctx.accounts.staking_config;
+
+    staking_config.authority = ctx.accounts.authority.key();
+    staking_config.stake_mint = ctx.accounts.stake_mint.key();
+    staking_config.total_staked = 0;
+    staking_config.bump = ctx.bumps.staking_config;
+    staking_config.set_tier_thresholds(tier_thresholds)?;
+
+    msg!("Staking initialized for mint {}", staking_config.stake_mint);
+
+    emit_cpi!(InitializeStakingEvent {
+        staking_config: staking_config.key(),
+        authority: staking_config.authority,
+        stake_mint: staking_config.stake_mint,
+        tier_thresholds,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn update_tiers_handler(
+    ctx: Context<UpdateStakingTiers>,
+    tier_thresholds: [TierThreshold; STAKE_TIERS],
+) -> Result<()> {
+    let staking_config = &mut ctx.accounts.staking_config;
+
+    staking_config.set_tier_thresholds(tier_thresholds)?;
+
+    emit_cpi!(UpdateStakingTiersEvent {
+        staking_config: staking_config.key(),
+        authority: staking_config.authority,
+        tier_thresholds,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn stake_handler(ctx: Context<Stake>, amount: u64, lock_duration: i64) -> Result<()> {
+    let staking_config = &mut ctx.accounts.staking_config;
+    let stake_account = &mut ctx.accounts.stake_account;
+    let user = &ctx.accounts.user;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(amount.gt(&0), MyError::InvalidAmount);
+    require!(lock_duration >= 0, MyError::InvalidUnlockDate);
+
+    // a fresh position, or one whose lock already ran out, starts a new lock
+    if stake_account.amount == 0 || stake_account.lock_end <= now {
+        stake_account.owner = user.key();
+        stake_account.lock_start = now;
+        stake_account.lock_end = now;
+    }
+
+    let lock_end = now.checked_add(lock_duration).ok_or(MyError::Overflow)?;
+    if lock_end > stake_account.lock_end {
+        stake_account.lock_end = lock_end;
+    }
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+                authority: user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    stake_account.amount = stake_account
+        .amount
+        .checked_add(amount)
+        .ok_or(MyError::Overflow)?;
+    stake_account.tier =
+        staking_config.tier_for(stake_account.amount, stake_account.lock_duration());
+    staking_config.total_staked = staking_config
+        .total_staked
+        .checked_add(amount)
+        .ok_or(MyError::Overflow)?;
+
+    msg!(
+        "User staked {} tokens until {}, tier {}",
+        stake_account.amount,
+        stake_account.lock_end,
+        stake_account.tier as u8
+    );
+
+    emit_cpi!(StakeEvent {
+        staking_config: staking_config.key(),
+        user: user.key(),
+        amount,
+        staked: stake_account.amount,
+        lock_end: stake_account.lock_end,
+        tier: stake_account.tier,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+pub fn unstake_handler(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+    let staking_config = &mut ctx.accounts.staking_config;
+    let stake_account = &mut ctx.accounts.stake_account;
+
+    require!(amount.gt(&0), MyError::InvalidAmount);
+    require!(stake_account.amount.ge(&amount), MyError::InvalidAmount);
+    require!(
+        stake_account.lock_end <= Clock::get()?.unix_timestamp,
+        MyError::TimeLockNotExpired
+    );
+
+    let signer_seeds = [STAKING_CONFIG_SEED, &[staking_config.bump]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: staking_config.to_account_info(),
+            },
+            &[&signer_seeds],
+        ),
+        amount,
+    )?;
+
+    stake_account.amount -= amount;
+    stake_account.tier = if stake_account.amount == 0 {
+        StakeTier::Unranked
+    } else {
+        staking_config.tier_for(stake_account.amount, stake_account.lock_duration())
+    };
+    staking_config.total_staked -= amount;
+
+    msg!("User unstaked {} tokens", amount);
+
+    emit_cpi!(UnstakeEvent {
+        staking_config: staking_config.key(),
+        user: *ctx.accounts.user.key,
+        amount,
+        staked: stake_account.amount,
+        tier: stake_account.tier,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
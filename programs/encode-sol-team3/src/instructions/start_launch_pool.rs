@@ -2,22 +2,46 @@ use anchor_lang::prelude::*;
 use anchor_spl::token;
 
 use crate::{
-    constants::{LAUNCH_POOL_SEED, TREASURER_SEED},
+    constants::{TEAM_LOCK_SEED, TREASURER_SEED},
     errors::MyError,
-    state::{LaunchPool, LaunchPoolState, Treasurer},
+    state::{LaunchPool, LaunchPoolState, TeamLock, Treasurer, UnsoldPolicy},
 };
 
+#[event]
+pub struct StartLaunchPoolEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub token_amount: u64,
+    pub team_lock_amount: u64,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
 #[derive(Accounts)]
 pub struct StartLaunchPool<'info> {
-    #[account(mut, seeds = [LAUNCH_POOL_SEED.as_ref(), authority.key().as_ref(), token_mint.key().as_ref()], bump)]
+    // not re-derived, pools migrated from the first layout live at an address of their mint
+    #[account(mut)]
     pub launch_pool: Account<'info, LaunchPool>,
     pub token_mint: Box<Account<'info, token::Mint>>,
+    // not needed when the supply was minted into the treasury at creation
     #[account(mut)]
-    pub source_token_account: Account<'info, token::TokenAccount>,
-    #[account(mut, seeds = [TREASURER_SEED.as_ref(), launch_pool.key().as_ref(), token_mint.key().as_ref()], bump)]
+    pub source_token_account: Option<Account<'info, token::TokenAccount>>,
+    #[account(mut, seeds = [TREASURER_SEED, launch_pool.key().as_ref(), token_mint.key().as_ref()], bump = launch_pool.bumps.treasurer_bump)]
     pub treasurer: Box<Account<'info, Treasurer>>,
-    #[account(mut, constraint = treasury.mint == launch_pool.token_mint)]
+    #[account(
+        mut,
+        constraint = treasury.mint == launch_pool.token_mint @ MyError::InvalidTokenMint,
+        token::authority = treasurer,
+    )]
     pub treasury: Box<Account<'info, token::TokenAccount>>,
+    #[account(
+        seeds = [TEAM_LOCK_SEED, launch_pool.key().as_ref()],
+        bump = team_lock.bump,
+    )]
+    pub team_lock: Option<Box<Account<'info, TeamLock>>>,
+    #[account(mut)]
+    pub team_lock_token_account: Option<Box<Account<'info, token::TokenAccount>>>,
     #[account(mut)]
     pub authority: Signer<'info>,
     pub token_program: Program<'info, token::Token>,
@@ -25,10 +49,10 @@ pub struct StartLaunchPool<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handler(ctx: Context<StartLaunchPool>) -> Result<()> {
+pub fn start_launch_pool_handler(ctx: Context<StartLaunchPool>) -> Result<()> {
     let launch_pool = &mut ctx.accounts.launch_pool;
     let treasurer = &mut ctx.accounts.treasurer;
-    let source_token_account = &mut ctx.accounts.source_token_account;
+    let source_token_account = &ctx.accounts.source_token_account;
     let treasury = &mut ctx.accounts.treasury;
     let authority = &ctx.accounts.authority;
     let token_mint = &ctx.accounts.token_mint;
@@ -47,21 +71,116 @@ pub fn handler(ctx: Context<StartLaunchPool>) -> Result<()> {
         token_mint.to_account_info().key.eq(&launch_pool.token_mint),
         MyError::InvalidTokenMint
     );
+    // unsold tokens can only be sent to an AMM pool the launch pool seeds
+    require!(
+        launch_pool.unsold_policy != Some(UnsoldPolicy::SendToLiquidity)
+            || launch_pool.seeds_liquidity(),
+        MyError::LiquidityAccountsRequired
+    );
 
-    let transfer_amount = launch_pool.pool_size;
-    launch_pool.pool_size_remaining = transfer_amount;
+    // tokens reserved for the AMM listing are deposited along with the sale supply
+    let transfer_amount = launch_pool
+        .pool_size
+        .checked_add(launch_pool.liquidity_token_reserve)
+        .ok_or(MyError::Overflow)?;
+    launch_pool.pool_size_remaining = launch_pool.pool_size;
     launch_pool.status = LaunchPoolState::Active;
     treasurer.amount = transfer_amount;
 
-    msg!("Transfering {} tokens to treasury", transfer_amount);
+    // a minted supply is already in the treasury, team allocation included
+    let minted = launch_pool.minted_supply > 0;
+    if minted {
+        require!(
+            transfer_amount.checked_add(launch_pool.team_lock_amount)
+                == Some(launch_pool.minted_supply),
+            MyError::InvalidMintedSupply
+        );
+    } else {
+        let source_token_account = source_token_account
+            .as_ref()
+            .ok_or(MyError::TokenAccountRequired)?;
 
-    let cpi_context = CpiContext::new(
-        token_program.to_account_info(),
-        token::Transfer {
-            from: source_token_account.to_account_info(),
-            to: treasury.to_account_info(),
-            authority: authority.to_account_info(),
-        },
-    );
-    Ok(token::transfer(cpi_context, transfer_amount)?)
+        msg!("Transfering {} tokens to treasury", transfer_amount);
+
+        let cpi_context = CpiContext::new(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: source_token_account.to_account_info(),
+                to: treasury.to_account_info(),
+                authority: authority.to_account_info(),
+            },
+        );
+        token::transfer(cpi_context, transfer_amount)?;
+    }
+
+    // the team allocation is locked at the same time as the sale supply
+    if launch_pool.team_lock_amount > 0 {
+        let team_lock = ctx
+            .accounts
+            .team_lock
+            .as_ref()
+            .ok_or(MyError::InvalidTeamLock)?;
+        let team_lock_token_account = ctx
+            .accounts
+            .team_lock_token_account
+            .as_ref()
+            .ok_or(MyError::InvalidTeamLock)?;
+        require_keys_eq!(
+            team_lock_token_account.key(),
+            team_lock.token_account,
+            MyError::InvalidTeamLock
+        );
+
+        msg!(
+            "Transfering {} tokens to team lock",
+            launch_pool.team_lock_amount
+        );
+
+        if minted {
+            let lp_key = launch_pool.key();
+            let token_mint_key = token_mint.key();
+            let signer_seeds = [
+                TREASURER_SEED,
+                lp_key.as_ref(),
+                token_mint_key.as_ref(),
+                &[launch_pool.bumps.treasurer_bump],
+            ];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: treasury.to_account_info(),
+                        to: team_lock_token_account.to_account_info(),
+                        authority: treasurer.to_account_info(),
+                    },
+                    &[&signer_seeds],
+                ),
+                launch_pool.team_lock_amount,
+            )?;
+        } else {
+            let source_token_account = source_token_account
+                .as_ref()
+                .ok_or(MyError::TokenAccountRequired)?;
+            let cpi_context = CpiContext::new(
+                token_program.to_account_info(),
+                token::Transfer {
+                    from: source_token_account.to_account_info(),
+                    to: team_lock_token_account.to_account_info(),
+                    authority: authority.to_account_info(),
+                },
+            );
+            token::transfer(cpi_context, launch_pool.team_lock_amount)?;
+        }
+    }
+
+    emit_cpi!(StartLaunchPoolEvent {
+        launch_pool: launch_pool.key(),
+        authority: *authority.key,
+        token_amount: transfer_amount,
+        team_lock_amount: launch_pool.team_lock_amount,
+        status: launch_pool.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
 }
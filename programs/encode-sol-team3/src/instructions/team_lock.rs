@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token, token};
+
+use crate::{
+    constants::TEAM_LOCK_SEED,
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, TeamLock, VestingSchedule},
+};
+
+#[event]
+pub struct ConfigureTeamLockEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub cliff_date: i64,
+    pub end_date: i64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ConfigureTeamLock<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(address = launch_pool.token_mint @ MyError::InvalidTokenMint)]
+    pub token_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        init,
+        seeds = [TEAM_LOCK_SEED, launch_pool.key().as_ref()],
+        bump,
+        payer = authority,
+        space = TeamLock::LEN
+    )]
+    pub team_lock: Box<Account<'info, TeamLock>>,
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = token_mint,
+        associated_token::authority = team_lock
+    )]
+    pub team_lock_token_account: Box<Account<'info, token::TokenAccount>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, token::Token>,
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event]
+pub struct WithdrawTeamTokensEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub withdrawn: u64,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawTeamTokens<'info> {
+    #[account(has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [TEAM_LOCK_SEED, launch_pool.key().as_ref()],
+        bump = team_lock.bump,
+    )]
+    pub team_lock: Box<Account<'info, TeamLock>>,
+    #[account(mut, address = team_lock.token_account @ MyError::InvalidTeamLock)]
+    pub team_lock_token_account: Box<Account<'info, token::TokenAccount>>,
+    #[account(mut, token::mint = launch_pool.token_mint)]
+    pub destination: Box<Account<'info, token::TokenAccount>>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, token::Token>,
+}
+
+pub fn configure_team_lock_handler(
+    ctx: Context<ConfigureTeamLock>,
+    amount: u64,
+    cliff_date: i64,
+    vesting_duration: i64,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let team_lock = &mut ctx.accounts.team_lock;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Pending,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(amount > 0, MyError::InvalidAmount);
+    // the team can never unlock before its buyers
+    require!(
+        cliff_date >= launch_pool.unlock_date,
+        MyError::InvalidUnlockDate
+    );
+
+    team_lock.launch_pool = launch_pool.key();
+    team_lock.token_account = ctx.accounts.team_lock_token_account.key();
+    team_lock.amount = amount;
+    team_lock.withdrawn = 0;
+    team_lock.schedule = VestingSchedule::new(cliff_date, vesting_duration)?;
+    team_lock.bump = ctx.bumps.team_lock;
+
+    launch_pool.team_lock_amount = amount;
+
+    msg!(
+        "Team lock of {} tokens, cliff {} and fully vested at {}",
+        amount,
+        team_lock.schedule.cliff_date,
+        team_lock.schedule.end_date
+    );
+
+    emit_cpi!(ConfigureTeamLockEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        amount,
+        cliff_date: team_lock.schedule.cliff_date,
+        end_date: team_lock.schedule.end_date,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn withdraw_team_tokens_handler(ctx: Context<WithdrawTeamTokens>) -> Result<()> {
+    let launch_pool = &ctx.accounts.launch_pool;
+    let team_lock = &mut ctx.accounts.team_lock;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Completed,
+        MyError::InvalidLaunchPoolStatus
+    );
+
+    let vested_amount = team_lock
+        .schedule
+        .vested_amount(team_lock.amount, Clock::get()?.unix_timestamp)?;
+    let amount = vested_amount - team_lock.withdrawn;
+
+    require!(amount > 0, MyError::InvalidAmount);
+
+    let lp_key = launch_pool.key();
+    let signer_seeds = [TEAM_LOCK_SEED, lp_key.as_ref(), &[team_lock.bump]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.team_lock_token_account.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: team_lock.to_account_info(),
+            },
+            &[&signer_seeds],
+        ),
+        amount,
+    )?;
+
+    team_lock.withdrawn += amount;
+
+    msg!("Team tokens withdrawn: {}", team_lock.withdrawn);
+
+    emit_cpi!(WithdrawTeamTokensEvent {
+        launch_pool: lp_key,
+        authority: *ctx.accounts.authority.key,
+        amount,
+        withdrawn: team_lock.withdrawn,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token,
+    metadata::{self, mpl_token_metadata::types::DataV2, Metadata},
+    token::{self, spl_token::instruction::AuthorityType},
+};
+
+use crate::{
+    constants::{MINT_AUTHORITY_SEED, TREASURER_SEED},
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, Treasurer},
+};
+
+// what the metaplex metadata account of a minted token says about it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct CreateTokenMintEvent {
+    pub token_mint: Pubkey,
+    pub authority: Pubkey,
+    pub decimals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintPoolSupplyEvent {
+    pub launch_pool: Pubkey,
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub liquidity_amount: u64,
+    pub team_amount: u64,
+    pub authorities_revoked: bool,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(decimals: u8)]
+pub struct CreateTokenMint<'info> {
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = decimals,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority
+    )]
+    pub token_mint: Box<Account<'info, token::Mint>>,
+    /// CHECK: PDA that signs for the mint, it holds no data
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, authority.key().as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, token::Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MintPoolSupply<'info> {
+    #[account(
+        mut,
+        has_one = authority @ MyError::InvalidAuthority,
+        has_one = token_mint @ MyError::InvalidTokenMint
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(mut)]
+    pub token_mint: Box<Account<'info, token::Mint>>,
+    /// CHECK: PDA that signs for the mint, only the creator's pools can use it
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED, launch_pool.creator.as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+    #[account(
+        seeds = [TREASURER_SEED, launch_pool.key().as_ref(), token_mint.key().as_ref()],
+        bump = launch_pool.bumps.treasurer_bump
+    )]
+    pub treasurer: Box<Account<'info, Treasurer>>,
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = treasurer
+    )]
+    pub treasury: Box<Account<'info, token::TokenAccount>>,
+    /// CHECK: Created and checked by the token metadata program
+    #[account(mut)]
+    pub metadata: Option<UncheckedAccount<'info>>,
+    pub token_metadata_program: Option<Program<'info, Metadata>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, token::Token>,
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn create_mint_handler(ctx: Context<CreateTokenMint>, decimals: u8) -> Result<()> {
+    msg!(
+        "Token mint {} created with {} decimals for {}",
+        ctx.accounts.token_mint.key(),
+        decimals,
+        ctx.accounts.authority.key
+    );
+
+    emit_cpi!(CreateTokenMintEvent {
+        token_mint: ctx.accounts.token_mint.key(),
+        authority: ctx.accounts.authority.key(),
+        decimals,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// the whole supply is minted once, so the pool never depends on the creator's deposit at start
+pub fn mint_supply_handler(
+    ctx: Context<MintPoolSupply>,
+    liquidity_amount: u64,
+    team_amount: u64,
+    token_metadata: Option<TokenMetadata>,
+    revoke_authorities: bool,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let token_mint = &ctx.accounts.token_mint;
+    let mint_authority = &ctx.accounts.mint_authority;
+    let authority = &ctx.accounts.authority;
+    let token_program = &ctx.accounts.token_program;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Pending,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(
+        token_mint.supply == 0 && launch_pool.minted_supply == 0,
+        MyError::InvalidMintedSupply
+    );
+
+    // start checks these against the configured liquidity reserve and team lock
+    let amount = launch_pool
+        .pool_size
+        .checked_add(liquidity_amount)
+        .and_then(|amount| amount.checked_add(team_amount))
+        .ok_or(MyError::Overflow)?;
+    launch_pool.minted_supply = amount;
+
+    let creator = launch_pool.creator;
+    let mint_key = token_mint.key();
+    let signer_seeds = [
+        MINT_AUTHORITY_SEED,
+        creator.as_ref(),
+        mint_key.as_ref(),
+        &[ctx.bumps.mint_authority],
+    ];
+
+    msg!("Minting {} tokens to treasury", amount);
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::MintTo {
+                mint: token_mint.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+                authority: mint_authority.to_account_info(),
+            },
+            &[&signer_seeds],
+        ),
+        amount,
+    )?;
+
+    // the metadata program needs the mint authority, so it runs before any revoke
+    if let Some(token_metadata) = token_metadata {
+        let metadata_account = ctx
+            .accounts
+            .metadata
+            .as_ref()
+            .ok_or(MyError::TokenMetadataAccountsRequired)?;
+        let token_metadata_program = ctx
+            .accounts
+            .token_metadata_program
+            .as_ref()
+            .ok_or(MyError::TokenMetadataAccountsRequired)?;
+
+        metadata::create_metadata_accounts_v3(
+            CpiContext::new_with_signer(
+                token_metadata_program.to_account_info(),
+                metadata::CreateMetadataAccountsV3 {
+                    metadata: metadata_account.to_account_info(),
+                    mint: token_mint.to_account_info(),
+                    mint_authority: mint_authority.to_account_info(),
+                    payer: authority.to_account_info(),
+                    update_authority: authority.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                &[&signer_seeds],
+            ),
+            DataV2 {
+                name: token_metadata.name,
+                symbol: token_metadata.symbol,
+                uri: token_metadata.uri,
+                seller_fee_basis_points: 0,
+                creators: None,
+                collection: None,
+                uses: None,
+            },
+            true,
+            true,
+            None,
+        )?;
+    }
+
+    // without either authority anyone can verify the supply can never change
+    if revoke_authorities {
+        for authority_type in [AuthorityType::MintTokens, AuthorityType::FreezeAccount] {
+            token::set_authority(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::SetAuthority {
+                        current_authority: mint_authority.to_account_info(),
+                        account_or_mint: token_mint.to_account_info(),
+                    },
+                    &[&signer_seeds],
+                ),
+                authority_type,
+                None,
+            )?;
+        }
+    }
+
+    msg!(
+        "Launch pool {} minted {} tokens of {}, authorities revoked: {}",
+        launch_pool.key(),
+        amount,
+        mint_key,
+        revoke_authorities
+    );
+
+    emit_cpi!(MintPoolSupplyEvent {
+        launch_pool: launch_pool.key(),
+        token_mint: mint_key,
+        amount,
+        liquidity_amount,
+        team_amount,
+        authorities_revoked: revoke_authorities,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
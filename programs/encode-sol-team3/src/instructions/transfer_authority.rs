@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::TREASURER_SEED,
+    errors::MyError,
+    state::{LaunchPool, Treasurer},
+};
+
+#[event]
+pub struct ProposeAuthorityEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AcceptAuthorityEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    pub authority: Signer<'info>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        constraint = launch_pool.pending_authority == Some(new_authority.key()) @ MyError::InvalidPendingAuthority
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        mut,
+        seeds = [TREASURER_SEED, launch_pool.key().as_ref(), launch_pool.token_mint.as_ref()],
+        bump = launch_pool.bumps.treasurer_bump
+    )]
+    pub treasurer: Box<Account<'info, Treasurer>>,
+    pub new_authority: Signer<'info>,
+}
+
+pub fn propose_authority_handler(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+
+    // proposing the current authority cancels a pending handover
+    launch_pool.pending_authority = if new_authority == launch_pool.authority {
+        None
+    } else {
+        Some(new_authority)
+    };
+
+    msg!("Pending authority: {:?}", launch_pool.pending_authority);
+
+    emit_cpi!(ProposeAuthorityEvent {
+        launch_pool: launch_pool.key(),
+        authority: launch_pool.authority,
+        pending_authority: launch_pool.pending_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn accept_handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let treasurer = &mut ctx.accounts.treasurer;
+    let new_authority = ctx.accounts.new_authority.key();
+
+    launch_pool.authority = new_authority;
+    launch_pool.pending_authority = None;
+    treasurer.authority = new_authority;
+
+    msg!("Launch pool authority transferred to {}", new_authority);
+
+    emit_cpi!(AcceptAuthorityEvent {
+        launch_pool: launch_pool.key(),
+        authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CONTENT_HASH_SIZE, POOL_METADATA_SEED},
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState, PoolMetadata},
+};
+
+#[derive(Accounts)]
+pub struct UpdatePoolMetadata<'info> {
+    #[account(has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(
+        init_if_needed,
+        seeds = [POOL_METADATA_SEED, launch_pool.key().as_ref()],
+        bump,
+        payer = authority,
+        space = PoolMetadata::LEN
+    )]
+    pub pool_metadata: Box<Account<'info, PoolMetadata>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn update_pool_metadata_handler(
+    ctx: Context<UpdatePoolMetadata>,
+    name: String,
+    symbol: String,
+    uri: String,
+    website: Option<String>,
+    socials: Vec<String>,
+    content_hash: [u8; CONTENT_HASH_SIZE],
+) -> Result<()> {
+    let launch_pool = &ctx.accounts.launch_pool;
+    let pool_metadata = &mut ctx.accounts.pool_metadata;
+
+    // buyers judge the sale by its description, so it is frozen once the pool opens
+    require!(
+        launch_pool.status == LaunchPoolState::Pending,
+        MyError::InvalidLaunchPoolStatus
+    );
+
+    pool_metadata.launch_pool = launch_pool.key();
+    pool_metadata.bump = ctx.bumps.pool_metadata;
+    pool_metadata.update(name, symbol, uri, website, socials, content_hash)?;
+
+    msg!(
+        "Launch pool {} metadata: {} ({}) at {}",
+        launch_pool.key(),
+        pool_metadata.name,
+        pool_metadata.symbol,
+        pool_metadata.uri
+    );
+
+    Ok(())
+}
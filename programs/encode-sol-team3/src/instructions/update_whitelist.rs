@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::WHITELIST_SEED,
+    errors::MyError,
+    state::{LaunchPool, SaleRound, SaleRoundAccess, Whitelist},
+};
+
+#[event]
+pub struct AddWalletsToWhitelistEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub index: u8,
+    pub wallets: Vec<Pubkey>,
+    pub wallet_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RemoveWalletFromWhitelistEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub index: u8,
+    pub wallet: Pubkey,
+    pub wallet_count: u32,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddWalletsToWhitelist<'info> {
+    #[account(has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(has_one = launch_pool @ MyError::InvalidSaleRound)]
+    pub sale_round: Box<Account<'info, SaleRound>>,
+    #[account(
+        init_if_needed,
+        seeds = [WHITELIST_SEED, sale_round.key().as_ref()],
+        bump,
+        payer = authority,
+        space = Whitelist::LEN
+    )]
+    pub whitelist: Box<Account<'info, Whitelist>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveWalletFromWhitelist<'info> {
+    #[account(has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(has_one = launch_pool @ MyError::InvalidSaleRound)]
+    pub sale_round: Box<Account<'info, SaleRound>>,
+    #[account(
+        mut,
+        seeds = [WHITELIST_SEED, sale_round.key().as_ref()],
+        bump,
+    )]
+    pub whitelist: Box<Account<'info, Whitelist>>,
+    pub authority: Signer<'info>,
+}
+
+pub fn add_wallets_handler(
+    ctx: Context<AddWalletsToWhitelist>,
+    wallets: Vec<Pubkey>,
+) -> Result<()> {
+    let sale_round = &ctx.accounts.sale_round;
+    let whitelist = &mut ctx.accounts.whitelist;
+
+    require!(
+        sale_round.access == SaleRoundAccess::Whitelist,
+        MyError::InvalidWhitelist
+    );
+    require!(!sale_round.is_closed, MyError::SaleRoundNotOpen);
+
+    whitelist.sale_round = sale_round.key();
+    whitelist.add_wallets(wallets.clone())?;
+
+    msg!(
+        "Whitelist of sale round {} has {} wallets",
+        sale_round.index,
+        whitelist.wallets.len()
+    );
+
+    emit_cpi!(AddWalletsToWhitelistEvent {
+        launch_pool: ctx.accounts.launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        index: sale_round.index,
+        wallets,
+        wallet_count: whitelist.wallets.len() as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn remove_wallet_handler(
+    ctx: Context<RemoveWalletFromWhitelist>,
+    wallet: Pubkey,
+) -> Result<()> {
+    let whitelist = &mut ctx.accounts.whitelist;
+
+    whitelist.remove_wallet(wallet)?;
+
+    msg!("Wallet {} removed from whitelist", wallet);
+
+    emit_cpi!(RemoveWalletFromWhitelistEvent {
+        launch_pool: ctx.accounts.launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        index: ctx.accounts.sale_round.index,
+        wallet,
+        wallet_count: whitelist.wallets.len() as u32,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use anchor_lang::{prelude::*, system_program};
+
+use crate::{
+    constants::VAULT_SEED,
+    errors::MyError,
+    state::{LaunchPool, LaunchPoolState},
+};
+
+#[event]
+pub struct WithdrawProceedsEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub proceeds_withdrawn: u64,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawProceeds<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    /// CHECK: The vault of the launch pool
+    #[account(
+        mut,
+        seeds = [
+            VAULT_SEED,
+            launch_pool.key().as_ref(),
+            launch_pool.creator.as_ref()
+        ],
+        bump = launch_pool.bumps.vault_bump,
+    )]
+    pub vault: AccountInfo<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_proceeds_handler(ctx: Context<WithdrawProceeds>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Completed,
+        MyError::InvalidLaunchPoolStatus
+    );
+    require!(!launch_pool.is_governed, MyError::LaunchPoolIsGoverned);
+
+    let released = launch_pool.released_proceeds(Clock::get()?.unix_timestamp);
+    let amount = released
+        .checked_sub(launch_pool.proceeds_withdrawn)
+        .unwrap();
+
+    require!(amount > 0, MyError::InvalidAmount);
+
+    let lp_key = launch_pool.key();
+    let signer_seeds = [
+        VAULT_SEED,
+        lp_key.as_ref(),
+        launch_pool.creator.as_ref(),
+        &[launch_pool.bumps.vault_bump],
+    ];
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.authority.to_account_info(),
+            },
+            &[&signer_seeds],
+        ),
+        amount,
+    )?;
+
+    launch_pool.proceeds_withdrawn = released;
+
+    msg!(
+        "Proceeds withdrawn: {} of {}",
+        launch_pool.proceeds_withdrawn,
+        launch_pool.distributable_proceeds()
+    );
+
+    emit_cpi!(WithdrawProceedsEvent {
+        launch_pool: lp_key,
+        authority: *ctx.accounts.authority.key,
+        amount,
+        proceeds_withdrawn: launch_pool.proceeds_withdrawn,
+        status: launch_pool.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
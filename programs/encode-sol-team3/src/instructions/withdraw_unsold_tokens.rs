@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+use crate::{
+    constants::TREASURER_SEED,
+    errors::MyError,
+    instructions::finalize_unsold_tokens::release_unsold_tokens,
+    state::{LaunchPool, LaunchPoolState, Treasurer},
+};
+
+#[event]
+pub struct WithdrawUnsoldTokensEvent {
+    pub launch_pool: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub burned: bool,
+    pub treasurer_amount: u64,
+    pub status: LaunchPoolState,
+    pub timestamp: i64,
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawUnsoldTokens<'info> {
+    #[account(mut, has_one = authority @ MyError::InvalidAuthority)]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+    #[account(mut, address = launch_pool.token_mint @ MyError::InvalidTokenMint)]
+    pub token_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        mut,
+        seeds = [TREASURER_SEED, launch_pool.key().as_ref(), token_mint.key().as_ref()],
+        bump = launch_pool.bumps.treasurer_bump
+    )]
+    pub treasurer: Box<Account<'info, Treasurer>>,
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = treasurer,
+    )]
+    pub treasury: Box<Account<'info, token::TokenAccount>>,
+    #[account(mut, token::mint = token_mint)]
+    pub destination: Option<Box<Account<'info, token::TokenAccount>>>,
+    pub authority: Signer<'info>,
+    pub token_program: Program<'info, token::Token>,
+}
+
+pub fn withdraw_unsold_tokens_handler(
+    ctx: Context<WithdrawUnsoldTokens>,
+    burn: bool,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let treasurer = &mut ctx.accounts.treasurer;
+
+    require!(
+        launch_pool.status == LaunchPoolState::Completed,
+        MyError::InvalidLaunchPoolStatus
+    );
+    // a policy declared at creation is settled by finalize_unsold_tokens instead
+    require!(
+        launch_pool.unsold_policy.is_none(),
+        MyError::UnsoldPolicyDeclared
+    );
+    require!(
+        !launch_pool.unsold_withdrawn,
+        MyError::UnsoldTokensWithdrawn
+    );
+
+    let destination = if burn {
+        None
+    } else {
+        Some(
+            ctx.accounts
+                .destination
+                .as_deref()
+                .ok_or(MyError::TokenAccountRequired)?,
+        )
+    };
+    let amount = release_unsold_tokens(
+        launch_pool,
+        treasurer,
+        &ctx.accounts.token_mint,
+        &ctx.accounts.treasury,
+        destination,
+        &ctx.accounts.token_program,
+    )?;
+
+    msg!(
+        "Unsold tokens {}: {}, {} left for buyers",
+        if burn { "burned" } else { "withdrawn" },
+        amount,
+        treasurer.amount
+    );
+
+    emit_cpi!(WithdrawUnsoldTokensEvent {
+        launch_pool: launch_pool.key(),
+        authority: *ctx.accounts.authority.key,
+        amount,
+        burned: burn,
+        treasurer_amount: treasurer.amount,
+        status: launch_pool.status,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
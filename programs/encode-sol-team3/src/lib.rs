@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
+pub mod amm;
 pub mod constants;
 pub mod errors;
 pub mod instructions;
 pub mod state;
+use constants::{CONTENT_HASH_SIZE, STAKE_TIERS};
 use instructions::*;
+use state::{ProceedsVesting, SaleRoundAccess, TierThreshold, UnsoldPolicy};
 
 declare_id!("Eo9a3Zjn5HbGnL9wqkjDmajQ5EGzgaBbW77YhUZNVLo5");
 
@@ -11,6 +14,7 @@ declare_id!("Eo9a3Zjn5HbGnL9wqkjDmajQ5EGzgaBbW77YhUZNVLo5");
 pub mod encode_sol_team3 {
     use super::*;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_native_pool(
         ctx: Context<CreateFairlaunchPool>,
         unlock_date: i64,
@@ -19,8 +23,10 @@ pub mod encode_sol_team3 {
         maximum_token_amount: u64,
         rate: u64,
         token_mint_decimals: u8,
+        proceeds_vesting: Option<ProceedsVesting>,
+        unsold_policy: Option<UnsoldPolicy>,
     ) -> Result<()> {
-        instructions::create_fairlaunch_pool::handler(
+        instructions::create_fairlaunch_pool::create_fairlaunch_pool_handler(
             ctx,
             unlock_date,
             pool_size,
@@ -28,22 +34,277 @@ pub mod encode_sol_team3 {
             maximum_token_amount,
             rate,
             token_mint_decimals,
+            proceeds_vesting,
+            unsold_policy,
         )
     }
 
     pub fn start_launch_pool(ctx: Context<StartLaunchPool>) -> Result<()> {
-        instructions::start_launch_pool::handler(ctx)
+        instructions::start_launch_pool::start_launch_pool_handler(ctx)
     }
 
     pub fn buy_token_with_native(ctx: Context<BuyTokenWithNative>, amount: u64) -> Result<()> {
-        instructions::buy_token_with_native::handler(ctx, amount)
+        instructions::buy_token_with_native::buy_token_with_native_handler(ctx, amount)
     }
 
     pub fn complete_launch_pool(ctx: Context<CompleteLaunchPool>) -> Result<()> {
-        instructions::complete_launch_pool::handler(ctx)
+        instructions::complete_launch_pool::complete_launch_pool_handler(ctx)
     }
 
     pub fn claim_token(ctx: Context<ClaimToken>) -> Result<()> {
-        instructions::claim_token::handler(ctx)
+        instructions::claim_token::claim_token_handler(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_sale_round(
+        ctx: Context<CreateSaleRound>,
+        index: u8,
+        rate: u64,
+        minimum_token_amount: u64,
+        maximum_token_amount: u64,
+        start_time: i64,
+        end_time: i64,
+        allocation: u64,
+        access: SaleRoundAccess,
+    ) -> Result<()> {
+        instructions::create_sale_round::create_sale_round_handler(
+            ctx,
+            index,
+            rate,
+            minimum_token_amount,
+            maximum_token_amount,
+            start_time,
+            end_time,
+            allocation,
+            access,
+        )
+    }
+
+    pub fn add_wallets_to_whitelist(
+        ctx: Context<AddWalletsToWhitelist>,
+        wallets: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_whitelist::add_wallets_handler(ctx, wallets)
+    }
+
+    pub fn remove_wallet_from_whitelist(
+        ctx: Context<RemoveWalletFromWhitelist>,
+        wallet: Pubkey,
+    ) -> Result<()> {
+        instructions::update_whitelist::remove_wallet_handler(ctx, wallet)
+    }
+
+    pub fn buy_token_in_round(ctx: Context<BuyTokenInRound>, amount: u64) -> Result<()> {
+        instructions::buy_token_in_round::buy_token_in_round_handler(ctx, amount)
+    }
+
+    pub fn advance_sale_round(ctx: Context<AdvanceSaleRound>) -> Result<()> {
+        instructions::advance_sale_round::advance_sale_round_handler(ctx)
+    }
+
+    pub fn initialize_staking(
+        ctx: Context<InitializeStaking>,
+        tier_thresholds: [TierThreshold; STAKE_TIERS],
+    ) -> Result<()> {
+        instructions::staking::initialize_handler(ctx, tier_thresholds)
+    }
+
+    pub fn update_staking_tiers(
+        ctx: Context<UpdateStakingTiers>,
+        tier_thresholds: [TierThreshold; STAKE_TIERS],
+    ) -> Result<()> {
+        instructions::staking::update_tiers_handler(ctx, tier_thresholds)
+    }
+
+    pub fn stake(ctx: Context<Stake>, amount: u64, lock_duration: i64) -> Result<()> {
+        instructions::staking::stake_handler(ctx, amount, lock_duration)
+    }
+
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        instructions::staking::unstake_handler(ctx, amount)
+    }
+
+    pub fn configure_tier_allocations(
+        ctx: Context<ConfigureTierAllocations>,
+        tier_caps: [u64; STAKE_TIERS],
+    ) -> Result<()> {
+        instructions::configure_tier_allocations::configure_tier_allocations_handler(ctx, tier_caps)
+    }
+
+    pub fn configure_nft_gate(
+        ctx: Context<ConfigureNftGate>,
+        collection: Option<Pubkey>,
+        creators: Vec<Pubkey>,
+        single_use: bool,
+    ) -> Result<()> {
+        instructions::configure_nft_gate::configure_nft_gate_handler(
+            ctx, collection, creators, single_use,
+        )
+    }
+
+    pub fn configure_allocation_signer(
+        ctx: Context<ConfigureAllocationSigner>,
+        allocation_signer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::configure_allocation_signer::configure_allocation_signer_handler(
+            ctx,
+            allocation_signer,
+        )
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::referral::register_referrer_handler(ctx)
+    }
+
+    pub fn create_referral_stats(ctx: Context<CreateReferralStats>) -> Result<()> {
+        instructions::referral::create_referral_stats_handler(ctx)
+    }
+
+    pub fn configure_referral(ctx: Context<ConfigureReferral>, referral_bps: u16) -> Result<()> {
+        instructions::referral::configure_referral_handler(ctx, referral_bps)
+    }
+
+    pub fn claim_referral_reward(ctx: Context<ClaimReferralReward>) -> Result<()> {
+        instructions::referral::claim_reward_handler(ctx)
+    }
+
+    pub fn configure_liquidity(
+        ctx: Context<ConfigureLiquidity>,
+        liquidity_bps: u16,
+        listing_rate: u64,
+        liquidity_token_reserve: u64,
+        lp_unlock_date: i64,
+    ) -> Result<()> {
+        instructions::configure_liquidity::configure_liquidity_handler(
+            ctx,
+            liquidity_bps,
+            listing_rate,
+            liquidity_token_reserve,
+            lp_unlock_date,
+        )
+    }
+
+    pub fn withdraw_lp(ctx: Context<WithdrawLp>) -> Result<()> {
+        instructions::lp_lock::withdraw_lp_handler(ctx)
+    }
+
+    pub fn extend_lock(ctx: Context<ExtendLpLock>, unlock_date: i64) -> Result<()> {
+        instructions::lp_lock::extend_handler(ctx, unlock_date)
+    }
+
+    pub fn withdraw_proceeds(ctx: Context<WithdrawProceeds>) -> Result<()> {
+        instructions::withdraw_proceeds::withdraw_proceeds_handler(ctx)
+    }
+
+    pub fn configure_governance(ctx: Context<ConfigureGovernance>, quorum_bps: u16) -> Result<()> {
+        instructions::milestone::configure_governance_handler(ctx, quorum_bps)
+    }
+
+    pub fn propose_milestone(
+        ctx: Context<ProposeMilestone>,
+        amount: u64,
+        deadline: i64,
+    ) -> Result<()> {
+        instructions::milestone::propose_milestone_handler(ctx, amount, deadline)
+    }
+
+    pub fn vote_milestone(ctx: Context<VoteMilestone>, approve: bool) -> Result<()> {
+        instructions::milestone::vote_handler(ctx, approve)
+    }
+
+    pub fn execute_milestone(ctx: Context<ExecuteMilestone>) -> Result<()> {
+        instructions::milestone::execute_handler(ctx)
+    }
+
+    pub fn reclaim_funds(ctx: Context<ReclaimFunds>) -> Result<()> {
+        instructions::milestone::reclaim_handler(ctx)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::transfer_authority::propose_authority_handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::transfer_authority::accept_handler(ctx)
+    }
+
+    pub fn close_user_pool(ctx: Context<CloseUserPool>) -> Result<()> {
+        instructions::close_accounts::close_user_pool_handler(ctx)
+    }
+
+    pub fn close_launch_pool(ctx: Context<CloseLaunchPool>) -> Result<()> {
+        instructions::close_accounts::close_launch_pool_handler(ctx)
+    }
+
+    pub fn finalize_unsold_tokens(ctx: Context<FinalizeUnsoldTokens>) -> Result<()> {
+        instructions::finalize_unsold_tokens::finalize_unsold_tokens_handler(ctx)
+    }
+
+    pub fn withdraw_unsold_tokens(ctx: Context<WithdrawUnsoldTokens>, burn: bool) -> Result<()> {
+        instructions::withdraw_unsold_tokens::withdraw_unsold_tokens_handler(ctx, burn)
+    }
+
+    pub fn configure_team_lock(
+        ctx: Context<ConfigureTeamLock>,
+        amount: u64,
+        cliff_date: i64,
+        vesting_duration: i64,
+    ) -> Result<()> {
+        instructions::team_lock::configure_team_lock_handler(
+            ctx,
+            amount,
+            cliff_date,
+            vesting_duration,
+        )
+    }
+
+    pub fn withdraw_team_tokens(ctx: Context<WithdrawTeamTokens>) -> Result<()> {
+        instructions::team_lock::withdraw_team_tokens_handler(ctx)
+    }
+
+    pub fn update_pool_metadata(
+        ctx: Context<UpdatePoolMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+        website: Option<String>,
+        socials: Vec<String>,
+        content_hash: [u8; CONTENT_HASH_SIZE],
+    ) -> Result<()> {
+        instructions::update_pool_metadata::update_pool_metadata_handler(
+            ctx,
+            name,
+            symbol,
+            uri,
+            website,
+            socials,
+            content_hash,
+        )
+    }
+
+    pub fn migrate_launch_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateLaunchPool<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_launch_pool::migrate_launch_pool_handler(ctx)
+    }
+
+    pub fn create_token_mint(ctx: Context<CreateTokenMint>, decimals: u8) -> Result<()> {
+        instructions::token_mint::create_mint_handler(ctx, decimals)
+    }
+
+    pub fn mint_pool_supply(
+        ctx: Context<MintPoolSupply>,
+        liquidity_amount: u64,
+        team_amount: u64,
+        token_metadata: Option<TokenMetadata>,
+        revoke_authorities: bool,
+    ) -> Result<()> {
+        instructions::token_mint::mint_supply_handler(
+            ctx,
+            liquidity_amount,
+            team_amount,
+            token_metadata,
+            revoke_authorities,
+        )
     }
 }
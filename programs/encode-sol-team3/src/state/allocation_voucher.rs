@@ -0,0 +1,95 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{ed25519_program, sysvar::instructions as sysvar_instructions},
+};
+
+use crate::{
+    constants::{I64_SIZE, PUBKEY_SIZE, U64_SIZE},
+    errors::MyError,
+};
+
+const ED25519_SIGNATURE_OFFSETS_START: usize = 2;
+const ED25519_SIGNATURE_OFFSETS_SIZE: usize = 14;
+const ED25519_PUBKEY_SIZE: usize = 32;
+
+// struct for an allocation signed off-chain by the pool's allocation signer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct AllocationVoucher {
+    pub pool: Pubkey,
+    pub buyer: Pubkey,
+    pub max_amount: u64,
+    pub expiry: i64,
+}
+
+impl AllocationVoucher {
+    pub const LEN: usize = PUBKEY_SIZE + PUBKEY_SIZE + U64_SIZE + I64_SIZE;
+
+    // reads the voucher from the Ed25519 program instruction right before this one
+    pub fn load_verified(instructions_sysvar: &AccountInfo, signer: &Pubkey) -> Result<Self> {
+        let current_index = sysvar_instructions::load_current_index_checked(instructions_sysvar)?;
+        require!(current_index > 0, MyError::InvalidAllocationVoucher);
+
+        let ed25519_ix = sysvar_instructions::load_instruction_at_checked(
+            (current_index - 1) as usize,
+            instructions_sysvar,
+        )?;
+        require!(
+            ed25519_ix.program_id == ed25519_program::ID && ed25519_ix.accounts.is_empty(),
+            MyError::InvalidAllocationVoucher
+        );
+
+        let data = &ed25519_ix.data;
+        require!(
+            data.len() >= ED25519_SIGNATURE_OFFSETS_START + ED25519_SIGNATURE_OFFSETS_SIZE
+                && data[0] == 1,
+            MyError::InvalidAllocationVoucher
+        );
+
+        let offsets = &data[ED25519_SIGNATURE_OFFSETS_START
+            ..ED25519_SIGNATURE_OFFSETS_START + ED25519_SIGNATURE_OFFSETS_SIZE];
+        let read_u16 = |i: usize| u16::from_le_bytes([offsets[i], offsets[i + 1]]);
+        let signature_instruction_index = read_u16(2);
+        let public_key_offset = read_u16(4) as usize;
+        let public_key_instruction_index = read_u16(6);
+        let message_data_offset = read_u16(8) as usize;
+        let message_data_size = read_u16(10) as usize;
+        let message_instruction_index = read_u16(12);
+
+        // the signature, key and message must all live in the Ed25519 instruction itself
+        require!(
+            signature_instruction_index == u16::MAX
+                && public_key_instruction_index == u16::MAX
+                && message_instruction_index == u16::MAX,
+            MyError::InvalidAllocationVoucher
+        );
+        require!(
+            message_data_size == Self::LEN
+                && data.len() >= public_key_offset + ED25519_PUBKEY_SIZE
+                && data.len() >= message_data_offset + message_data_size,
+            MyError::InvalidAllocationVoucher
+        );
+
+        let public_key = &data[public_key_offset..public_key_offset + ED25519_PUBKEY_SIZE];
+        require!(
+            public_key == signer.as_ref(),
+            MyError::InvalidAllocationSigner
+        );
+
+        let mut message = &data[message_data_offset..message_data_offset + message_data_size];
+        AllocationVoucher::deserialize(&mut message)
+            .map_err(|_| error!(MyError::InvalidAllocationVoucher))
+    }
+
+    pub fn verify(&self, pool: &Pubkey, buyer: &Pubkey, total_amount: u64, now: i64) -> Result<()> {
+        require!(
+            self.pool.eq(pool) && self.buyer.eq(buyer),
+            MyError::InvalidAllocationVoucher
+        );
+        require!(self.expiry >= now, MyError::AllocationVoucherExpired);
+        require!(
+            total_amount.le(&self.max_amount),
+            MyError::MaximumTokenAmountReached
+        );
+        Ok(())
+    }
+}
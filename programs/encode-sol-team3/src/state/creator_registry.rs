@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DISCRIMINATOR_SIZE, PUBKEY_SIZE, U64_SIZE};
+
+// struct for the pools launched by a creator, pool i is seeded with its index i
+#[account]
+pub struct CreatorRegistry {
+    pub creator: Pubkey,
+    pub pool_count: u64,
+}
+
+impl CreatorRegistry {
+    pub const LEN: usize = DISCRIMINATOR_SIZE + PUBKEY_SIZE + U64_SIZE;
+}
@@ -2,9 +2,11 @@ use anchor_lang::prelude::*;
 
 use crate::{
     constants::{
-        BOOL_SIZE, CURRENCY_DECIMALS, DISCRIMINATOR_SIZE, I64_SIZE, PUBKEY_SIZE, U64_SIZE, U8_SIZE,
+        BOOL_SIZE, BPS_DENOMINATOR, CURRENCY_DECIMALS, DISCRIMINATOR_SIZE, I64_SIZE,
+        LAUNCH_POOL_RESERVED_SIZE, PUBKEY_SIZE, STAKE_TIERS, U16_SIZE, U32_SIZE, U64_SIZE, U8_SIZE,
     },
     errors::MyError,
+    state::{NftGate, ProceedsVesting, StakeTier, VestingSchedule},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy)]
@@ -31,6 +33,53 @@ pub struct LaunchPool {
     pub currency: CurrencyType,
     pub pool_type: LaunchPoolType,
     pub status: LaunchPoolState,
+    pub round_count: u8,
+    pub current_round: u8,
+    pub rounds_allocation: u64,
+    pub is_tiered: bool,
+    pub tier_caps: [u64; STAKE_TIERS],
+    pub nft_gate: NftGate,
+    pub allocation_signer: Option<Pubkey>,
+    pub referral_bps: u16,
+    pub referral_reward_total: u64,
+    pub liquidity_bps: u16,
+    pub listing_rate: u64,
+    pub liquidity_token_reserve: u64,
+    pub amm_program: Pubkey,
+    pub amm_pool: Pubkey,
+    pub liquidity_sol_amount: u64,
+    pub liquidity_token_amount: u64,
+    pub team_lock_amount: u64,
+    pub proceeds_vesting: Option<ProceedsVesting>,
+    pub proceeds_withdrawn: u64,
+    pub completed_at: i64,
+    pub is_governed: bool,
+    pub quorum_bps: u16,
+    pub milestone_count: u8,
+    pub has_open_milestone: bool,
+    pub milestone_released: u64,
+    pub is_refunding: bool,
+    pub refundable_amount: u64,
+    pub creator: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+    pub index: u64,
+    pub bumps: LaunchPoolBumps,
+    pub open_positions: u32,
+    pub unsold_withdrawn: bool,
+    // none for pools whose authority settles the unsold supply with withdraw_unsold_tokens
+    pub unsold_policy: Option<UnsoldPolicy>,
+    pub version: u8,
+    // supply minted into the treasury at creation, zero when the creator deposits it at start
+    pub minted_supply: u64,
+    // tokens of the positions refunded so far and the lamports paid for them
+    pub refunded_sold_amount: u64,
+    pub refunded_total: u64,
+    pub referral_reward_claimed: u64,
+    // tokens of the positions that have claimed, which can no longer be refunded
+    pub claimed_sold_amount: u64,
+    // tokens of the unclaimed positions the refund is split over, set when refunding opens
+    pub refund_sold_amount: u64,
+    pub reserved: [u8; LAUNCH_POOL_RESERVED_SIZE],
 }
 
 // enum for currency token type
@@ -76,7 +125,18 @@ pub enum LaunchPoolState {
     Cancelled,
 }
 
+// enum for what happens to the unsold supply once the pool completes
+#[derive(AnchorDeserialize, AnchorSerialize, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum UnsoldPolicy {
+    RefundToCreator,
+    Burn,
+    SendToLiquidity,
+}
+
 impl LaunchPool {
+    // layout written by this program, accounts of an older layout go through migrate_launch_pool
+    pub const VERSION: u8 = 1;
+
     pub const LEN: usize = DISCRIMINATOR_SIZE +
         I64_SIZE +
         U64_SIZE +
@@ -94,8 +154,56 @@ impl LaunchPool {
         1 +
         1 + // enum LaunchPoolType
         1 +
-        1; // enum LaunchPoolState
+        1 + // enum LaunchPoolState
+        U8_SIZE + // round_count
+        U8_SIZE + // current_round
+        U64_SIZE + // rounds_allocation
+        BOOL_SIZE + // is_tiered
+        U64_SIZE * STAKE_TIERS + // tier_caps
+        NftGate::LEN +
+        U8_SIZE +
+        PUBKEY_SIZE + // Option<Pubkey> allocation_signer
+        U16_SIZE + // referral_bps
+        U64_SIZE + // referral_reward_total
+        U16_SIZE + // liquidity_bps
+        U64_SIZE + // listing_rate
+        U64_SIZE + // liquidity_token_reserve
+        PUBKEY_SIZE + // amm_program
+        PUBKEY_SIZE + // amm_pool
+        U64_SIZE + // liquidity_sol_amount
+        U64_SIZE + // liquidity_token_amount
+        U64_SIZE + // team_lock_amount
+        U8_SIZE +
+        ProceedsVesting::LEN + // Option<ProceedsVesting> proceeds_vesting
+        U64_SIZE + // proceeds_withdrawn
+        I64_SIZE + // completed_at
+        BOOL_SIZE + // is_governed
+        U16_SIZE + // quorum_bps
+        U8_SIZE + // milestone_count
+        BOOL_SIZE + // has_open_milestone
+        U64_SIZE + // milestone_released
+        BOOL_SIZE + // is_refunding
+        U64_SIZE + // refundable_amount
+        PUBKEY_SIZE + // creator
+        U8_SIZE +
+        PUBKEY_SIZE + // Option<Pubkey> pending_authority
+        U64_SIZE + // index
+        U8_SIZE * 3 + // LaunchPoolBumps bumps
+        U32_SIZE + // open_positions
+        BOOL_SIZE + // unsold_withdrawn
+        U8_SIZE +
+        1 +
+        1 + // Option<enum UnsoldPolicy> unsold_policy
+        U8_SIZE + // version
+        U64_SIZE + // minted_supply
+        U64_SIZE + // refunded_sold_amount
+        U64_SIZE + // refunded_total
+        U64_SIZE + // referral_reward_claimed
+        U64_SIZE + // claimed_sold_amount
+        U64_SIZE + // refund_sold_amount
+        LAUNCH_POOL_RESERVED_SIZE; // reserved
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         &mut self,
         unlock_date: i64,
@@ -128,16 +236,196 @@ impl LaunchPool {
         self.pool_type = pool_type;
         self.status = LaunchPoolState::Pending;
         self.is_vesting = false;
+        self.round_count = 0;
+        self.current_round = 0;
+        self.rounds_allocation = 0;
+        self.is_tiered = false;
+        self.tier_caps = [0; STAKE_TIERS];
+        self.nft_gate = NftGate::default();
+        self.allocation_signer = None;
+        self.referral_bps = 0;
+        self.referral_reward_total = 0;
+        self.liquidity_bps = 0;
+        self.listing_rate = 0;
+        self.liquidity_token_reserve = 0;
+        self.amm_program = Pubkey::default();
+        self.amm_pool = Pubkey::default();
+        self.liquidity_sol_amount = 0;
+        self.liquidity_token_amount = 0;
+        self.team_lock_amount = 0;
+        self.proceeds_vesting = None;
+        self.proceeds_withdrawn = 0;
+        self.completed_at = 0;
+        self.is_governed = false;
+        self.quorum_bps = 0;
+        self.milestone_count = 0;
+        self.has_open_milestone = false;
+        self.milestone_released = 0;
+        self.is_refunding = false;
+        self.refundable_amount = 0;
+        // the creator seeds the pool and its vault, so it never changes with the authority
+        self.creator = authority;
+        self.pending_authority = None;
+        self.index = 0;
+        self.bumps = LaunchPoolBumps::default();
+        self.open_positions = 0;
+        self.unsold_withdrawn = false;
+        self.unsold_policy = None;
+        self.version = Self::VERSION;
+        self.minted_supply = 0;
+        self.refunded_sold_amount = 0;
+        self.refunded_total = 0;
+        self.referral_reward_claimed = 0;
+        self.claimed_sold_amount = 0;
+        self.refund_sold_amount = 0;
+        self.reserved = [0; LAUNCH_POOL_RESERVED_SIZE];
         Ok(())
     }
 
-    pub fn calculate_user_must_pay(&self, amount: u64) -> u64 {
-        ((amount
-            .checked_div(self.rate)
+    // fields appended since the account's version are zeroed by the realloc,
+    // only those whose default is not zero need a value here
+    pub fn migrate(&mut self, bumps: LaunchPoolBumps) {
+        // the first layout had neither a creator nor stored bumps, its authority created the pool
+        if self.version == 0 {
+            self.creator = self.authority;
+            self.bumps = bumps;
+        }
+        self.version = Self::VERSION;
+    }
+
+    pub fn has_sale_rounds(&self) -> bool {
+        self.round_count > 0
+    }
+
+    // tiered pools cap each wallet by its stake tier instead of maximum_token_amount
+    pub fn maximum_token_amount_for(&self, tier: Option<StakeTier>) -> Result<u64> {
+        if !self.is_tiered {
+            return Ok(self.maximum_token_amount);
+        }
+
+        match tier {
+            Some(StakeTier::Unranked) | None => err!(MyError::StakeTierRequired),
+            Some(tier) => Ok(self.tier_caps[tier as usize - 1]),
+        }
+    }
+
+    // buyers get everything at unlock_date
+    pub fn buyer_vesting(&self) -> VestingSchedule {
+        VestingSchedule {
+            cliff_date: self.unlock_date,
+            end_date: self.unlock_date,
+        }
+    }
+
+    pub fn seeds_liquidity(&self) -> bool {
+        self.liquidity_bps > 0
+    }
+
+    // returns the (sol, token) amounts to deposit in the AMM when the pool completes
+    pub fn calculate_liquidity_amounts(&self) -> Result<(u64, u64)> {
+        let proceeds = self
+            .vault_amount
+            .checked_sub(self.referral_reward_total)
+            .ok_or(MyError::Overflow)?;
+        let sol_amount =
+            ((proceeds as u128) * (self.liquidity_bps as u128) / (BPS_DENOMINATOR as u128)) as u64;
+        let token_amount = ((sol_amount as u128)
+            * (self.listing_rate as u128)
+            * 10_u128.pow(self.token_mint_decimals as u32)
+            / 10_u128.pow(CURRENCY_DECIMALS)) as u64;
+
+        // never list more tokens than the creator reserved, keep the listing price instead
+        if token_amount > self.liquidity_token_reserve {
+            let sol_amount = ((sol_amount as u128) * (self.liquidity_token_reserve as u128)
+                / (token_amount as u128)) as u64;
+            return Ok((sol_amount, self.liquidity_token_reserve));
+        }
+
+        Ok((sol_amount, token_amount))
+    }
+
+    // what the creator can take from the vault once rewards and liquidity are set aside
+    pub fn distributable_proceeds(&self) -> u64 {
+        self.vault_amount
+            .checked_sub(self.referral_reward_total)
+            .unwrap()
+            .checked_sub(self.liquidity_sol_amount)
             .unwrap()
-            .checked_mul(10_i32.pow(CURRENCY_DECIMALS) as u64))
-        .unwrap() as u128)
-            .checked_div(10_i32.pow(self.token_mint_decimals as u32) as u128)
-            .unwrap() as u64
+    }
+
+    pub fn released_proceeds(&self, now: i64) -> u64 {
+        let total = self.distributable_proceeds();
+        match &self.proceeds_vesting {
+            Some(vesting) => vesting.released_amount(total, self.completed_at, now),
+            None => total,
+        }
+    }
+
+    // tokens bought by all buyers, the total voting weight of a governed pool
+    pub fn sold_amount(&self) -> u64 {
+        self.pool_size
+            .checked_sub(self.pool_size_remaining)
+            .unwrap()
+    }
+
+    // proceeds still held in the vault for milestones or refunds
+    pub fn unreleased_proceeds(&self) -> u64 {
+        self.distributable_proceeds()
+            .checked_sub(self.milestone_released)
+            .unwrap()
+    }
+
+    // whether every lamport of the proceeds went to the creator or back to the buyers,
+    // so closing the pool only sweeps rent and rounding dust out of the vault
+    pub fn proceeds_settled(&self) -> bool {
+        if !self.is_governed {
+            // only the whole release, after the vesting ran out, can have been withdrawn
+            return self.proceeds_withdrawn == self.distributable_proceeds();
+        }
+        if self.is_refunding {
+            // each unclaimed position reclaimed, so the refunds paid out the whole refundable amount
+            return self.refunded_sold_amount == self.refund_sold_amount;
+        }
+        self.milestone_released == self.distributable_proceeds()
+    }
+
+    // a buyer's share of the refund, split over the positions that had not claimed and
+    // rounded on the running total so the last buyer to reclaim takes whatever earlier
+    // rounding left in the vault
+    pub fn refund_for(&self, amount: u64) -> u64 {
+        let refunded_sold_amount = self.refunded_sold_amount.checked_add(amount).unwrap();
+        let refunded_total = (self.refundable_amount as u128) * (refunded_sold_amount as u128)
+            / (self.refund_sold_amount as u128);
+        (refunded_total as u64)
+            .checked_sub(self.refunded_total)
+            .unwrap()
+    }
+
+    // tokens in the treasury that no buyer can claim: the unsold supply and the unused listing reserve
+    pub fn unsold_amount(&self) -> u64 {
+        self.pool_size_remaining
+            .checked_add(
+                self.liquidity_token_reserve
+                    .checked_sub(self.liquidity_token_amount)
+                    .unwrap(),
+            )
+            .unwrap()
+    }
+
+    pub fn calculate_user_must_pay(&self, amount: u64) -> u64 {
+        self.calculate_user_must_pay_at_rate(amount, self.rate)
+    }
+
+    pub fn calculate_user_must_pay_at_rate(&self, amount: u64, rate: u64) -> u64 {
+        self.checked_user_must_pay_at_rate(amount, rate).unwrap()
+    }
+
+    // None when the rate is zero or the price does not fit, for callers that must not panic
+    pub fn checked_user_must_pay_at_rate(&self, amount: u64, rate: u64) -> Option<u64> {
+        let lamports = amount
+            .checked_div(rate)?
+            .checked_mul(10_u64.pow(CURRENCY_DECIMALS))?;
+        u64::try_from((lamports as u128).checked_div(10_u128.pow(self.token_mint_decimals as u32))?)
+            .ok()
     }
 }
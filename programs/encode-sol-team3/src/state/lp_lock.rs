@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DISCRIMINATOR_SIZE, I64_SIZE, PUBKEY_SIZE, U64_SIZE, U8_SIZE};
+
+// struct for the LP tokens of a launch pool, locked until unlock_date
+#[account]
+pub struct LpLock {
+    pub launch_pool: Pubkey,
+    pub lp_mint: Pubkey,
+    pub lp_token_account: Pubkey,
+    pub amount: u64,
+    pub unlock_date: i64,
+    pub bump: u8,
+}
+
+impl LpLock {
+    pub const LEN: usize = DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE
+        + PUBKEY_SIZE
+        + PUBKEY_SIZE
+        + U64_SIZE
+        + I64_SIZE
+        + U8_SIZE; // bump
+}
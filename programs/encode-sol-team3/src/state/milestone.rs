@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    BOOL_SIZE, BPS_DENOMINATOR, DISCRIMINATOR_SIZE, I64_SIZE, PUBKEY_SIZE, U64_SIZE, U8_SIZE,
+};
+
+// struct for a release of raised funds proposed by the creator and voted on by buyers
+#[account]
+pub struct Milestone {
+    pub launch_pool: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub deadline: i64,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub status: MilestoneState,
+}
+
+// enum for milestone status
+#[derive(AnchorDeserialize, AnchorSerialize, PartialEq, Eq, Clone, Copy)]
+pub enum MilestoneState {
+    Voting,
+    Executed,
+    Rejected,
+}
+
+impl Milestone {
+    pub const LEN: usize = DISCRIMINATOR_SIZE +
+        PUBKEY_SIZE +
+        U8_SIZE + // index
+        U64_SIZE +
+        I64_SIZE +
+        U64_SIZE +
+        U64_SIZE +
+        1 +
+        1; // enum MilestoneState
+
+    pub fn is_voting(&self, now: i64) -> bool {
+        self.status == MilestoneState::Voting && now < self.deadline
+    }
+
+    // a milestone passes with quorum of the sold tokens and more votes for than against
+    pub fn is_approved(&self, total_weight: u64, quorum_bps: u16) -> bool {
+        let turnout = (self.votes_for as u128) + (self.votes_against as u128);
+        let quorum = (total_weight as u128) * (quorum_bps as u128) / (BPS_DENOMINATOR as u128);
+        turnout >= quorum && self.votes_for > self.votes_against
+    }
+}
+
+// struct for a buyer's vote on a milestone, weighted by the tokens bought
+#[account]
+pub struct Vote {
+    pub milestone: Pubkey,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub approve: bool,
+}
+
+impl Vote {
+    pub const LEN: usize = DISCRIMINATOR_SIZE + PUBKEY_SIZE + PUBKEY_SIZE + U64_SIZE + BOOL_SIZE;
+}
@@ -6,3 +6,36 @@ pub use user_pool::*;
 
 pub mod treasurer;
 pub use treasurer::*;
+
+pub mod sale_round;
+pub use sale_round::*;
+
+pub mod staking;
+pub use staking::*;
+
+pub mod nft_gate;
+pub use nft_gate::*;
+
+pub mod allocation_voucher;
+pub use allocation_voucher::*;
+
+pub mod referral;
+pub use referral::*;
+
+pub mod lp_lock;
+pub use lp_lock::*;
+
+pub mod vesting;
+pub use vesting::*;
+
+pub mod team_lock;
+pub use team_lock::*;
+
+pub mod milestone;
+pub use milestone::*;
+
+pub mod creator_registry;
+pub use creator_registry::*;
+
+pub mod pool_metadata;
+pub use pool_metadata::*;
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::MetadataAccount;
+
+use crate::{
+    constants::{
+        BOOL_SIZE, DISCRIMINATOR_SIZE, MAX_NFT_GATE_CREATORS, PUBKEY_SIZE, U8_SIZE,
+        VECTOR_OVERHEAD_SIZE,
+    },
+    errors::MyError,
+};
+
+// struct for the NFT holders allowed to buy in a launch pool
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct NftGate {
+    pub is_enabled: bool,
+    pub collection: Option<Pubkey>,
+    pub creators: Vec<Pubkey>,
+    pub single_use: bool,
+}
+
+impl NftGate {
+    pub const LEN: usize = BOOL_SIZE + // is_enabled
+        U8_SIZE +
+        PUBKEY_SIZE + // Option<Pubkey> collection
+        VECTOR_OVERHEAD_SIZE +
+        PUBKEY_SIZE * MAX_NFT_GATE_CREATORS +
+        BOOL_SIZE; // single_use
+
+    pub fn new(
+        collection: Option<Pubkey>,
+        creators: Vec<Pubkey>,
+        single_use: bool,
+    ) -> Result<Self> {
+        require!(
+            collection.is_some() || !creators.is_empty(),
+            MyError::InvalidNftGate
+        );
+        require!(
+            creators.len() <= MAX_NFT_GATE_CREATORS,
+            MyError::InvalidNftGate
+        );
+
+        Ok(Self {
+            is_enabled: true,
+            collection,
+            creators,
+            single_use,
+        })
+    }
+
+    // the NFT must be in the verified collection, or be verified by one of the creators
+    pub fn verify(&self, metadata: &MetadataAccount) -> Result<()> {
+        let in_collection = match (&self.collection, &metadata.collection) {
+            (Some(collection), Some(nft_collection)) => {
+                nft_collection.verified && nft_collection.key.eq(collection)
+            }
+            _ => false,
+        };
+
+        let by_creator = metadata.creators.as_ref().is_some_and(|creators| {
+            creators
+                .iter()
+                .any(|creator| creator.verified && self.creators.contains(&creator.address))
+        });
+
+        require!(in_collection || by_creator, MyError::NftNotInCollection);
+        Ok(())
+    }
+}
+
+// struct for a NFT that was already used to buy in a launch pool
+#[account]
+pub struct NftUsage {
+    pub launch_pool: Pubkey,
+    pub nft_mint: Pubkey,
+    pub user: Pubkey,
+}
+
+impl NftUsage {
+    pub const LEN: usize = DISCRIMINATOR_SIZE + PUBKEY_SIZE + PUBKEY_SIZE + PUBKEY_SIZE;
+
+    // the first wallet to use the NFT keeps it, any other wallet is rejected
+    pub fn use_by(&mut self, launch_pool: Pubkey, nft_mint: Pubkey, user: Pubkey) -> Result<()> {
+        if self.user == Pubkey::default() {
+            self.launch_pool = launch_pool;
+            self.nft_mint = nft_mint;
+            self.user = user;
+        }
+
+        require!(self.user == user, MyError::NftAlreadyUsed);
+        Ok(())
+    }
+}
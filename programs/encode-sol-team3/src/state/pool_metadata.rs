@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        CONTENT_HASH_SIZE, DISCRIMINATOR_SIZE, MAX_POOL_NAME_LENGTH, MAX_POOL_SOCIALS,
+        MAX_POOL_SYMBOL_LENGTH, MAX_POOL_URI_LENGTH, PUBKEY_SIZE, STRING_PREFIX_SIZE, U8_SIZE,
+        VECTOR_OVERHEAD_SIZE,
+    },
+    errors::MyError,
+};
+
+// struct for what a launch pool sells, shown by UIs next to the pool
+#[account]
+pub struct PoolMetadata {
+    pub launch_pool: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub website: Option<String>,
+    pub socials: Vec<String>,
+    // sha256 of the JSON document at uri
+    pub content_hash: [u8; CONTENT_HASH_SIZE],
+    pub bump: u8,
+}
+
+impl PoolMetadata {
+    pub const LEN: usize = DISCRIMINATOR_SIZE +
+        PUBKEY_SIZE + // launch_pool
+        STRING_PREFIX_SIZE +
+        MAX_POOL_NAME_LENGTH + // name
+        STRING_PREFIX_SIZE +
+        MAX_POOL_SYMBOL_LENGTH + // symbol
+        STRING_PREFIX_SIZE +
+        MAX_POOL_URI_LENGTH + // uri
+        U8_SIZE +
+        STRING_PREFIX_SIZE +
+        MAX_POOL_URI_LENGTH + // Option<String> website
+        VECTOR_OVERHEAD_SIZE +
+        (STRING_PREFIX_SIZE + MAX_POOL_URI_LENGTH) * MAX_POOL_SOCIALS + // socials
+        CONTENT_HASH_SIZE + // content_hash
+        U8_SIZE; // bump
+
+    pub fn update(
+        &mut self,
+        name: String,
+        symbol: String,
+        uri: String,
+        website: Option<String>,
+        socials: Vec<String>,
+        content_hash: [u8; CONTENT_HASH_SIZE],
+    ) -> Result<()> {
+        require!(
+            !name.is_empty() && name.len() <= MAX_POOL_NAME_LENGTH,
+            MyError::InvalidPoolMetadata
+        );
+        require!(
+            !symbol.is_empty() && symbol.len() <= MAX_POOL_SYMBOL_LENGTH,
+            MyError::InvalidPoolMetadata
+        );
+        require!(
+            socials.len() <= MAX_POOL_SOCIALS,
+            MyError::InvalidPoolMetadata
+        );
+        // every link is a url of the same bounded length as the document uri
+        for url in std::iter::once(&uri)
+            .chain(website.iter())
+            .chain(socials.iter())
+        {
+            require!(
+                !url.is_empty() && url.len() <= MAX_POOL_URI_LENGTH,
+                MyError::InvalidPoolMetadata
+            );
+        }
+
+        self.name = name;
+        self.symbol = symbol;
+        self.uri = uri;
+        self.website = website;
+        self.socials = socials;
+        self.content_hash = content_hash;
+        Ok(())
+    }
+}
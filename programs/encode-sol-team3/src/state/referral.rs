@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BPS_DENOMINATOR, DISCRIMINATOR_SIZE, I64_SIZE, PUBKEY_SIZE, U32_SIZE, U64_SIZE},
+    errors::MyError,
+};
+
+// struct for a wallet registered as a referrer on the platform
+#[account]
+pub struct Referrer {
+    pub wallet: Pubkey,
+    pub registered_at: i64,
+}
+
+impl Referrer {
+    pub const LEN: usize = DISCRIMINATOR_SIZE + PUBKEY_SIZE + I64_SIZE;
+}
+
+// struct for the purchases referred by a wallet in a launch pool
+#[account]
+pub struct ReferralStats {
+    pub launch_pool: Pubkey,
+    pub referrer: Pubkey,
+    pub referred_count: u32,
+    pub referred_amount: u64,
+    pub referred_currency_amount: u64,
+    pub reward: u64,
+    pub claimed: u64,
+}
+
+impl ReferralStats {
+    pub const LEN: usize = DISCRIMINATOR_SIZE +
+        PUBKEY_SIZE +
+        PUBKEY_SIZE +
+        U32_SIZE + // referred_count
+        U64_SIZE +
+        U64_SIZE +
+        U64_SIZE +
+        U64_SIZE;
+
+    // returns the reward accrued for this purchase
+    pub fn accrue(&mut self, amount: u64, currency_amount: u64, referral_bps: u16) -> Result<u64> {
+        let reward = (currency_amount as u128)
+            .checked_mul(referral_bps as u128)
+            .ok_or(MyError::Overflow)?
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(MyError::Overflow)? as u64;
+
+        self.referred_count = self
+            .referred_count
+            .checked_add(1)
+            .ok_or(MyError::Overflow)?;
+        self.referred_amount = self
+            .referred_amount
+            .checked_add(amount)
+            .ok_or(MyError::Overflow)?;
+        self.referred_currency_amount = self
+            .referred_currency_amount
+            .checked_add(currency_amount)
+            .ok_or(MyError::Overflow)?;
+        self.reward = self.reward.checked_add(reward).ok_or(MyError::Overflow)?;
+        Ok(reward)
+    }
+
+    pub fn unclaimed(&self) -> u64 {
+        self.reward.checked_sub(self.claimed).unwrap()
+    }
+}
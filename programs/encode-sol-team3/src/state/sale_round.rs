@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        BOOL_SIZE, DISCRIMINATOR_SIZE, I64_SIZE, MAX_WHITELIST_WALLETS, PUBKEY_SIZE, U64_SIZE,
+        U8_SIZE, VECTOR_OVERHEAD_SIZE,
+    },
+    errors::MyError,
+};
+
+// struct for a sale round (private, whitelist, public...) of a launch pool
+#[account]
+pub struct SaleRound {
+    pub launch_pool: Pubkey,
+    pub index: u8,
+    pub rate: u64,
+    pub minimum_token_amount: u64,
+    pub maximum_token_amount: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub allocation: u64,
+    pub sold: u64,
+    pub access: SaleRoundAccess,
+    pub is_closed: bool,
+}
+
+// enum for who can buy in a sale round
+#[derive(AnchorDeserialize, AnchorSerialize, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum SaleRoundAccess {
+    Public,
+    Whitelist,
+}
+
+impl SaleRound {
+    pub const LEN: usize = DISCRIMINATOR_SIZE +
+        PUBKEY_SIZE +
+        U8_SIZE + // index
+        U64_SIZE +
+        U64_SIZE +
+        U64_SIZE +
+        I64_SIZE +
+        I64_SIZE +
+        U64_SIZE +
+        U64_SIZE +
+        1 + // enum SaleRoundAccess
+        BOOL_SIZE; // is_closed
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        launch_pool: Pubkey,
+        index: u8,
+        rate: u64,
+        minimum_token_amount: u64,
+        maximum_token_amount: u64,
+        start_time: i64,
+        end_time: i64,
+        allocation: u64,
+        access: SaleRoundAccess,
+    ) -> Result<()> {
+        require!(rate.gt(&0), MyError::InvalidAmount);
+        require!(
+            minimum_token_amount.le(&maximum_token_amount),
+            MyError::InvalidAmount
+        );
+        require!(start_time.lt(&end_time), MyError::InvalidSaleRoundTime);
+
+        self.launch_pool = launch_pool;
+        self.index = index;
+        self.rate = rate;
+        self.minimum_token_amount = minimum_token_amount;
+        self.maximum_token_amount = maximum_token_amount;
+        self.start_time = start_time;
+        self.end_time = end_time;
+        self.allocation = allocation;
+        self.sold = 0;
+        self.access = access;
+        self.is_closed = false;
+        Ok(())
+    }
+
+    pub fn is_open(&self, now: i64) -> bool {
+        !self.is_closed && self.start_time <= now && now < self.end_time
+    }
+
+    pub fn allocation_remaining(&self) -> u64 {
+        self.allocation.checked_sub(self.sold).unwrap()
+    }
+}
+
+// struct for the wallets allowed to buy in a whitelisted sale round
+#[account]
+pub struct Whitelist {
+    pub sale_round: Pubkey,
+    pub wallets: Vec<Pubkey>,
+}
+
+impl Whitelist {
+    pub const LEN: usize = DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE
+        + VECTOR_OVERHEAD_SIZE
+        + PUBKEY_SIZE * MAX_WHITELIST_WALLETS;
+
+    pub fn add_wallets(&mut self, wallets: Vec<Pubkey>) -> Result<()> {
+        require!(!wallets.is_empty(), MyError::WalletsMustNotBeEmpty);
+        require!(
+            self.wallets.len() < MAX_WHITELIST_WALLETS,
+            MyError::WhitelistFulled
+        );
+        require!(
+            self.wallets.len() + wallets.len() <= MAX_WHITELIST_WALLETS,
+            MyError::WhitelistNotEnoughSpace
+        );
+
+        for wallet in wallets {
+            require!(!self.wallets.contains(&wallet), MyError::WalletAlreadyAdded);
+            self.wallets.push(wallet);
+        }
+        Ok(())
+    }
+
+    pub fn remove_wallet(&mut self, wallet: Pubkey) -> Result<()> {
+        let position = self
+            .wallets
+            .iter()
+            .position(|w| w.eq(&wallet))
+            .ok_or(MyError::WalletNotInList)?;
+        self.wallets.swap_remove(position);
+        Ok(())
+    }
+
+    pub fn contains(&self, wallet: &Pubkey) -> bool {
+        self.wallets.contains(wallet)
+    }
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, I64_SIZE, PUBKEY_SIZE, STAKE_TIERS, U64_SIZE, U8_SIZE},
+    errors::MyError,
+};
+
+// struct for the minimum stake needed to reach a tier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy)]
+pub struct TierThreshold {
+    pub minimum_amount: u64,
+    pub minimum_lock_duration: i64,
+}
+
+impl TierThreshold {
+    pub const LEN: usize = U64_SIZE + I64_SIZE;
+}
+
+// struct for the platform wide staking settings
+#[account]
+pub struct StakingConfig {
+    pub authority: Pubkey,
+    pub stake_mint: Pubkey,
+    pub total_staked: u64,
+    pub tier_thresholds: [TierThreshold; STAKE_TIERS],
+    pub bump: u8,
+}
+
+// enum for the allocation tier of a staker
+#[derive(AnchorDeserialize, AnchorSerialize, PartialEq, Eq, Clone, Copy)]
+pub enum StakeTier {
+    Unranked,
+    Bronze,
+    Silver,
+    Gold,
+}
+
+impl From<u8> for StakeTier {
+    fn from(val: u8) -> Self {
+        match val {
+            0 => StakeTier::Unranked,
+            1 => StakeTier::Bronze,
+            2 => StakeTier::Silver,
+            3 => StakeTier::Gold,
+            _ => panic!("Invalid StakeTier"),
+        }
+    }
+}
+
+// struct for the platform token staked by a wallet
+#[account]
+pub struct StakeAccount {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub lock_start: i64,
+    pub lock_end: i64,
+    pub tier: StakeTier,
+}
+
+impl StakingConfig {
+    pub const LEN: usize = DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE
+        + PUBKEY_SIZE
+        + U64_SIZE
+        + TierThreshold::LEN * STAKE_TIERS
+        + U8_SIZE; // bump
+
+    pub fn set_tier_thresholds(
+        &mut self,
+        tier_thresholds: [TierThreshold; STAKE_TIERS],
+    ) -> Result<()> {
+        // every tier must ask for at least as much as the one below it
+        for i in 1..STAKE_TIERS {
+            require!(
+                tier_thresholds[i].minimum_amount >= tier_thresholds[i - 1].minimum_amount
+                    && tier_thresholds[i].minimum_lock_duration
+                        >= tier_thresholds[i - 1].minimum_lock_duration,
+                MyError::InvalidStakeTiers
+            );
+        }
+        require!(
+            tier_thresholds[0].minimum_amount > 0,
+            MyError::InvalidStakeTiers
+        );
+
+        self.tier_thresholds = tier_thresholds;
+        Ok(())
+    }
+
+    pub fn tier_for(&self, amount: u64, lock_duration: i64) -> StakeTier {
+        let reached = self
+            .tier_thresholds
+            .iter()
+            .take_while(|t| amount >= t.minimum_amount && lock_duration >= t.minimum_lock_duration)
+            .count();
+        StakeTier::from(reached as u8)
+    }
+}
+
+impl StakeAccount {
+    pub const LEN: usize =
+        DISCRIMINATOR_SIZE + PUBKEY_SIZE + U64_SIZE + I64_SIZE + I64_SIZE + 1 + 1; // enum StakeTier
+
+    pub fn lock_duration(&self) -> i64 {
+        self.lock_end.saturating_sub(self.lock_start)
+    }
+
+    // a stake only counts towards allocations while it is still locked
+    pub fn active_tier(&self, now: i64) -> StakeTier {
+        if self.lock_end > now {
+            self.tier
+        } else {
+            StakeTier::Unranked
+        }
+    }
+}
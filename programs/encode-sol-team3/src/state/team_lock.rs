@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_SIZE, PUBKEY_SIZE, U64_SIZE, U8_SIZE},
+    state::VestingSchedule,
+};
+
+// struct for the creator's own token allocation, released on a vesting schedule
+#[account]
+pub struct TeamLock {
+    pub launch_pool: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+    pub withdrawn: u64,
+    pub schedule: VestingSchedule,
+    pub bump: u8,
+}
+
+impl TeamLock {
+    pub const LEN: usize = DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE
+        + PUBKEY_SIZE
+        + U64_SIZE
+        + U64_SIZE
+        + VestingSchedule::LEN
+        + U8_SIZE; // bump
+}
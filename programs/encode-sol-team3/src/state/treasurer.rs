@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{DISCRIMINATOR_SIZE, PUBKEY_SIZE, U64_SIZE};
+use crate::constants::{
+    DISCRIMINATOR_SIZE, PUBKEY_SIZE, TREASURER_RESERVED_SIZE, U64_SIZE, U8_SIZE,
+};
 
 #[account]
 pub struct Treasurer {
@@ -8,15 +10,31 @@ pub struct Treasurer {
     pub launch_pool: Pubkey,
     pub token_mint: Pubkey,
     pub amount: u64,
+    pub version: u8,
+    pub reserved: [u8; TREASURER_RESERVED_SIZE],
 }
 
 impl Treasurer {
-    pub const LEN: usize = DISCRIMINATOR_SIZE + PUBKEY_SIZE + PUBKEY_SIZE + PUBKEY_SIZE + U64_SIZE;
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = DISCRIMINATOR_SIZE
+        + PUBKEY_SIZE
+        + PUBKEY_SIZE
+        + PUBKEY_SIZE
+        + U64_SIZE
+        + U8_SIZE // version
+        + TREASURER_RESERVED_SIZE; // reserved
 
     pub fn initialize(&mut self, authority: Pubkey, launch_pool: Pubkey, token_mint: Pubkey) {
         self.authority = authority;
         self.launch_pool = launch_pool;
         self.token_mint = token_mint;
         self.amount = 0;
+        self.version = Self::VERSION;
+        self.reserved = [0; TREASURER_RESERVED_SIZE];
+    }
+
+    pub fn migrate(&mut self) {
+        self.version = Self::VERSION;
     }
 }
@@ -1,14 +1,38 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{DISCRIMINATOR_SIZE, U64_SIZE};
+use crate::constants::{
+    DISCRIMINATOR_SIZE, MAX_SALE_ROUNDS, U64_SIZE, U8_SIZE, USER_POOL_RESERVED_SIZE,
+};
 
 #[account]
 pub struct UserPool {
     pub amount: u64,
     pub currency_amount: u64,
     pub claimed: u64,
+    pub round_amounts: [u64; MAX_SALE_ROUNDS],
+    pub refunded: u64,
+    pub version: u8,
+    pub reserved: [u8; USER_POOL_RESERVED_SIZE],
 }
 
 impl UserPool {
-    pub const LEN: usize = DISCRIMINATOR_SIZE + U64_SIZE + U64_SIZE + U64_SIZE;
+    pub const VERSION: u8 = 1;
+
+    pub const LEN: usize = DISCRIMINATOR_SIZE
+        + U64_SIZE
+        + U64_SIZE
+        + U64_SIZE
+        + U64_SIZE * MAX_SALE_ROUNDS
+        + U64_SIZE
+        + U8_SIZE // version
+        + USER_POOL_RESERVED_SIZE; // reserved
+
+    // a position is settled once every token is claimed or the buyer took a refund
+    pub fn is_settled(&self) -> bool {
+        self.claimed == self.amount || self.refunded > 0
+    }
+
+    pub fn migrate(&mut self) {
+        self.version = Self::VERSION;
+    }
 }
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BPS_DENOMINATOR, I64_SIZE, U16_SIZE},
+    errors::MyError,
+};
+
+// struct for a cliff followed by a linear release until end_date
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct VestingSchedule {
+    pub cliff_date: i64,
+    pub end_date: i64,
+}
+
+impl VestingSchedule {
+    pub const LEN: usize = I64_SIZE + I64_SIZE;
+
+    pub fn new(cliff_date: i64, vesting_duration: i64) -> Result<Self> {
+        require!(vesting_duration >= 0, MyError::InvalidVestingPlan);
+
+        Ok(Self {
+            cliff_date,
+            end_date: cliff_date
+                .checked_add(vesting_duration)
+                .ok_or(MyError::Overflow)?,
+        })
+    }
+
+    // how much of total is released at now, nothing can be released before the cliff
+    pub fn vested_amount(&self, total: u64, now: i64) -> Result<u64> {
+        require!(self.cliff_date <= now, MyError::TimeLockNotExpired);
+
+        if now >= self.end_date {
+            return Ok(total);
+        }
+
+        let elapsed = (now - self.cliff_date) as u128;
+        let duration = (self.end_date - self.cliff_date) as u128;
+        Ok(((total as u128) * elapsed / duration) as u64)
+    }
+}
+
+// struct for proceeds released partly at completion and the rest in equal periods
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ProceedsVesting {
+    pub completion_bps: u16,
+    pub period: i64,
+    pub periods: u16,
+}
+
+impl ProceedsVesting {
+    pub const LEN: usize = U16_SIZE + I64_SIZE + U16_SIZE;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (self.completion_bps as u64).le(&BPS_DENOMINATOR),
+            MyError::InvalidBasisPoints
+        );
+        require!(
+            (self.completion_bps as u64) == BPS_DENOMINATOR
+                || (self.period > 0 && self.periods > 0),
+            MyError::InvalidVestingPlan
+        );
+        // released_amount divides by the period whenever there are periods
+        require!(
+            self.periods == 0 || self.period > 0,
+            MyError::InvalidVestingPlan
+        );
+        Ok(())
+    }
+
+    // how much of total can be released at now for a pool completed at completed_at
+    pub fn released_amount(&self, total: u64, completed_at: i64, now: i64) -> u64 {
+        let at_completion =
+            (total as u128) * (self.completion_bps as u128) / (BPS_DENOMINATOR as u128);
+        if self.periods == 0 || now <= completed_at {
+            return at_completion as u64;
+        }
+
+        let elapsed_periods = std::cmp::min(
+            ((now - completed_at) / self.period) as u128,
+            self.periods as u128,
+        );
+        let rest = (total as u128) - at_completion;
+        (at_completion + rest * elapsed_periods / (self.periods as u128)) as u64
+    }
+}
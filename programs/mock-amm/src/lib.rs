@@ -0,0 +1,196 @@
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{associated_token, token};
+
+declare_id!("HVyvxum8JBiG7s2Wnw7mo7Hoa24G6H8ggc1sds8g41FB");
+
+pub const POOL_SEED: &[u8] = b"pool";
+pub const LP_MINT_SEED: &[u8] = b"lpmint";
+pub const LP_DECIMALS: u8 = 9;
+
+// A minimal SOL/token constant product pool. It is only meant to stand in for
+// a real AMM when testing the launchpad liquidity seeding locally.
+#[program]
+pub mod mock_amm {
+    use super::*;
+
+    pub fn initialize_pool(ctx: Context<InitializePool>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+
+        pool.token_mint = ctx.accounts.token_mint.key();
+        pool.lp_mint = ctx.accounts.lp_mint.key();
+        pool.token_reserve = ctx.accounts.token_reserve.key();
+        pool.sol_amount = 0;
+        pool.token_amount = 0;
+        pool.bump = ctx.bumps.pool;
+
+        Ok(())
+    }
+
+    pub fn add_liquidity(
+        ctx: Context<AddLiquidity>,
+        sol_amount: u64,
+        token_amount: u64,
+    ) -> Result<()> {
+        require!(
+            sol_amount > 0 && token_amount > 0,
+            MockAmmError::InvalidAmount
+        );
+
+        let lp_supply = ctx.accounts.lp_mint.supply;
+        let pool = &ctx.accounts.pool;
+        let lp_amount = if lp_supply == 0 {
+            integer_sqrt((sol_amount as u128) * (token_amount as u128))
+        } else {
+            std::cmp::min(
+                (sol_amount as u128) * (lp_supply as u128) / (pool.sol_amount as u128),
+                (token_amount as u128) * (lp_supply as u128) / (pool.token_amount as u128),
+            )
+        } as u64;
+        require!(lp_amount > 0, MockAmmError::InvalidAmount);
+
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.sol_source.to_account_info(),
+                    to: ctx.accounts.pool.to_account_info(),
+                },
+            ),
+            sol_amount,
+        )?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.token_source.to_account_info(),
+                    to: ctx.accounts.token_reserve.to_account_info(),
+                    authority: ctx.accounts.token_authority.to_account_info(),
+                },
+            ),
+            token_amount,
+        )?;
+
+        let token_mint = ctx.accounts.pool.token_mint;
+        let signer_seeds = [POOL_SEED, token_mint.as_ref(), &[ctx.accounts.pool.bump]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.lp_destination.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[&signer_seeds],
+            ),
+            lp_amount,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.sol_amount = pool.sol_amount.checked_add(sol_amount).unwrap();
+        pool.token_amount = pool.token_amount.checked_add(token_amount).unwrap();
+
+        msg!(
+            "Added {} lamports and {} tokens for {} LP",
+            sol_amount,
+            token_amount,
+            lp_amount
+        );
+
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Pool {
+    pub token_mint: Pubkey,
+    pub lp_mint: Pubkey,
+    pub token_reserve: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub bump: u8,
+}
+
+impl Pool {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1;
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(
+        init,
+        seeds = [POOL_SEED, token_mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = Pool::LEN
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    pub token_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        init,
+        seeds = [LP_MINT_SEED, pool.key().as_ref()],
+        bump,
+        payer = payer,
+        mint::decimals = LP_DECIMALS,
+        mint::authority = pool
+    )]
+    pub lp_mint: Box<Account<'info, token::Mint>>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = pool
+    )]
+    pub token_reserve: Box<Account<'info, token::TokenAccount>>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, token::Token>,
+    pub associated_token_program: Program<'info, associated_token::AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    #[account(
+        mut,
+        seeds = [POOL_SEED, pool.token_mint.as_ref()],
+        bump = pool.bump,
+        has_one = lp_mint,
+        has_one = token_reserve
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+    #[account(mut)]
+    pub lp_mint: Box<Account<'info, token::Mint>>,
+    #[account(mut)]
+    pub token_reserve: Box<Account<'info, token::TokenAccount>>,
+    #[account(mut)]
+    pub sol_source: Signer<'info>,
+    #[account(mut)]
+    pub token_source: Box<Account<'info, token::TokenAccount>>,
+    pub token_authority: Signer<'info>,
+    #[account(mut, token::mint = lp_mint)]
+    pub lp_destination: Box<Account<'info, token::TokenAccount>>,
+    pub token_program: Program<'info, token::Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[error_code]
+pub enum MockAmmError {
+    #[msg("Invalid amount")]
+    InvalidAmount,
+}
+
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
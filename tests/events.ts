@@ -0,0 +1,554 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { EncodeSolTeam3 } from "../target/types/encode_sol_team3";
+import { LifecycleStep, runLifecycle } from "./helpers/lifecycle";
+import {
+  TOKEN,
+  TestPool,
+  buy,
+  claim,
+  completePool,
+  confirm,
+  createPool,
+  eventAccounts,
+  find,
+  findUserPool,
+  fundedWallet,
+  sleep,
+  startPool,
+} from "./helpers/pool";
+
+// Events are emitted with emit_cpi!, so they are read back from the inner
+// instructions of the transaction instead of the (truncatable) program logs.
+describe("events", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const program = anchor.workspace.EncodeSolTeam3 as Program<EncodeSolTeam3>;
+
+  async function decodeEvents(signature: string) {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const keys = tx.transaction.message.getAccountKeys();
+
+    return tx.meta.innerInstructions
+      .flatMap((inner) => inner.instructions)
+      .filter((ix) => keys.get(ix.programIdIndex).equals(program.programId))
+      .map((ix) => {
+        const data = anchor.utils.bytes.bs58.decode(ix.data);
+        // skip the 8 byte event instruction tag
+        return program.coder.events.decode(
+          anchor.utils.bytes.base64.encode(data.subarray(8))
+        );
+      })
+      .filter((event) => event !== null);
+  }
+
+  // the one event name emitted for pool by the transaction
+  async function decodeEvent(signature: string, name: string, pool: TestPool) {
+    const events = (await decodeEvents(signature)).filter(
+      (e) => e.name === name
+    );
+    expect(events, name).to.have.lengthOf(1);
+    const event = events[0];
+    expect(event.data.launchPool.equals(pool.launchPool)).to.be.true;
+    expect(event.data.timestamp.toNumber()).to.be.greaterThan(0);
+    return event.data;
+  }
+
+  it("emits a typed event for every lifecycle transition", async () => {
+    const events: Partial<Record<LifecycleStep, any[]>> = {};
+    const pool = await runLifecycle(program, async (step, signature) => {
+      events[step] = await decodeEvents(signature);
+    });
+
+    const expected: Record<LifecycleStep, [string, string]> = {
+      create_native_pool: ["CreateLaunchPoolEvent", "pending"],
+      start_launch_pool: ["StartLaunchPoolEvent", "active"],
+      buy_token_with_native: ["BuyTokenWithNativeEvent", "active"],
+      complete_launch_pool: ["CompleteLaunchPoolEvent", "completed"],
+      claim_token: ["ClaimTokenEvent", "completed"],
+    };
+
+    for (const [step, [name, status]] of Object.entries(expected)) {
+      const event = events[step].find((e) => e.name === name);
+      expect(event, `${name} in ${step}`).to.not.be.undefined;
+      expect(event.data.launchPool.equals(pool.launchPool)).to.be.true;
+      expect(event.data.status).to.have.property(status);
+      expect(event.data.timestamp.toNumber()).to.be.greaterThan(0);
+    }
+
+    const bought = events.buy_token_with_native.find(
+      (e) => e.name === "BuyTokenWithNativeEvent"
+    );
+    expect(bought.data.buyer.equals(pool.buyer.publicKey)).to.be.true;
+    expect(bought.data.tokenAmount.toString()).to.equal(
+      (50 * anchor.web3.LAMPORTS_PER_SOL).toString()
+    );
+    expect(bought.data.currencyAmount.toNumber()).to.be.greaterThan(0);
+
+    const claimed = events.claim_token.find(
+      (e) => e.name === "ClaimTokenEvent"
+    );
+    expect(claimed.data.user.equals(pool.buyer.publicKey)).to.be.true;
+    expect(claimed.data.amount.toString()).to.equal(
+      claimed.data.claimed.toString()
+    );
+  });
+
+  it("emits the sale round events", async () => {
+    const [pool] = await createPool(program);
+    const buyer = await fundedWallet(program);
+    const now = Math.floor(Date.now() / 1000);
+    const rounds = [0, 1].map((index) =>
+      find(program, [
+        Buffer.from("saleround"),
+        pool.launchPool.toBuffer(),
+        Buffer.from([index]),
+      ])
+    );
+
+    for (const [index, saleRound] of rounds.entries()) {
+      const created = await decodeEvent(
+        await confirm(
+          program,
+          await program.methods
+            .createSaleRound(
+              index,
+              new BN(50),
+              new BN(1 * TOKEN),
+              new BN(50 * TOKEN),
+              new BN(now - 10),
+              new BN(now + 100),
+              new BN(50 * TOKEN),
+              { public: {} }
+            )
+            .accounts({
+              launchPool: pool.launchPool,
+              saleRound,
+              authority: pool.creator.publicKey,
+              systemProgram: anchor.web3.SystemProgram.programId,
+              rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+              ...eventAccounts(program),
+            })
+            .rpc()
+        ),
+        "CreateSaleRoundEvent",
+        pool
+      );
+      expect(created.authority.equals(pool.creator.publicKey)).to.be.true;
+      expect(created.index).to.equal(index);
+      expect(created.allocation.toString()).to.equal((50 * TOKEN).toString());
+      expect(created.access).to.have.property("public");
+    }
+    await startPool(program, pool);
+
+    const bought = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .buyTokenInRound(new BN(20 * TOKEN))
+          .accounts({
+            launchPool: pool.launchPool,
+            saleRound: rounds[0],
+            whitelist: null,
+            tokenMint: pool.mint,
+            userPool: findUserPool(program, pool, buyer.publicKey),
+            vault: pool.vault,
+            stakeAccount: null,
+            nftTokenAccount: null,
+            nftMetadata: null,
+            nftUsage: null,
+            instructionsSysvar: null,
+            referralStats: null,
+            user: buyer.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            ...eventAccounts(program),
+          })
+          .signers([buyer])
+          .rpc()
+      ),
+      "BuyTokenInRoundEvent",
+      pool
+    );
+    expect(bought.buyer.equals(buyer.publicKey)).to.be.true;
+    expect(bought.round).to.equal(0);
+    expect(bought.amount.toString()).to.equal((20 * TOKEN).toString());
+
+    // the authority closes the round early, rolling what is left over
+    const advanced = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .advanceSaleRound()
+          .accounts({
+            launchPool: pool.launchPool,
+            currentRound: rounds[0],
+            nextRound: rounds[1],
+            signer: pool.creator.publicKey,
+            ...eventAccounts(program),
+          })
+          .rpc()
+      ),
+      "AdvanceSaleRoundEvent",
+      pool
+    );
+    expect(advanced.signer.equals(pool.creator.publicKey)).to.be.true;
+    expect(advanced.closedRound).to.equal(0);
+    expect(advanced.currentRound).to.equal(1);
+    expect(advanced.rolledOver.toString()).to.equal((30 * TOKEN).toString());
+    expect(advanced.status).to.have.property("active");
+  });
+
+  it("emits the milestone and reclaim events", async () => {
+    const [pool] = await createPool(program);
+    const buyer = await fundedWallet(program);
+    const governed = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .configureGovernance(5_000)
+          .accounts({
+            launchPool: pool.launchPool,
+            authority: pool.creator.publicKey,
+            ...eventAccounts(program),
+          })
+          .rpc()
+      ),
+      "ConfigureGovernanceEvent",
+      pool
+    );
+    expect(governed.authority.equals(pool.creator.publicKey)).to.be.true;
+    expect(governed.quorumBps).to.equal(5_000);
+    await startPool(program, pool);
+    await buy(program, pool, buyer, new BN(50 * TOKEN));
+    await completePool(program, pool);
+
+    const milestone = find(program, [
+      Buffer.from("milestone"),
+      pool.launchPool.toBuffer(),
+      Buffer.from([0]),
+    ]);
+    // leaves room for the vote, whatever the drift of the validator clock
+    const deadline = Math.floor(Date.now() / 1000) + 8;
+    const proposed = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .proposeMilestone(new BN(LAMPORTS_PER_SOL / 10), new BN(deadline))
+          .accounts({
+            launchPool: pool.launchPool,
+            milestone,
+            authority: pool.creator.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            ...eventAccounts(program),
+          })
+          .rpc()
+      ),
+      "ProposeMilestoneEvent",
+      pool
+    );
+    expect(proposed.index).to.equal(0);
+    expect(proposed.amount.toNumber()).to.equal(LAMPORTS_PER_SOL / 10);
+    expect(proposed.deadline.toNumber()).to.equal(deadline);
+    expect(proposed.status).to.have.property("voting");
+
+    const userPool = findUserPool(program, pool, buyer.publicKey);
+    const voted = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .voteMilestone(false)
+          .accounts({
+            launchPool: pool.launchPool,
+            milestone,
+            userPool,
+            vote: find(program, [
+              Buffer.from("vote"),
+              milestone.toBuffer(),
+              buyer.publicKey.toBuffer(),
+            ]),
+            voter: buyer.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            ...eventAccounts(program),
+          })
+          .signers([buyer])
+          .rpc()
+      ),
+      "VoteMilestoneEvent",
+      pool
+    );
+    expect(voted.voter.equals(buyer.publicKey)).to.be.true;
+    expect(voted.weight.toString()).to.equal((50 * TOKEN).toString());
+    expect(voted.approve).to.be.false;
+
+    await sleep(10);
+    const executed = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .executeMilestone()
+          .accounts({
+            launchPool: pool.launchPool,
+            milestone,
+            vault: pool.vault,
+            authority: pool.creator.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            ...eventAccounts(program),
+          })
+          .rpc()
+      ),
+      "ExecuteMilestoneEvent",
+      pool
+    );
+    expect(executed.status).to.have.property("rejected");
+    expect(executed.votesAgainst.toString()).to.equal((50 * TOKEN).toString());
+    expect(executed.refundableAmount.toNumber()).to.equal(LAMPORTS_PER_SOL);
+
+    const reclaimed = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .reclaimFunds()
+          .accounts({
+            launchPool: pool.launchPool,
+            userPool,
+            vault: pool.vault,
+            user: buyer.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            ...eventAccounts(program),
+          })
+          .signers([buyer])
+          .rpc()
+      ),
+      "ReclaimFundsEvent",
+      pool
+    );
+    expect(reclaimed.user.equals(buyer.publicKey)).to.be.true;
+    expect(reclaimed.amount.toNumber()).to.equal(LAMPORTS_PER_SOL);
+  });
+
+  it("emits the authority handover events", async () => {
+    const [pool] = await createPool(program);
+    const successor = await fundedWallet(program);
+
+    const proposed = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .proposeAuthority(successor.publicKey)
+          .accounts({
+            launchPool: pool.launchPool,
+            authority: pool.creator.publicKey,
+            ...eventAccounts(program),
+          })
+          .rpc()
+      ),
+      "ProposeAuthorityEvent",
+      pool
+    );
+    expect(proposed.authority.equals(pool.creator.publicKey)).to.be.true;
+    expect(proposed.pendingAuthority.equals(successor.publicKey)).to.be.true;
+
+    const accepted = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .acceptAuthority()
+          .accounts({
+            launchPool: pool.launchPool,
+            treasurer: pool.treasurer,
+            newAuthority: successor.publicKey,
+            ...eventAccounts(program),
+          })
+          .signers([successor])
+          .rpc()
+      ),
+      "AcceptAuthorityEvent",
+      pool
+    );
+    expect(accepted.authority.equals(successor.publicKey)).to.be.true;
+  });
+
+  it("emits the team lock event", async () => {
+    const [pool] = await createPool(program);
+    const buyer = await fundedWallet(program);
+    const teamLock = find(program, [
+      Buffer.from("teamlock"),
+      pool.launchPool.toBuffer(),
+    ]);
+    const teamLockTokenAccount = getAssociatedTokenAddressSync(
+      pool.mint,
+      teamLock,
+      true
+    );
+
+    // everything vests at the buyers' unlock date
+    const locked = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .configureTeamLock(new BN(100 * TOKEN), pool.unlockDate, new BN(0))
+          .accounts({
+            launchPool: pool.launchPool,
+            tokenMint: pool.mint,
+            teamLock,
+            teamLockTokenAccount,
+            authority: pool.creator.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            ...eventAccounts(program),
+          })
+          .rpc()
+      ),
+      "ConfigureTeamLockEvent",
+      pool
+    );
+    expect(locked.amount.toString()).to.equal((100 * TOKEN).toString());
+    expect(locked.cliffDate.toString()).to.equal(pool.unlockDate.toString());
+    await startPool(program, pool, teamLock);
+    await buy(program, pool, buyer, new BN(10 * TOKEN));
+    await completePool(program, pool);
+    await sleep(6);
+
+    const withdrawn = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .withdrawTeamTokens()
+          .accounts({
+            launchPool: pool.launchPool,
+            teamLock,
+            teamLockTokenAccount,
+            destination: pool.source,
+            authority: pool.creator.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            ...eventAccounts(program),
+          })
+          .rpc()
+      ),
+      "WithdrawTeamTokensEvent",
+      pool
+    );
+    expect(withdrawn.authority.equals(pool.creator.publicKey)).to.be.true;
+    expect(withdrawn.amount.toString()).to.equal((100 * TOKEN).toString());
+    expect(withdrawn.withdrawn.toString()).to.equal((100 * TOKEN).toString());
+  });
+
+  it("emits the close events", async () => {
+    const [pool] = await createPool(program);
+    const buyer = await fundedWallet(program);
+    await startPool(program, pool);
+    await buy(program, pool, buyer, new BN(10 * TOKEN));
+    await completePool(program, pool);
+    await sleep(6);
+    await claim(program, pool, buyer);
+
+    const finalized = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .finalizeUnsoldTokens()
+          .accounts({
+            launchPool: pool.launchPool,
+            tokenMint: pool.mint,
+            treasurer: pool.treasurer,
+            treasury: pool.treasury,
+            authorityTokenAccount: pool.source,
+            ammTokenReserve: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            ...eventAccounts(program),
+          })
+          .rpc()
+      ),
+      "FinalizeUnsoldTokensEvent",
+      pool
+    );
+    expect(finalized.unsoldPolicy).to.have.property("refundToCreator");
+    expect(finalized.amount.toString()).to.equal((90 * TOKEN).toString());
+
+    const closedPosition = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .closeUserPool()
+          .accounts({
+            launchPool: pool.launchPool,
+            userPool: findUserPool(program, pool, buyer.publicKey),
+            user: buyer.publicKey,
+            ...eventAccounts(program),
+          })
+          .signers([buyer])
+          .rpc()
+      ),
+      "CloseUserPoolEvent",
+      pool
+    );
+    expect(closedPosition.user.equals(buyer.publicKey)).to.be.true;
+    expect(closedPosition.openPositions).to.equal(0);
+
+    // the creator takes the proceeds before the pool can close
+    const withdrawn = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .withdrawProceeds()
+          .accounts({
+            launchPool: pool.launchPool,
+            vault: pool.vault,
+            authority: pool.creator.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            ...eventAccounts(program),
+          })
+          .rpc()
+      ),
+      "WithdrawProceedsEvent",
+      pool
+    );
+    expect(withdrawn.amount.toNumber()).to.equal(LAMPORTS_PER_SOL / 5);
+
+    const seed = (prefix: string): PublicKey =>
+      find(program, [Buffer.from(prefix), pool.launchPool.toBuffer()]);
+    const closed = await decodeEvent(
+      await confirm(
+        program,
+        await program.methods
+          .closeLaunchPool()
+          .accounts({
+            launchPool: pool.launchPool,
+            treasurer: pool.treasurer,
+            treasury: pool.treasury,
+            vault: pool.vault,
+            poolMetadata: seed("poolmetadata"),
+            teamLock: seed("teamlock"),
+            lpLock: seed("lplock"),
+            creator: pool.creator.publicKey,
+            authority: pool.creator.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            ...eventAccounts(program),
+          })
+          .rpc()
+      ),
+      "CloseLaunchPoolEvent",
+      pool
+    );
+    expect(closed.authority.equals(pool.creator.publicKey)).to.be.true;
+    expect(closed.status).to.have.property("completed");
+    expect(await provider.connection.getAccountInfo(pool.launchPool)).to.be
+      .null;
+  });
+});
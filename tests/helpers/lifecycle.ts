@@ -0,0 +1,56 @@
+import { BN, Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { EncodeSolTeam3 } from "../../target/types/encode_sol_team3";
+import {
+  TOKEN,
+  buy,
+  claim,
+  completePool,
+  createPool,
+  fundedWallet,
+  sleep,
+  startPool,
+} from "./pool";
+
+export type LifecycleStep =
+  | "create_native_pool"
+  | "start_launch_pool"
+  | "buy_token_with_native"
+  | "complete_launch_pool"
+  | "claim_token";
+
+export type LifecyclePool = {
+  launchPool: PublicKey;
+  mint: PublicKey;
+  creator: Keypair;
+  buyer: Keypair;
+};
+
+// Runs create -> start -> buy -> complete -> claim on a fresh mint and hands
+// every confirmed signature to onStep.
+export async function runLifecycle(
+  program: Program<EncodeSolTeam3>,
+  onStep: (step: LifecycleStep, signature: string) => Promise<void>
+): Promise<LifecyclePool> {
+  const buyer = await fundedWallet(program);
+
+  const [pool, created] = await createPool(program);
+  await onStep("create_native_pool", created);
+  await onStep("start_launch_pool", await startPool(program, pool));
+  await onStep(
+    "buy_token_with_native",
+    await buy(program, pool, buyer, new BN(50 * TOKEN))
+  );
+  await onStep("complete_launch_pool", await completePool(program, pool));
+
+  // wait for the unlock date before claiming
+  await sleep(6);
+  await onStep("claim_token", await claim(program, pool, buyer));
+
+  return {
+    launchPool: pool.launchPool,
+    mint: pool.mint,
+    creator: pool.creator,
+    buyer,
+  };
+}
@@ -0,0 +1,279 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { EncodeSolTeam3 } from "../../target/types/encode_sol_team3";
+
+// one whole token of the 9 decimals test mint
+export const TOKEN = LAMPORTS_PER_SOL;
+
+export type TestPool = {
+  launchPool: PublicKey;
+  mint: PublicKey;
+  treasurer: PublicKey;
+  treasury: PublicKey;
+  vault: PublicKey;
+  // the creator's supply of the pool token
+  source: PublicKey;
+  creator: Keypair;
+  unlockDate: BN;
+};
+
+export const find = (program: Program<EncodeSolTeam3>, seeds: Buffer[]) =>
+  PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+export const eventAccounts = (program: Program<EncodeSolTeam3>) => ({
+  eventAuthority: find(program, [Buffer.from("__event_authority")]),
+  program: program.programId,
+});
+
+export const findUserPool = (
+  program: Program<EncodeSolTeam3>,
+  pool: TestPool,
+  user: PublicKey
+) =>
+  find(program, [
+    Buffer.from("userpool"),
+    user.toBuffer(),
+    pool.launchPool.toBuffer(),
+    pool.mint.toBuffer(),
+  ]);
+
+export const sleep = (seconds: number) =>
+  new Promise((resolve) => setTimeout(resolve, seconds * 1000));
+
+export async function confirm(
+  program: Program<EncodeSolTeam3>,
+  signature: string
+) {
+  await program.provider.connection.confirmTransaction(signature, "confirmed");
+  return signature;
+}
+
+export async function fundedWallet(program: Program<EncodeSolTeam3>) {
+  const wallet = Keypair.generate();
+  await confirm(
+    program,
+    await program.provider.connection.requestAirdrop(
+      wallet.publicKey,
+      10 * LAMPORTS_PER_SOL
+    )
+  );
+  return wallet;
+}
+
+// Creates a pending pool of 100 tokens at 50 tokens per SOL on a fresh mint,
+// created by the provider wallet and claimable unlockIn seconds from now.
+export async function createPool(
+  program: Program<EncodeSolTeam3>,
+  unlockIn = 5
+): Promise<[TestPool, string]> {
+  const provider = program.provider as anchor.AnchorProvider;
+  const connection = provider.connection;
+  const creator = (provider.wallet as anchor.Wallet).payer;
+
+  const mint = await createMint(
+    connection,
+    creator,
+    creator.publicKey,
+    null,
+    9
+  );
+  const source = await getOrCreateAssociatedTokenAccount(
+    connection,
+    creator,
+    mint,
+    creator.publicKey
+  );
+  await mintTo(
+    connection,
+    creator,
+    mint,
+    source.address,
+    creator,
+    1000 * TOKEN
+  );
+
+  const creatorRegistry = find(program, [
+    Buffer.from("creatorregistry"),
+    creator.publicKey.toBuffer(),
+  ]);
+  const registry = await program.account.creatorRegistry.fetchNullable(
+    creatorRegistry
+  );
+  const index: BN = registry ? registry.poolCount : new BN(0);
+  const launchPool = find(program, [
+    Buffer.from("launchpool"),
+    creator.publicKey.toBuffer(),
+    index.toArrayLike(Buffer, "le", 8),
+  ]);
+  const treasurer = find(program, [
+    Buffer.from("treasurer"),
+    launchPool.toBuffer(),
+    mint.toBuffer(),
+  ]);
+  const pool: TestPool = {
+    launchPool,
+    mint,
+    treasurer,
+    treasury: getAssociatedTokenAddressSync(mint, treasurer, true),
+    vault: find(program, [
+      Buffer.from("vault"),
+      launchPool.toBuffer(),
+      creator.publicKey.toBuffer(),
+    ]),
+    source: source.address,
+    creator,
+    unlockDate: new BN(Math.floor(Date.now() / 1000) + unlockIn),
+  };
+
+  const signature = await program.methods
+    .createNativePool(
+      pool.unlockDate,
+      new BN(100 * TOKEN),
+      new BN(1 * TOKEN),
+      new BN(100 * TOKEN),
+      new BN(50),
+      9,
+      null,
+      { refundToCreator: {} }
+    )
+    .accounts({
+      creatorRegistry,
+      launchPool,
+      tokenMint: mint,
+      treasurer,
+      treasury: pool.treasury,
+      authority: creator.publicKey,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      ...eventAccounts(program),
+    })
+    .rpc();
+  return [pool, await confirm(program, signature)];
+}
+
+// a pool that configured a team lock must pass it to fund the lock on start
+export async function startPool(
+  program: Program<EncodeSolTeam3>,
+  pool: TestPool,
+  teamLock: PublicKey | null = null
+) {
+  const signature = await program.methods
+    .startLaunchPool()
+    .accounts({
+      launchPool: pool.launchPool,
+      tokenMint: pool.mint,
+      sourceTokenAccount: pool.source,
+      treasurer: pool.treasurer,
+      treasury: pool.treasury,
+      teamLock,
+      teamLockTokenAccount: teamLock
+        ? getAssociatedTokenAddressSync(pool.mint, teamLock, true)
+        : null,
+      authority: pool.creator.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      ...eventAccounts(program),
+    })
+    .rpc();
+  return confirm(program, signature);
+}
+
+export async function buy(
+  program: Program<EncodeSolTeam3>,
+  pool: TestPool,
+  buyer: Keypair,
+  amount: BN
+) {
+  const signature = await program.methods
+    .buyTokenWithNative(amount)
+    .accounts({
+      launchPool: pool.launchPool,
+      tokenMint: pool.mint,
+      userPool: findUserPool(program, pool, buyer.publicKey),
+      vault: pool.vault,
+      stakeAccount: null,
+      nftTokenAccount: null,
+      nftMetadata: null,
+      nftUsage: null,
+      instructionsSysvar: null,
+      referralStats: null,
+      user: buyer.publicKey,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      ...eventAccounts(program),
+    })
+    .signers([buyer])
+    .rpc();
+  return confirm(program, signature);
+}
+
+export async function completePool(
+  program: Program<EncodeSolTeam3>,
+  pool: TestPool
+) {
+  const signature = await program.methods
+    .completeLaunchPool()
+    .accounts({
+      launchPool: pool.launchPool,
+      tokenMint: pool.mint,
+      authority: pool.creator.publicKey,
+      treasurer: null,
+      treasury: null,
+      vault: null,
+      ammProgram: null,
+      ammPool: null,
+      ammLpMint: null,
+      ammTokenReserve: null,
+      lpLock: null,
+      lpTokenAccount: null,
+      liquidityEscrow: null,
+      escrowTokenAccount: null,
+      systemProgram: null,
+      tokenProgram: null,
+      ...eventAccounts(program),
+    })
+    .rpc();
+  return confirm(program, signature);
+}
+
+export async function claim(
+  program: Program<EncodeSolTeam3>,
+  pool: TestPool,
+  buyer: Keypair
+) {
+  const signature = await program.methods
+    .claimToken()
+    .accounts({
+      launchPool: pool.launchPool,
+      tokenMint: pool.mint,
+      treasurer: pool.treasurer,
+      treasury: pool.treasury,
+      userPool: findUserPool(program, pool, buyer.publicKey),
+      userTokenAccount: getAssociatedTokenAddressSync(
+        pool.mint,
+        buyer.publicKey
+      ),
+      user: buyer.publicKey,
+      systemProgram: anchor.web3.SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+      ...eventAccounts(program),
+    })
+    .signers([buyer])
+    .rpc();
+  return confirm(program, signature);
+}